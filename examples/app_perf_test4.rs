@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Simple performance test for rust_xlsxwriter comparing formatted writes with
+//! a `Format` and with a pre-registered `FormatId`.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use std::env;
+use std::time::Instant;
+
+fn main() -> Result<(), XlsxError> {
+    let args: Vec<String> = env::args().collect();
+
+    let col_max = 50;
+    let row_max = match args.get(1) {
+        Some(arg) => arg.parse::<u32>().unwrap_or(20_000),
+        None => 20_000,
+    };
+
+    let formats = [
+        Format::new().set_bold(),
+        Format::new().set_num_format("0.00"),
+        Format::new().set_italic().set_font_color("#FF0000"),
+    ];
+
+    // Write the cells with a Format reference.
+    let start = Instant::now();
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    for row in 0..row_max {
+        for col in 0..col_max {
+            let format = &formats[col as usize % formats.len()];
+            worksheet.write_with_format(row, col, 123.456, format)?;
+        }
+    }
+    println!("write_with_format():    {:?}", start.elapsed());
+
+    // Write the cells with a pre-registered FormatId.
+    let start = Instant::now();
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let format_ids: Vec<_> = formats
+        .iter()
+        .map(|format| worksheet.register_format(format))
+        .collect();
+
+    for row in 0..row_max {
+        for col in 0..col_max {
+            let format_id = format_ids[col as usize % format_ids.len()];
+            worksheet.write_with_format_id(row, col, 123.456, format_id)?;
+        }
+    }
+    println!("write_with_format_id(): {:?}", start.elapsed());
+
+    workbook.save("rust_perf_test.xlsx")?;
+
+    Ok(())
+}
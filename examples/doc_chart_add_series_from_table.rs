@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating a chart series from the columns of a table.
+
+use rust_xlsxwriter::{Chart, ChartType, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the table.
+    worksheet.write_column(1, 0, ["North", "South", "East", "West"])?;
+    worksheet.write_column(1, 1, [50, 30, 40, 60])?;
+
+    // Add a table over the data.
+    let table = Table::new().set_name("Sales");
+    worksheet.add_table(0, 0, 4, 1, &table)?;
+
+    // Create a chart with a series based on the table columns.
+    let mut chart = Chart::new(ChartType::Column);
+    chart.add_series_from_table(worksheet, "Sales", 0, 1)?;
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating a Histogram chart with a fixed number of bins.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    let data = [2, 3, 3, 4, 5, 5, 5, 6, 6, 7, 8, 9, 9, 10, 12];
+    worksheet.write(0, 0, "Scores")?;
+    worksheet.write_column(1, 0, data)?;
+
+    // Create a new Histogram chart.
+    let mut chart = Chart::new(ChartType::Histogram);
+
+    // Add a data series and group the values into 5 bins.
+    chart
+        .add_series()
+        .set_name("Sheet1!$A$1")
+        .set_values("Sheet1!$A$2:$A$16")
+        .set_bin_count(5);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of setting the viewing angle of a 3D column chart.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 40)?;
+    worksheet.write(2, 0, 50)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column3D);
+
+    // Add a data series.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+    // Set the viewing angle of the chart.
+    chart
+        .set_rotation_x(30)
+        .set_rotation_y(40)
+        .set_right_angle_axes(false)
+        .set_perspective(60);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
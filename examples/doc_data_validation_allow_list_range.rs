@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a data validation dropdown list
+//! whose values are taken from a range on another worksheet.
+
+use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet for the input data and one for the lookup list.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(1, 0, "Select value in cell C2:")?;
+
+    let data_validation = DataValidation::new()
+        .allow_list_range("My Lists", 0, 0, 4, 0)
+        .set_input_title("Select a fruit")
+        .set_input_message("Choose a value from the dropdown list.");
+
+    worksheet.add_data_validation(1, 2, 1, 2, &data_validation)?;
+
+    let lists = workbook.add_worksheet().set_name("My Lists")?;
+    lists.write_column(0, 0, ["Apple", "Banana", "Cherry", "Damson", "Elder"])?;
+
+    // Save the file to disk.
+    workbook.save("data_validation.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the font scheme for a format.
+
+use rust_xlsxwriter::{Format, FormatFontScheme, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new()
+        .set_font_name("Cambria")
+        .set_font_scheme(FormatFontScheme::Major);
+
+    worksheet.write_string_with_format(0, 0, "Heading", &format)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the text reading order.
+
+use rust_xlsxwriter::{Format, FormatReadingOrder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 25)?;
+
+    let format = Format::new().set_reading_order(FormatReadingOrder::RightToLeft);
+
+    worksheet.set_right_to_left(true);
+    worksheet.write_string_with_format(0, 0, "نص عربي / English text", &format)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
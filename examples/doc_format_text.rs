@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a text format.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::text();
+
+    // The ID will be displayed as written and will stay as text if the cell is
+    // edited in Excel.
+    worksheet.write_string_with_format(0, 0, "00123", &format)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
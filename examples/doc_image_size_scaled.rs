@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the displayed size of a scaled
+//! image.
+
+use rust_xlsxwriter::{Image, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let image = Image::new("examples/rust_logo.png")?
+        .set_scale_width(2.0)
+        .set_scale_height(0.5);
+
+    assert_eq!((212.0, 53.0), image.size_scaled());
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates using the `NumFormat` helpers to create
+//! fraction number formats.
+
+use rust_xlsxwriter::{Format, NumFormat, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Fractions with up to 2 digits in the denominator, like "3 11/16".
+    let format1 = Format::new().set_num_format(NumFormat::fraction(2));
+
+    // Fractions in halves, like "3 1/2".
+    let format2 = Format::new().set_num_format(NumFormat::fraction_fixed(2));
+
+    worksheet.write_number_with_format(0, 0, 3.6875, &format1)?;
+    worksheet.write_number_with_format(1, 0, 3.6875, &format2)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of setting constant creation and modified dates in the workbook
+//! document properties.
+
+use rust_xlsxwriter::{DocProperties, ExcelDateTime, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create the creation and modified dates for the file.
+    let created = ExcelDateTime::from_ymd(2023, 1, 1)?;
+    let modified = ExcelDateTime::from_ymd(2024, 6, 30)?.and_hms(12, 30, 0)?;
+
+    // Add them to the document metadata.
+    let properties = DocProperties::new()
+        .set_creation_datetime(&created)
+        .set_modified_datetime(&modified);
+
+    workbook.set_properties(&properties);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    workbook.save("properties.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a url with a brand color and
+//! without the default underline.
+
+use rust_xlsxwriter::{Color, Format, Url, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a format without an underline.
+    let brand_format = Format::new().set_font_color(Color::RGB(0x7030A0));
+
+    // Write a url with the alternative format.
+    let url = Url::new("https://www.rust-lang.org").set_format(&brand_format);
+    worksheet.write(0, 0, url)?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a pie chart to a chartsheet.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add some data for the chart.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_column(0, 0, ["Apple", "Cherry", "Pecan"])?;
+    worksheet.write_column(0, 1, [60, 30, 10])?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Pie);
+    chart
+        .add_series()
+        .set_categories("Sheet1!$A$1:$A$3")
+        .set_values("Sheet1!$B$1:$B$3");
+
+    // Add the chart to a chartsheet.
+    let chartsheet = workbook.add_chartsheet();
+    chartsheet.insert_chart(0, 0, &chart)?;
+    chartsheet.set_active(true);
+
+    workbook.save("chartsheet.xlsx")?;
+
+    Ok(())
+}
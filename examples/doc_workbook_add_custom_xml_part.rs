@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a custom XML part to a workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?><document id="1234"/>"#;
+    let custom_xml_id = workbook.add_custom_xml_part(xml);
+
+    println!("Custom XML itemID: {}", custom_xml_id.item_id());
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the calculation mode of a
+//! workbook to manual.
+
+use rust_xlsxwriter::{CalcMode, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_calc_mode(CalcMode::Manual);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_formula_with_result(0, 0, "=1+1", "2", &Format::new())?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a workbook where all the
+//! worksheets are displayed from right to left.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_default_right_to_left(true);
+
+    // Both worksheets are displayed right to left.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "نص عربي")?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "טקסט בעברית")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
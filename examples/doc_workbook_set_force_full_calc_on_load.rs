@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates forcing a full recalculation of the
+//! workbook formulas when the file is opened.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_force_full_calc_on_load(true);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_formula(0, 0, "=1+1")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the default formula result for
+//! all the worksheets in a workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_formula_result_default("");
+
+    // Both worksheets use the empty string as the default result.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_formula(0, 0, "=1+1")?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_formula(0, 0, "=2+2")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
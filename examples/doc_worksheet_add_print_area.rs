@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a print area made up of two
+//! ranges.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Set the print area to "A1:C10,E1:G10".
+    worksheet.set_print_area(0, 0, 9, 2)?;
+    worksheet.add_print_area(0, 4, 9, 6)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
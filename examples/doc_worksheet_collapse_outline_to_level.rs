@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates collapsing a multi-level row outline so
+//! that only the top level summary rows are visible.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some sample data.
+    worksheet.write_column(1, 0, ["North", "North", "North"])?;
+    worksheet.write_column(1, 1, [1000, 1200, 800])?;
+    worksheet.write(4, 0, "North Total")?;
+    worksheet.write_formula(4, 1, "=SUBTOTAL(9,B2:B4)")?;
+    worksheet.write_column(5, 0, ["South", "South"])?;
+    worksheet.write_column(5, 1, [500, 700])?;
+    worksheet.write(7, 0, "South Total")?;
+    worksheet.write_formula(7, 1, "=SUBTOTAL(9,B6:B7)")?;
+    worksheet.write(8, 0, "Grand Total")?;
+    worksheet.write_formula(8, 1, "=SUBTOTAL(9,B2:B7)")?;
+
+    // Create a 2 level outline of the regions and their detail rows.
+    worksheet.group_rows(1, 7, false)?;
+    worksheet.group_rows(1, 3, false)?;
+    worksheet.group_rows(5, 6, false)?;
+
+    // Collapse the outline so that only the grand total is visible.
+    worksheet.collapse_outline_to_level(1, 0)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
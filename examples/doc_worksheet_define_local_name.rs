@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates defining a local/worksheet name and
+//! using it in a formula.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet().set_name("Sales Data")?;
+
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+
+    // Define a local name for the data range and use it in a formula.
+    worksheet.define_local_name("Sales", "='Sales Data'!$A$1:$A$3")?;
+    worksheet.write_formula(3, 0, "=SUM(Sales)")?;
+
+    workbook.save("defined_name.xlsx")?;
+
+    Ok(())
+}
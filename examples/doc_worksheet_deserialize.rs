@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing some data to a worksheet and
+//! then deserializing it back to a vector of structs.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: String,
+        cost: f64,
+    }
+
+    // Create some data instances.
+    let items = vec![
+        Produce {
+            fruit: "Peach".to_string(),
+            cost: 1.05,
+        },
+        Produce {
+            fruit: "Plum".to_string(),
+            cost: 0.15,
+        },
+        Produce {
+            fruit: "Pear".to_string(),
+            cost: 0.75,
+        },
+    ];
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.deserialize_headers::<Produce>(0, 0)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Get the range of the serialized data, including the headers.
+    let (first_row, first_col, last_row, last_col) =
+        worksheet.get_serialize_dimensions("Produce")?;
+
+    // Read the data back from the worksheet.
+    let data: Vec<Produce> = worksheet.deserialize(first_row, first_col, last_row, last_col)?;
+
+    assert_eq!(data, items);
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
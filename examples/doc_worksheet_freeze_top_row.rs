@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates freezing the top row and/or the first
+//! column of worksheets.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Freeze the top row only.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Scroll down")?;
+    worksheet.freeze_top_row();
+
+    // Freeze the first column only.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Scroll across")?;
+    worksheet.freeze_first_column();
+
+    // Freeze the top row and first column.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Scroll down or across")?;
+    worksheet.freeze_top_row_and_first_column();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
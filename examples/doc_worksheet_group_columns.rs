@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates grouping columns into an outline
+//! group.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some sample data.
+    worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1"])?;
+    worksheet.write_row(1, 0, [100, 200, 300])?;
+    worksheet.write_formula(1, 3, "=SUM(A2:C2)")?;
+
+    // Group the monthly columns so they can be collapsed into the
+    // quarterly summary column.
+    worksheet.group_columns(0, 2, false)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates grouping rows into nested outline
+//! groups with explicit outline levels.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some sample data.
+    worksheet.write_column(1, 0, ["Salaries", "Rent", "Utilities"])?;
+    worksheet.write_column(1, 1, [5000, 1500, 300])?;
+    worksheet.write(4, 0, "Operating Costs")?;
+    worksheet.write_formula(4, 1, "=SUBTOTAL(9,B2:B4)")?;
+    worksheet.write_column(5, 0, ["Interest", "Fees"])?;
+    worksheet.write_column(5, 1, [200, 50])?;
+    worksheet.write(7, 0, "Financing Costs")?;
+    worksheet.write_formula(7, 1, "=SUBTOTAL(9,B6:B7)")?;
+    worksheet.write(8, 0, "Total Costs")?;
+    worksheet.write_formula(8, 1, "=SUBTOTAL(9,B2:B7)")?;
+
+    // Group all the cost rows at the first outline level.
+    worksheet.group_rows_with_level(1, 7, 1, false)?;
+
+    // Group the detail rows of each category at the second outline level.
+    worksheet.group_rows_with_level(1, 3, 2, false)?;
+    worksheet.group_rows_with_level(5, 6, 2, false)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
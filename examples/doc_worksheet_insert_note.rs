@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding notes to worksheet cells.
+
+use rust_xlsxwriter::{Note, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data.
+    worksheet.write(0, 0, "Price")?;
+    worksheet.write(1, 0, 17.5)?;
+
+    // Add notes to the cells.
+    let note = Note::new("Price excludes tax.").set_author("Finance");
+    worksheet.insert_note(1, 0, &note)?;
+
+    let note = Note::new("Prices are in EUR.").set_visible(true);
+    worksheet.insert_note(0, 0, &note)?;
+
+    workbook.save("notes.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a threaded comment with a reply
+//! to a worksheet cell.
+
+use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data.
+    worksheet.write(0, 0, "Budget")?;
+    worksheet.write(1, 0, 5000)?;
+
+    // Add a threaded comment with a reply.
+    let reply = ThreadedComment::new("Agreed, it is approved.").set_author("Finance");
+    let comment = ThreadedComment::new("Is this the final figure?")
+        .set_author("Sales")
+        .add_reply(&reply);
+
+    worksheet.insert_threaded_comment(1, 0, &comment)?;
+
+    workbook.save("threaded_comments.xlsx")?;
+
+    Ok(())
+}
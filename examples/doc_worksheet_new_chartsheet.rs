@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a chartsheet and adding it to
+//! a workbook.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, Worksheet, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add some data for the chart.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+    chart.add_series().set_values("Sheet1!$A$1:$A$6");
+
+    // Add the chart to a chartsheet.
+    let mut chartsheet = Worksheet::new_chartsheet();
+    chartsheet.set_name("Results")?;
+    chartsheet.insert_chart(0, 0, &chart)?;
+
+    workbook.push_worksheet(chartsheet);
+
+    workbook.save("chartsheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting and clearing the print area of
+//! a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_print_area(0, 0, 9, 2)?;
+    assert_eq!(worksheet.print_area(), Some("$A$1:$C$10".to_string()));
+
+    worksheet.clear_print_area();
+    assert_eq!(worksheet.print_area(), None);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates registering a format and using it to
+//! write data.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Register the format once.
+    let format = Format::new().set_num_format("0.00");
+    let format_id = worksheet.register_format(&format);
+
+    // Write data using the pre-registered format.
+    for row in 0..1000 {
+        worksheet.write_with_format_id(row, 0, f64::from(row) / 3.0, format_id)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
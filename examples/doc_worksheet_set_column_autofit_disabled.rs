@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates excluding a column from autofit.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data.
+    worksheet.write_string(0, 0, "Name")?;
+    worksheet.write_string(0, 1, "A long description of the item")?;
+
+    // Keep the description column narrow.
+    worksheet.set_column_width(1, 10)?;
+    worksheet.set_column_autofit_disabled(1)?;
+
+    // Autofit the other columns.
+    worksheet.autofit();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
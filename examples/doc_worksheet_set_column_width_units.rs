@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the width of columns in Excel in
+//! character units and in pixels.
+
+use rust_xlsxwriter::{ColumnWidth, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add some text.
+    worksheet.write_string(0, 0, "Normal")?;
+    worksheet.write_string(0, 2, "Wider")?;
+    worksheet.write_string(0, 4, "Narrower")?;
+
+    // Set the column widths in character units and in pixels.
+    worksheet.set_column_width_units(2, ColumnWidth::Chars(16.0))?;
+    worksheet.set_column_width_units(4, ColumnWidth::Pixels(33.0))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
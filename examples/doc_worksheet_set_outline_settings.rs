@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates displaying the outline group symbols
+//! above the grouped rows, for a report where the summary row comes first.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some sample data.
+    worksheet.write(0, 0, "Total")?;
+    worksheet.write_formula(0, 1, "=SUBTOTAL(9,B2:B4)")?;
+    worksheet.write_column(1, 0, ["North", "South", "East"])?;
+    worksheet.write_column(1, 1, [1000, 1200, 800])?;
+
+    // Display the outline symbols above the group, next to the summary row.
+    worksheet.set_outline_settings(false, true);
+
+    // Group and collapse the detail rows under the summary row.
+    worksheet.group_rows(1, 3, true)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a separate zoom level for the
+//! page break preview view.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Hello")?;
+    worksheet.set_zoom(150);
+    worksheet.set_page_break_preview_zoom(80);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates selecting several ranges of cells in a
+//! worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Select cells B2 to C3 and E5 to F6. The active cell is B2.
+    worksheet.set_selections(&[(1, 1, 2, 2), (4, 4, 5, 5)])?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting split worksheet panes.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Scroll in any pane")?;
+
+    // Split the worksheet below the first row and to the right of the
+    // first column.
+    worksheet.set_split_panes(15.0, 8.43);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
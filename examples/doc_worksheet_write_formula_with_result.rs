@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing formulas with numeric, boolean
+//! and string results to a worksheet.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    let bold = Format::new().set_bold();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write some formulas with their calculated results.
+    worksheet.write_formula_with_result(0, 0, "=1+2+3", "6", &bold)?;
+    worksheet.write_formula_with_result(1, 0, "=A1>5", "TRUE", &bold)?;
+    worksheet.write_formula_with_result(2, 0, r#"=UPPER("rust")"#, "RUST", &bold)?;
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a rounded number to a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // This is stored as 0.30000000000000004.
+    worksheet.write_number(0, 0, 0.1 + 0.2)?;
+
+    // This is stored as 0.3.
+    worksheet.write_number_rounded(1, 0, 0.1 + 0.2, 2)?;
+
+    workbook.save("numbers.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing `Result` values to a worksheet
+//! with the different error modes.
+
+use rust_xlsxwriter::{ResultErrorMode, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Some data parsed from strings, some of which will fail.
+    let data = ["123", "456", "abc"];
+
+    for (row, value) in data.iter().enumerate() {
+        let result = value.parse::<i32>();
+        let row = row as u32;
+
+        worksheet.write_result(row, 0, &result, ResultErrorMode::Text)?;
+        worksheet.write_result(row, 1, &result, ResultErrorMode::Blank)?;
+        worksheet.write_result(row, 2, &result, ResultErrorMode::ValueError)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing sparse data to a row.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Some sparse data as (column offset, value) pairs.
+    let data = [(0, 10), (3, 20), (7, 30)];
+
+    // Write the data to columns B, E and I.
+    worksheet.write_sparse_row(0, 1, data)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
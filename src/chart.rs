@@ -741,7 +741,6 @@
 //!
 //! Future additions to chart support in `rust_xlsxwriter` include:
 //!
-//! - Chartsheets - Worksheets that only display a chart.
 //! - Some chart element layout options.
 //!
 //! See the [Chart Roadmap] on the `rust_xlsxwriter` GitHub for more
@@ -760,8 +759,8 @@ use crate::{
     static_regex,
     utility::{self, ToXmlBoolean},
     xmlwriter::XMLWriter,
-    ColNum, Color, IntoColor, IntoExcelDateTime, ObjectMovement, RowNum, XlsxError, COL_MAX,
-    ROW_MAX,
+    ColNum, Color, IntoColor, IntoExcelDateTime, ObjectMovement, RowNum, Worksheet, XlsxError,
+    COL_MAX, ROW_MAX,
 };
 
 #[derive(Clone)]
@@ -817,6 +816,7 @@ use crate::{
 /// ```
 pub struct Chart {
     pub(crate) id: u32,
+    pub(crate) is_chartsheet: bool,
     pub(crate) writer: XMLWriter,
     pub(crate) x_offset: u32,
     pub(crate) y_offset: u32,
@@ -844,6 +844,7 @@ pub struct Chart {
     pub(crate) legend: ChartLegend,
     pub(crate) chart_area_format: ChartFormat,
     pub(crate) plot_area_format: ChartFormat,
+    pub(crate) plot_area: ChartPlotArea,
     pub(crate) combined_chart: Option<Box<Chart>>,
     grouping: ChartGrouping,
     show_empty_cells_as: Option<ChartEmptyCells>,
@@ -855,6 +856,10 @@ pub struct Chart {
     style: u8,
     hole_size: u8,
     rotation: u16,
+    rotation_x: Option<i16>,
+    rotation_y: Option<u16>,
+    perspective: Option<u8>,
+    right_angle_axes: Option<bool>,
     has_up_down_bars: bool,
     up_bar_format: ChartFormat,
     down_bar_format: ChartFormat,
@@ -936,6 +941,7 @@ impl Chart {
         let chart = Chart {
             writer,
             id: 0,
+            is_chartsheet: false,
             height: 288.0,
             width: 480.0,
             scale_width: 1.0,
@@ -962,6 +968,7 @@ impl Chart {
             legend: ChartLegend::new(),
             chart_area_format: ChartFormat::default(),
             plot_area_format: ChartFormat::default(),
+            plot_area: ChartPlotArea::new(),
             grouping: ChartGrouping::Standard,
             show_empty_cells_as: None,
             show_hidden_data: false,
@@ -972,6 +979,10 @@ impl Chart {
             style: 2,
             hole_size: 50,
             rotation: 0,
+            rotation_x: None,
+            rotation_y: None,
+            perspective: None,
+            right_angle_axes: None,
             default_label_position: ChartDataLabelPosition::Default,
             has_up_down_bars: false,
             up_bar_format: ChartFormat::default(),
@@ -996,9 +1007,10 @@ impl Chart {
                 Self::initialize_bar_chart(chart)
             }
 
-            ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
-                Self::initialize_column_chart(chart)
-            }
+            ChartType::Column
+            | ChartType::ColumnStacked
+            | ChartType::ColumnPercentStacked
+            | ChartType::Column3D => Self::initialize_column_chart(chart),
 
             ChartType::Doughnut => Self::initialize_doughnut_chart(chart),
 
@@ -1019,6 +1031,8 @@ impl Chart {
             | ChartType::ScatterSmoothWithMarkers => Self::initialize_scatter_chart(chart),
 
             ChartType::Stock => Self::initialize_stock_chart(chart),
+
+            ChartType::Histogram | ChartType::Pareto => Self::initialize_chartex_chart(chart),
         }
     }
 
@@ -1121,6 +1135,28 @@ impl Chart {
         Self::new(ChartType::Stock)
     }
 
+    /// Create a new Histogram `Chart`.
+    ///
+    /// This is a syntactic shortcut for `Chart::new(ChartType::Histogram)` to
+    /// create a default Histogram chart.
+    ///
+    /// See [`Chart::new()`] for further details.
+    ///
+    pub fn new_histogram() -> Chart {
+        Self::new(ChartType::Histogram)
+    }
+
+    /// Create a new Pareto `Chart`.
+    ///
+    /// This is a syntactic shortcut for `Chart::new(ChartType::Pareto)` to
+    /// create a default Pareto chart.
+    ///
+    /// See [`Chart::new()`] for further details.
+    ///
+    pub fn new_pareto() -> Chart {
+        Self::new(ChartType::Pareto)
+    }
+
     /// Create and add a new chart series to a chart.
     ///
     /// Create and add a new chart series to a chart. The chart series
@@ -1280,6 +1316,126 @@ impl Chart {
         self
     }
 
+    /// Create and add a new chart series based on the columns of a worksheet
+    /// table.
+    ///
+    /// Create a chart series where the categories and values are taken from
+    /// the data rows of two columns of a [`Table`](crate::Table) that has
+    /// already been added to a worksheet with
+    /// [`Worksheet::add_table()`]. The series name is taken from the header of
+    /// the value column, if the table has a header row. This avoids having to
+    /// calculate the series ranges from the table dimensions.
+    ///
+    /// The table is identified by the name set with
+    /// [`Table::set_name()`](crate::Table::set_name).
+    ///
+    /// # Parameters
+    ///
+    /// * `worksheet` - The worksheet that contains the table.
+    /// * `table_name` - The name of the table.
+    /// * `category_col` - The zero indexed table column used for the series
+    ///   categories.
+    /// * `value_col` - The zero indexed table column used for the series
+    ///   values.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ParameterError`] - The table wasn't found in the
+    ///   worksheet or a column index is outside the table.
+    ///
+    /// # Examples
+    ///
+    /// An example of creating a chart series from the columns of a table.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_add_series_from_table.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Table, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the table.
+    /// #     worksheet.write_column(1, 0, ["North", "South", "East", "West"])?;
+    /// #     worksheet.write_column(1, 1, [50, 30, 40, 60])?;
+    /// #
+    ///     // Add a table over the data.
+    ///     let table = Table::new().set_name("Sales");
+    ///     worksheet.add_table(0, 0, 4, 1, &table)?;
+    ///
+    ///     // Create a chart with a series based on the table columns.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart.add_series_from_table(worksheet, "Sales", 0, 1)?;
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 3, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_series_from_table(
+        &mut self,
+        worksheet: &Worksheet,
+        table_name: &str,
+        category_col: ColNum,
+        value_col: ColNum,
+    ) -> Result<&mut ChartSeries, XlsxError> {
+        let sheet_name = worksheet.name();
+
+        let Some(table) = worksheet
+            .tables
+            .iter()
+            .find(|table| table.name == table_name)
+        else {
+            return Err(XlsxError::ParameterError(format!(
+                "Table '{table_name}' not found in worksheet '{sheet_name}'"
+            )));
+        };
+
+        let range = &table.cell_range;
+        let num_cols = range.last_col - range.first_col + 1;
+        if category_col >= num_cols || value_col >= num_cols {
+            return Err(XlsxError::ParameterError(format!(
+                "Column index outside the {num_cols} columns of table '{table_name}'"
+            )));
+        }
+
+        let first_row = table.first_data_row();
+        let last_row = table.last_data_row();
+        let category_col = range.first_col + category_col;
+        let value_col = range.first_col + value_col;
+        let header_row = range.first_row;
+        let has_header = table.show_header_row;
+
+        let series = self.add_series();
+        series
+            .set_categories((
+                sheet_name.as_str(),
+                first_row,
+                category_col,
+                last_row,
+                category_col,
+            ))
+            .set_values((
+                sheet_name.as_str(),
+                first_row,
+                value_col,
+                last_row,
+                value_col,
+            ));
+
+        if has_header {
+            series.set_name((sheet_name.as_str(), header_row, value_col));
+        }
+
+        Ok(series)
+    }
+
     /// Get the chart title object in order to set its properties.
     ///
     /// Get a reference to the chart's X-Axis [`ChartTitle`] object in order to
@@ -1379,6 +1535,8 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_axis_set_name.png">
     ///
     pub fn x_axis(&mut self) -> &mut ChartAxis {
+        self.warn_if_chartex("x_axis()");
+
         &mut self.x_axis
     }
 
@@ -1390,6 +1548,8 @@ impl Chart {
     /// See the [`chart.x_axis()`][Chart::x_axis] method above.
     ///
     pub fn y_axis(&mut self) -> &mut ChartAxis {
+        self.warn_if_chartex("y_axis()");
+
         &mut self.y_axis
     }
 
@@ -1401,6 +1561,8 @@ impl Chart {
     /// See the [`chart.x_axis()`][Chart::x_axis] method above.
     ///
     pub fn x2_axis(&mut self) -> &mut ChartAxis {
+        self.warn_if_chartex("x2_axis()");
+
         &mut self.x2_axis
     }
 
@@ -1412,6 +1574,8 @@ impl Chart {
     /// See the [`chart.x_axis()`][Chart::x_axis] method above.
     ///
     pub fn y2_axis(&mut self) -> &mut ChartAxis {
+        self.warn_if_chartex("y2_axis()");
+
         &mut self.y2_axis
     }
 
@@ -1467,9 +1631,64 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_legend.png">
     ///
     pub fn legend(&mut self) -> &mut ChartLegend {
+        self.warn_if_chartex("legend()");
+
         &mut self.legend
     }
 
+    /// Get the chart plot area object in order to set its properties.
+    ///
+    /// Get a reference to the chart's plot area [`ChartPlotArea`] object in
+    /// order to set its properties, such as a manual layout.
+    ///
+    /// See also [`Chart::set_plot_area_format()`] to set the formatting of the
+    /// plot area.
+    ///
+    /// # Examples
+    ///
+    /// An example of positioning the chart plot area manually.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_plot_area_set_layout.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 60)?;
+    /// #     worksheet.write(2, 0, 30)?;
+    /// #     worksheet.write(3, 0, 10)?;
+    /// #     worksheet.write(4, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     // Position the plot area manually within the chart area.
+    ///     chart.plot_area().set_layout(0.1, 0.2, 0.6, 0.6);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn plot_area(&mut self) -> &mut ChartPlotArea {
+        self.warn_if_chartex("plot_area()");
+
+        &mut self.plot_area
+    }
+
     /// Create a combined chart from two different chart types.
     ///
     /// In Excel is also possible to combine two different chart types, for
@@ -1699,6 +1918,8 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_styles.png">
     ///
     pub fn set_style(&mut self, style: u8) -> &mut Chart {
+        self.warn_if_chartex("set_style()");
+
         if (1..=48).contains(&style) {
             self.style = style;
         } else {
@@ -1786,6 +2007,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_chart_area_format()");
+
         self.chart_area_format = format.new_chart_format();
         self
     }
@@ -1868,6 +2091,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_plot_area_format()");
+
         self.plot_area_format = format.new_chart_format();
         self
     }
@@ -1926,6 +2151,8 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_set_rotation.png">
     ///
     pub fn set_rotation(&mut self, rotation: u16) -> &mut Chart {
+        self.warn_if_chartex("set_rotation()");
+
         if (0..=360).contains(&rotation) {
             self.rotation = rotation;
         }
@@ -1984,12 +2211,134 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_set_hole_size.png">
     ///
     pub fn set_hole_size(&mut self, hole_size: u8) -> &mut Chart {
+        self.warn_if_chartex("set_hole_size()");
+
         if (0..=90).contains(&hole_size) {
             self.hole_size = hole_size;
         }
         self
     }
 
+    /// Set the X rotation of a 3D chart.
+    ///
+    /// Set the rotation of a 3D chart around the horizontal X axis, i.e., the
+    /// angle of elevation of the view. This, along with the other 3D view
+    /// methods below, controls the viewing angle of a 3D chart such as
+    /// [`ChartType::Column3D`]. It is ignored for other chart types.
+    ///
+    /// # Parameters
+    ///
+    /// * `rotation`: The X rotation in degrees. The range is -90 <= `rotation`
+    ///   <= 90 and the default is 15.
+    ///
+    /// # Examples
+    ///
+    /// An example of setting the viewing angle of a 3D column chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_set_rotation_x.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column3D);
+    ///
+    ///     // Add a data series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Set the viewing angle of the chart.
+    ///     chart
+    ///         .set_rotation_x(30)
+    ///         .set_rotation_y(40)
+    ///         .set_right_angle_axes(false)
+    ///         .set_perspective(60);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_rotation_x(&mut self, rotation: i16) -> &mut Chart {
+        self.warn_if_chartex("set_rotation_x()");
+
+        if (-90..=90).contains(&rotation) {
+            self.rotation_x = Some(rotation);
+        }
+        self
+    }
+
+    /// Set the Y rotation of a 3D chart.
+    ///
+    /// Set the rotation of a 3D chart around the vertical Y axis. See
+    /// [`Chart::set_rotation_x()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// * `rotation`: The Y rotation in degrees. The range is 0 <= `rotation`
+    ///   <= 360 and the default is 20.
+    ///
+    pub fn set_rotation_y(&mut self, rotation: u16) -> &mut Chart {
+        self.warn_if_chartex("set_rotation_y()");
+
+        if (0..=360).contains(&rotation) {
+            self.rotation_y = Some(rotation);
+        }
+        self
+    }
+
+    /// Set the perspective of a 3D chart.
+    ///
+    /// Set the field of view of a 3D chart. Excel only applies the
+    /// perspective when right angle axes are turned off, see
+    /// [`Chart::set_right_angle_axes()`]. See [`Chart::set_rotation_x()`] for
+    /// an example.
+    ///
+    /// # Parameters
+    ///
+    /// * `perspective`: The perspective in units of half a degree. The range
+    ///   is 0 <= `perspective` <= 240 and the Excel default is 30.
+    ///
+    pub fn set_perspective(&mut self, perspective: u8) -> &mut Chart {
+        self.warn_if_chartex("set_perspective()");
+
+        if perspective <= 240 {
+            self.perspective = Some(perspective);
+        }
+        self
+    }
+
+    /// Set the right angle axes option of a 3D chart.
+    ///
+    /// Set whether the axes of a 3D chart are drawn at right angles,
+    /// independent of the chart rotation. This is on by default. It must be
+    /// turned off for [`Chart::set_perspective()`] to have an effect. See
+    /// [`Chart::set_rotation_x()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn set_right_angle_axes(&mut self, enable: bool) -> &mut Chart {
+        self.warn_if_chartex("set_right_angle_axes()");
+
+        self.right_angle_axes = Some(enable);
+        self
+    }
+
     /// Set Up-Down bar indicators for a Line chart.
     ///
     /// Set Up-Down bar indicator to indicate change between two or more series.
@@ -2052,6 +2401,8 @@ impl Chart {
     /// src="https://rustxlsxwriter.github.io/images/chart_set_up_down_bars.png">
     ///
     pub fn set_up_down_bars(&mut self, enable: bool) -> &mut Chart {
+        self.warn_if_chartex("set_up_down_bars()");
+
         self.has_up_down_bars = enable;
         self
     }
@@ -2134,6 +2485,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_up_bar_format()");
+
         self.has_up_down_bars = true;
         self.up_bar_format = format.new_chart_format();
         self
@@ -2148,6 +2501,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_down_bar_format()");
+
         self.has_up_down_bars = true;
         self.down_bar_format = format.new_chart_format();
         self
@@ -2216,6 +2571,8 @@ impl Chart {
     /// src="https://rustxlsxwriter.github.io/images/chart_set_high_low_lines.png">
     ///
     pub fn set_high_low_lines(&mut self, enable: bool) -> &mut Chart {
+        self.warn_if_chartex("set_high_low_lines()");
+
         self.has_high_low_lines = enable;
         self
     }
@@ -2291,6 +2648,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_high_low_lines_format()");
+
         self.has_high_low_lines = true;
         self.high_low_lines_format = format.new_chart_format();
         self
@@ -2358,6 +2717,8 @@ impl Chart {
     /// src="https://rustxlsxwriter.github.io/images/chart_set_drop_lines.png">
     ///
     pub fn set_drop_lines(&mut self, enable: bool) -> &mut Chart {
+        self.warn_if_chartex("set_drop_lines()");
+
         self.has_drop_lines = enable;
         self
     }
@@ -2433,6 +2794,8 @@ impl Chart {
     where
         T: IntoChartFormat,
     {
+        self.warn_if_chartex("set_drop_lines_format()");
+
         self.has_drop_lines = true;
         self.drop_lines_format = format.new_chart_format();
         self
@@ -2502,6 +2865,8 @@ impl Chart {
     /// src="https://rustxlsxwriter.github.io/images/chart_set_data_table.png">
     ///
     pub fn set_data_table(&mut self, table: &ChartDataTable) -> &mut Chart {
+        self.warn_if_chartex("set_data_table()");
+
         self.table = Some(table.clone());
         self
     }
@@ -2727,6 +3092,28 @@ impl Chart {
             ));
         }
 
+        // Check for Histogram/Pareto charts with more than one series or a
+        // combined chart, which aren't supported by Excel.
+        if self.is_chartex() {
+            if self.series.len() > 1 {
+                return Err(XlsxError::ChartError(
+                    "Histogram and Pareto charts can only contain one series".to_string(),
+                ));
+            }
+
+            if self.combined_chart.is_some() {
+                return Err(XlsxError::ChartError(
+                    "Histogram and Pareto charts cannot be combined with other charts".to_string(),
+                ));
+            }
+
+            for series in &self.series {
+                for option in series.chartex_unsupported_options() {
+                    self.warn_if_chartex(option);
+                }
+            }
+        }
+
         for series in &self.series {
             // Check for a series without a values range.
             if !series.value_range.has_data() {
@@ -2743,6 +3130,17 @@ impl Chart {
                 ));
             }
 
+            // Check for a series name range that couldn't be parsed.
+            if series.title.name.is_empty()
+                && !series.title.range.range_string.is_empty()
+                && !series.title.range.has_data()
+            {
+                return Err(XlsxError::ChartError(format!(
+                    "Chart series name range '{}' isn't a valid cell reference",
+                    series.title.range.range_string
+                )));
+            }
+
             // Validate the series values range.
             series.value_range.validate()?;
 
@@ -2790,6 +3188,8 @@ impl Chart {
     /// `option` - A [`ChartEmptyCells`] enum value.
     ///
     pub fn show_empty_cells_as(&mut self, option: ChartEmptyCells) -> &mut Chart {
+        self.warn_if_chartex("show_empty_cells_as()");
+
         self.show_empty_cells_as = Some(option);
 
         self
@@ -2798,6 +3198,8 @@ impl Chart {
     /// Display #N/A on charts as blank/empty cells.
     ///
     pub fn show_na_as_empty_cell(&mut self) -> &mut Chart {
+        self.warn_if_chartex("show_na_as_empty_cell()");
+
         self.show_na_as_empty = true;
 
         self
@@ -2806,6 +3208,8 @@ impl Chart {
     /// Display data on charts from hidden rows or columns.
     ///
     pub fn show_hidden_data(&mut self) -> &mut Chart {
+        self.warn_if_chartex("show_hidden_data()");
+
         self.show_hidden_data = true;
 
         self
@@ -2845,6 +3249,20 @@ impl Chart {
     // Crate level helper methods.
     // -----------------------------------------------------------------------
 
+    // Check if the chart is one of the newer chart types, such as Histogram,
+    // that are written in the chartex format.
+    pub(crate) fn is_chartex(&self) -> bool {
+        matches!(self.chart_type, ChartType::Histogram | ChartType::Pareto)
+    }
+
+    // Warn about chart options that aren't written for chartex charts, such as
+    // Histogram and Pareto, and which are therefore ignored.
+    fn warn_if_chartex(&self, option: &str) {
+        if self.is_chartex() {
+            eprintln!("Chart option '{option}' is ignored for Histogram/Pareto charts.");
+        }
+    }
+
     // Set chart unique axis ids.
     pub(crate) fn add_axis_ids(&mut self, chart_id: u32) {
         if self.axis_ids.0 != 0 {
@@ -3020,7 +3438,7 @@ impl Chart {
 
         self.chart_group_type = ChartType::Column;
 
-        if self.chart_type == ChartType::Column {
+        if self.chart_type == ChartType::Column || self.chart_type == ChartType::Column3D {
             self.grouping = ChartGrouping::Clustered;
         } else if self.chart_type == ChartType::ColumnStacked {
             self.grouping = ChartGrouping::Stacked;
@@ -3163,6 +3581,14 @@ impl Chart {
         self
     }
 
+    // Initialize Histogram and Pareto charts. These are written in the chartex
+    // format and don't use the standard chart axes.
+    fn initialize_chartex_chart(mut self) -> Chart {
+        self.drawing_type = DrawingType::ChartEx;
+
+        self
+    }
+
     // Write the <c:areaChart> element for Column charts.
     fn write_area_chart(&mut self, primary_axis: bool) {
         let series = self.get_series(primary_axis);
@@ -3223,7 +3649,7 @@ impl Chart {
         self.writer.xml_end_tag("c:barChart");
     }
 
-    // Write the <c:barChart> element for Column charts.
+    // Write the <c:barChart> or <c:bar3DChart> element for Column charts.
     fn write_column_chart(&mut self, primary_axis: bool) {
         let series = self.get_series(primary_axis);
 
@@ -3231,7 +3657,13 @@ impl Chart {
             return;
         }
 
-        self.writer.xml_start_tag_only("c:barChart");
+        let tag = if self.chart_type == ChartType::Column3D {
+            "c:bar3DChart"
+        } else {
+            "c:barChart"
+        };
+
+        self.writer.xml_start_tag_only(tag);
 
         // Write the c:barDir element.
         self.write_bar_dir("col");
@@ -3247,13 +3679,23 @@ impl Chart {
             self.write_gap_width(self.gap);
         }
 
-        // Write the c:overlap element.
-        self.write_overlap();
+        if self.chart_type == ChartType::Column3D {
+            // Write the c:shape element.
+            self.write_shape();
 
-        // Write the c:axId elements.
-        self.write_ax_ids(primary_axis);
+            // Write the c:axId elements. Clustered 3D charts don't have a
+            // series axis so the third id is 0.
+            self.write_ax_ids(primary_axis);
+            self.write_ax_id(0);
+        } else {
+            // Write the c:overlap element.
+            self.write_overlap();
 
-        self.writer.xml_end_tag("c:barChart");
+            // Write the c:axId elements.
+            self.write_ax_ids(primary_axis);
+        }
+
+        self.writer.xml_end_tag(tag);
     }
 
     // Write the <c:doughnutChart> element for Column charts.
@@ -3427,6 +3869,12 @@ impl Chart {
 
     // Assemble and write the XML file.
     pub(crate) fn assemble_xml_file(&mut self) {
+        // Histogram and Pareto charts use a different file structure.
+        if self.is_chartex() {
+            self.assemble_chartex_xml_file();
+            return;
+        }
+
         self.writer.xml_declaration();
 
         // Write the c:chartSpace element.
@@ -3446,8 +3894,11 @@ impl Chart {
         // Write the c:spPr element.
         self.write_sp_pr(&self.chart_area_format.clone());
 
-        // Write the c:printSettings element.
-        self.write_print_settings();
+        // Write the c:printSettings element. Chartsheets use the page setup of
+        // the sheet instead.
+        if !self.is_chartsheet {
+            self.write_print_settings();
+        }
 
         // Close the c:chartSpace tag.
         self.writer.xml_end_tag("c:chartSpace");
@@ -3491,6 +3942,11 @@ impl Chart {
             self.write_chart_title(&self.title.clone());
         }
 
+        // Write the c:view3D element.
+        if self.chart_type == ChartType::Column3D {
+            self.write_view_3d();
+        }
+
         // Write the c:plotArea element.
         self.write_plot_area();
 
@@ -3539,7 +3995,10 @@ impl Chart {
         self.writer.xml_start_tag_only("c:plotArea");
 
         // Write the c:layout element.
-        self.write_layout();
+        match &self.plot_area.layout {
+            Some(layout) => self.write_manual_layout(&layout.clone(), true),
+            None => self.write_layout(),
+        }
 
         // Write the <c:xxxChart> element for each chart type.
         self.write_chart_type();
@@ -3657,7 +4116,10 @@ impl Chart {
                 self.write_bar_chart(false);
             }
 
-            ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
+            ChartType::Column
+            | ChartType::ColumnStacked
+            | ChartType::ColumnPercentStacked
+            | ChartType::Column3D => {
                 self.write_column_chart(true);
                 self.write_column_chart(false);
             }
@@ -3695,6 +4157,9 @@ impl Chart {
                 self.write_stock_chart(true);
                 self.write_stock_chart(false);
             }
+
+            // Chartex charts are written separately.
+            ChartType::Histogram | ChartType::Pareto => {}
         }
     }
 
@@ -3703,6 +4168,46 @@ impl Chart {
         self.writer.xml_empty_tag_only("c:layout");
     }
 
+    // Write the <c:layout> element for a title, which may have a manual layout.
+    fn write_title_layout(&mut self, title: &ChartTitle) {
+        match &title.layout {
+            Some(layout) => self.write_manual_layout(layout, false),
+            None => self.write_layout(),
+        }
+    }
+
+    // Write the <c:layout> element with a <c:manualLayout> child element.
+    fn write_manual_layout(&mut self, layout: &ChartLayout, is_inner: bool) {
+        self.writer.xml_start_tag_only("c:layout");
+        self.writer.xml_start_tag_only("c:manualLayout");
+
+        // The plot area layout is relative to the inner plot area, excluding
+        // the tick labels.
+        if is_inner {
+            self.writer
+                .xml_empty_tag("c:layoutTarget", &[("val", "inner")]);
+        }
+
+        self.writer.xml_empty_tag("c:xMode", &[("val", "edge")]);
+        self.writer.xml_empty_tag("c:yMode", &[("val", "edge")]);
+        self.writer
+            .xml_empty_tag("c:x", &[("val", layout.x.to_string())]);
+        self.writer
+            .xml_empty_tag("c:y", &[("val", layout.y.to_string())]);
+
+        if let Some(width) = layout.width {
+            self.writer
+                .xml_empty_tag("c:w", &[("val", width.to_string())]);
+        }
+        if let Some(height) = layout.height {
+            self.writer
+                .xml_empty_tag("c:h", &[("val", height.to_string())]);
+        }
+
+        self.writer.xml_end_tag("c:manualLayout");
+        self.writer.xml_end_tag("c:layout");
+    }
+
     // Write the <c:barDir> element.
     fn write_bar_dir(&mut self, direction: &str) {
         let attributes = [("val", direction.to_string())];
@@ -4366,6 +4871,11 @@ impl Chart {
             self.write_tick_mark_skip(x_axis.tick_interval);
         }
 
+        // Write the c:baseTimeUnit element.
+        if let Some(unit) = x_axis.base_unit_date_type {
+            self.write_base_time_unit(unit);
+        }
+
         // Write the c:majorUnit element.
         if !x_axis.major_unit.is_empty() {
             self.write_major_unit(&x_axis.major_unit);
@@ -4779,6 +5289,13 @@ impl Chart {
         self.writer.xml_empty_tag("c:minorUnit", &attributes);
     }
 
+    // Write the <c:baseTimeUnit> element.
+    fn write_base_time_unit(&mut self, units: ChartAxisDateUnitType) {
+        let attributes = [("val", units.to_string())];
+
+        self.writer.xml_empty_tag("c:baseTimeUnit", &attributes);
+    }
+
     // Write the <c:majorTimeUnit> element.
     fn write_major_time_unit(&mut self, units: ChartAxisDateUnitType) {
         let attributes = [("val", units.to_string())];
@@ -4848,7 +5365,10 @@ impl Chart {
         }
 
         // Write the c:layout element.
-        self.write_layout();
+        match &self.legend.layout {
+            Some(layout) => self.write_manual_layout(&layout.clone(), false),
+            None => self.write_layout(),
+        }
 
         // Write the c:spPr formatting element.
         self.write_sp_pr(&self.legend.format.clone());
@@ -6195,6 +6715,43 @@ impl Chart {
         self.writer.xml_empty_tag("c:style", &attributes);
     }
 
+    // Write the <c:view3D> element.
+    fn write_view_3d(&mut self) {
+        self.writer.xml_start_tag_only("c:view3D");
+
+        // Write the c:rotX element.
+        let rotation_x = self.rotation_x.unwrap_or(15);
+        self.writer
+            .xml_empty_tag("c:rotX", &[("val", rotation_x.to_string())]);
+
+        // Write the c:rotY element.
+        let rotation_y = self.rotation_y.unwrap_or(20);
+        self.writer
+            .xml_empty_tag("c:rotY", &[("val", rotation_y.to_string())]);
+
+        // Write the c:rAngAx element.
+        let right_angle_axes = self.right_angle_axes.unwrap_or(true);
+        self.writer.xml_empty_tag(
+            "c:rAngAx",
+            &[("val", u8::from(right_angle_axes).to_string())],
+        );
+
+        // Write the c:perspective element.
+        if let Some(perspective) = self.perspective {
+            self.writer
+                .xml_empty_tag("c:perspective", &[("val", perspective.to_string())]);
+        }
+
+        self.writer.xml_end_tag("c:view3D");
+    }
+
+    // Write the <c:shape> element.
+    fn write_shape(&mut self) {
+        let attributes = [("val", "box")];
+
+        self.writer.xml_empty_tag("c:shape", &attributes);
+    }
+
     // Write the <c:autoTitleDeleted> element.
     fn write_auto_title_deleted(&mut self) {
         let attributes = [("val", "1")];
@@ -6210,7 +6767,7 @@ impl Chart {
         self.write_tx_formula(title);
 
         // Write the c:layout element.
-        self.write_layout();
+        self.write_title_layout(title);
 
         if title.format.has_formatting() {
             // Write the c:spPr formatting element.
@@ -6241,7 +6798,7 @@ impl Chart {
         self.write_tx_rich(title);
 
         // Write the c:layout element.
-        self.write_layout();
+        self.write_title_layout(title);
 
         if title.format.has_formatting() {
             // Write the c:spPr element.
@@ -6256,7 +6813,7 @@ impl Chart {
         self.writer.xml_start_tag_only("c:title");
 
         // Write the c:layout element.
-        self.write_layout();
+        self.write_title_layout(title);
 
         // Write the c:spPr element.
         self.write_sp_pr(&title.format.clone());
@@ -6337,41 +6894,372 @@ impl Chart {
         // Write the a:rPr element.
         self.write_a_r_pr(&title.font);
 
-        // Write the a:t element.
-        self.write_a_t(&title.name);
+        // Write the a:t element.
+        self.write_a_t(&title.name);
+
+        self.writer.xml_end_tag("a:r");
+    }
+
+    // Write the <c:dispBlanksAs> element.
+    fn write_disp_blanks_as(&mut self) {
+        if let Some(show_empty_cells) = self.show_empty_cells_as {
+            let attributes = [("val", show_empty_cells.to_string())];
+
+            self.writer.xml_empty_tag("c:dispBlanksAs", &attributes);
+        }
+    }
+
+    // Write the <dispNaAsBlank> element. This is an Excel 16 extension.
+    fn write_disp_na_as_blank(&mut self) {
+        let attributes = [
+            ("uri", "{56B9EC1D-385E-4148-901F-78D8002777C0}"),
+            (
+                "xmlns:c16r3",
+                "http://schemas.microsoft.com/office/drawing/2017/03/chart",
+            ),
+        ];
+
+        self.writer.xml_start_tag_only("c:extLst");
+        self.writer.xml_start_tag("c:ext", &attributes);
+        self.writer.xml_start_tag_only("c16r3:dataDisplayOptions16");
+
+        self.writer
+            .xml_empty_tag("c16r3:dispNaAsBlank", &[("val", "1")]);
+
+        self.writer.xml_end_tag("c16r3:dataDisplayOptions16");
+        self.writer.xml_end_tag("c:ext");
+        self.writer.xml_end_tag("c:extLst");
+    }
+
+    // -----------------------------------------------------------------------
+    // Chartex XML assembly methods. Only for Histogram and Pareto charts.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file for chartex charts.
+    fn assemble_chartex_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the cx:chartSpace element.
+        self.write_cx_chart_space();
+
+        // Write the cx:chartData element.
+        self.write_cx_chart_data();
+
+        // Write the cx:chart element.
+        self.write_cx_chart();
+
+        // Close the cx:chartSpace tag.
+        self.writer.xml_end_tag("cx:chartSpace");
+    }
+
+    // Write the <cx:chartSpace> element.
+    fn write_cx_chart_space(&mut self) {
+        let attributes = [
+            (
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+            (
+                "xmlns:cx",
+                "http://schemas.microsoft.com/office/drawing/2014/chartex",
+            ),
+        ];
+
+        self.writer.xml_start_tag("cx:chartSpace", &attributes);
+    }
+
+    // Write the <cx:chartData> element.
+    fn write_cx_chart_data(&mut self) {
+        self.writer.xml_start_tag_only("cx:chartData");
+
+        for (index, series) in self.series.clone().iter().enumerate() {
+            // Write the cx:data element.
+            self.write_cx_data(index, series);
+        }
+
+        self.writer.xml_end_tag("cx:chartData");
+    }
+
+    // Write the <cx:data> element.
+    fn write_cx_data(&mut self, index: usize, series: &ChartSeries) {
+        let attributes = [("id", index.to_string())];
+
+        self.writer.xml_start_tag("cx:data", &attributes);
+
+        // Write the cx:strDim element for Pareto categories.
+        if self.chart_type == ChartType::Pareto && series.category_range.has_data() {
+            self.write_cx_str_dim(&series.category_range);
+        }
+
+        // Write the cx:numDim element.
+        self.write_cx_num_dim(&series.value_range);
+
+        self.writer.xml_end_tag("cx:data");
+    }
+
+    // Write the <cx:strDim> element.
+    fn write_cx_str_dim(&mut self, range: &ChartRange) {
+        let attributes = [("type", "cat")];
+
+        self.writer.xml_start_tag("cx:strDim", &attributes);
+
+        // Write the cx:f element.
+        self.writer
+            .xml_data_element_only("cx:f", &range.formula_abs());
+
+        // Write the cx:lvl element.
+        if range.cache.has_data() {
+            let attributes = [("ptCount", range.cache.data.len().to_string())];
+
+            self.writer.xml_start_tag("cx:lvl", &attributes);
+
+            for (index, value) in range.cache.data.iter().enumerate() {
+                let attributes = [("idx", index.to_string())];
+                self.writer.xml_data_element("cx:pt", value, &attributes);
+            }
+
+            self.writer.xml_end_tag("cx:lvl");
+        }
+
+        self.writer.xml_end_tag("cx:strDim");
+    }
+
+    // Write the <cx:numDim> element.
+    fn write_cx_num_dim(&mut self, range: &ChartRange) {
+        let attributes = [("type", "val")];
+
+        self.writer.xml_start_tag("cx:numDim", &attributes);
+
+        // Write the cx:f element.
+        self.writer
+            .xml_data_element_only("cx:f", &range.formula_abs());
+
+        // Write the cx:lvl element.
+        if range.cache.has_data() {
+            let attributes = [
+                ("ptCount", range.cache.data.len().to_string()),
+                ("formatCode", "General".to_string()),
+            ];
+
+            self.writer.xml_start_tag("cx:lvl", &attributes);
+
+            // Blank and non-numeric values are omitted so that they aren't
+            // counted in the bins.
+            for (index, value) in range.cache.data.iter().enumerate() {
+                if value.parse::<f64>().is_ok() {
+                    let attributes = [("idx", index.to_string())];
+                    self.writer.xml_data_element("cx:pt", value, &attributes);
+                }
+            }
+
+            self.writer.xml_end_tag("cx:lvl");
+        }
+
+        self.writer.xml_end_tag("cx:numDim");
+    }
+
+    // Write the <cx:chart> element.
+    fn write_cx_chart(&mut self) {
+        self.writer.xml_start_tag_only("cx:chart");
+
+        // Write the cx:title element.
+        if !self.title.hidden && (!self.title.name.is_empty() || self.title.range.has_data()) {
+            self.write_cx_title(&self.title.clone());
+        }
+
+        // Write the cx:plotArea element.
+        self.write_cx_plot_area();
+
+        self.writer.xml_end_tag("cx:chart");
+    }
+
+    // Write the <cx:title> element.
+    fn write_cx_title(&mut self, title: &ChartTitle) {
+        let attributes = [("pos", "t"), ("align", "ctr"), ("overlay", "0")];
+
+        self.writer.xml_start_tag("cx:title", &attributes);
+
+        // Write the cx:tx element.
+        self.write_cx_tx(title);
+
+        self.writer.xml_end_tag("cx:title");
+    }
+
+    // Write the <cx:tx> element for a title or series name.
+    fn write_cx_tx(&mut self, title: &ChartTitle) {
+        self.writer.xml_start_tag_only("cx:tx");
+        self.writer.xml_start_tag_only("cx:txData");
+
+        if title.range.has_data() {
+            // Write the cx:f element.
+            self.writer
+                .xml_data_element_only("cx:f", &title.range.formula_abs());
+
+            // Write the cx:v element with the cached string.
+            if let Some(value) = title.range.cache.data.first() {
+                self.writer.xml_data_element_only("cx:v", value);
+            }
+        } else {
+            // Write the cx:v element.
+            self.writer.xml_data_element_only("cx:v", &title.name);
+        }
+
+        self.writer.xml_end_tag("cx:txData");
+        self.writer.xml_end_tag("cx:tx");
+    }
+
+    // Write the <cx:plotArea> element.
+    fn write_cx_plot_area(&mut self) {
+        self.writer.xml_start_tag_only("cx:plotArea");
+        self.writer.xml_start_tag_only("cx:plotAreaRegion");
+
+        for (index, series) in self.series.clone().iter().enumerate() {
+            // Write the cx:series element.
+            self.write_cx_series(index, series);
+
+            // Write the cx:series element for the Pareto cumulative line.
+            if self.chart_type == ChartType::Pareto {
+                self.write_cx_pareto_line(index);
+            }
+        }
+
+        self.writer.xml_end_tag("cx:plotAreaRegion");
+
+        // Write the cx:axis elements.
+        self.write_cx_cat_axis();
+        self.write_cx_val_axis();
+
+        if self.chart_type == ChartType::Pareto {
+            self.write_cx_percentage_axis();
+        }
+
+        self.writer.xml_end_tag("cx:plotArea");
+    }
+
+    // Write the <cx:series> element.
+    fn write_cx_series(&mut self, index: usize, series: &ChartSeries) {
+        let attributes = [
+            ("layoutId", "clusteredColumn".to_string()),
+            ("uniqueId", Self::cx_unique_id(index, 0)),
+        ];
+
+        self.writer.xml_start_tag("cx:series", &attributes);
+
+        // Write the cx:tx element.
+        if !series.title.name.is_empty() || series.title.range.has_data() {
+            self.write_cx_tx(&series.title);
+        }
+
+        // Write the cx:dataId element.
+        let attributes = [("val", index.to_string())];
+        self.writer.xml_empty_tag("cx:dataId", &attributes);
+
+        // Write the cx:layoutPr element.
+        self.writer.xml_start_tag_only("cx:layoutPr");
+
+        if self.chart_type == ChartType::Pareto && series.category_range.has_data() {
+            self.writer.xml_empty_tag_only("cx:aggregation");
+        } else {
+            self.write_cx_binning(series.binning);
+        }
+
+        self.writer.xml_end_tag("cx:layoutPr");
+
+        // Write the cx:axisId element. Pareto charts have a secondary axis.
+        if self.chart_type == ChartType::Pareto {
+            let attributes = [("val", "1")];
+            self.writer.xml_empty_tag("cx:axisId", &attributes);
+        }
+
+        self.writer.xml_end_tag("cx:series");
+    }
+
+    // Write the <cx:series> element for the Pareto cumulative line.
+    fn write_cx_pareto_line(&mut self, index: usize) {
+        let attributes = [
+            ("layoutId", "paretoLine".to_string()),
+            ("ownerIdx", index.to_string()),
+            ("uniqueId", Self::cx_unique_id(index, 1)),
+        ];
+
+        self.writer.xml_start_tag("cx:series", &attributes);
+
+        // Write the cx:axisId element.
+        let attributes = [("val", "2")];
+        self.writer.xml_empty_tag("cx:axisId", &attributes);
+
+        self.writer.xml_end_tag("cx:series");
+    }
+
+    // Write the <cx:binning> element.
+    fn write_cx_binning(&mut self, binning: ChartBinning) {
+        let attributes = [("intervalClosed", "r")];
+
+        match binning {
+            ChartBinning::Automatic => {
+                self.writer.xml_empty_tag("cx:binning", &attributes);
+            }
+            ChartBinning::Count(count) => {
+                self.writer.xml_start_tag("cx:binning", &attributes);
+                self.writer
+                    .xml_empty_tag("cx:binCount", &[("val", count.to_string())]);
+                self.writer.xml_end_tag("cx:binning");
+            }
+            ChartBinning::Width(width) => {
+                self.writer.xml_start_tag("cx:binning", &attributes);
+                self.writer
+                    .xml_empty_tag("cx:binSize", &[("val", width.to_string())]);
+                self.writer.xml_end_tag("cx:binning");
+            }
+        }
+    }
+
+    // Write the <cx:axis> element for the category axis.
+    fn write_cx_cat_axis(&mut self) {
+        self.writer.xml_start_tag("cx:axis", &[("id", "0")]);
+
+        self.writer
+            .xml_empty_tag("cx:catScaling", &[("gapWidth", "0")]);
+        self.writer.xml_empty_tag_only("cx:tickLabels");
 
-        self.writer.xml_end_tag("a:r");
+        self.writer.xml_end_tag("cx:axis");
     }
 
-    // Write the <c:dispBlanksAs> element.
-    fn write_disp_blanks_as(&mut self) {
-        if let Some(show_empty_cells) = self.show_empty_cells_as {
-            let attributes = [("val", show_empty_cells.to_string())];
+    // Write the <cx:axis> element for the value axis.
+    fn write_cx_val_axis(&mut self) {
+        self.writer.xml_start_tag("cx:axis", &[("id", "1")]);
 
-            self.writer.xml_empty_tag("c:dispBlanksAs", &attributes);
-        }
-    }
+        self.writer.xml_empty_tag_only("cx:valScaling");
+        self.writer.xml_empty_tag_only("cx:majorGridlines");
+        self.writer.xml_empty_tag_only("cx:tickLabels");
 
-    // Write the <dispNaAsBlank> element. This is an Excel 16 extension.
-    fn write_disp_na_as_blank(&mut self) {
-        let attributes = [
-            ("uri", "{56B9EC1D-385E-4148-901F-78D8002777C0}"),
-            (
-                "xmlns:c16r3",
-                "http://schemas.microsoft.com/office/drawing/2017/03/chart",
-            ),
-        ];
+        self.writer.xml_end_tag("cx:axis");
+    }
 
-        self.writer.xml_start_tag_only("c:extLst");
-        self.writer.xml_start_tag("c:ext", &attributes);
-        self.writer.xml_start_tag_only("c16r3:dataDisplayOptions16");
+    // Write the <cx:axis> element for the Pareto cumulative percentage axis.
+    fn write_cx_percentage_axis(&mut self) {
+        self.writer.xml_start_tag("cx:axis", &[("id", "2")]);
 
         self.writer
-            .xml_empty_tag("c16r3:dispNaAsBlank", &[("val", "1")]);
+            .xml_empty_tag("cx:valScaling", &[("max", "1"), ("min", "0")]);
+        self.writer
+            .xml_empty_tag("cx:units", &[("unit", "percentage")]);
+        self.writer.xml_empty_tag_only("cx:tickLabels");
 
-        self.writer.xml_end_tag("c16r3:dataDisplayOptions16");
-        self.writer.xml_end_tag("c:ext");
-        self.writer.xml_end_tag("c:extLst");
+        self.writer.xml_end_tag("cx:axis");
+    }
+
+    // Create a unique id for a chartex series. Excel uses GUIDs but any unique
+    // value in the same format is accepted.
+    fn cx_unique_id(index: usize, sub_index: usize) -> String {
+        format!(
+            "{{{:08X}-{sub_index:04X}-0000-0000-000000000000}}",
+            index + 1
+        )
     }
 }
 
@@ -6653,6 +7541,7 @@ pub struct ChartSeries {
     pub(crate) delete_from_legend: bool,
     pub(crate) smooth: Option<bool>,
     pub(crate) secondary_axis: bool,
+    pub(crate) binning: ChartBinning,
 }
 
 #[allow(clippy::new_without_default)]
@@ -6769,6 +7658,7 @@ impl ChartSeries {
             delete_from_legend: false,
             smooth: None,
             secondary_axis: false,
+            binning: ChartBinning::Automatic,
         }
     }
 
@@ -7009,6 +7899,10 @@ impl ChartSeries {
     /// 1..n`. The name can be a simple string, a formula such as `Sheet1!$A$1`
     /// or a tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
     ///
+    /// A string that looks like a cell reference is treated as a range. Use
+    /// [`ChartSeries::set_name_literal()`] or [`ChartSeries::set_name_range()`]
+    /// to always set the name as a string or as a range.
+    ///
     /// # Parameters
     ///
     /// * `range` - The range property which can be one of the following generic
@@ -7101,6 +7995,51 @@ impl ChartSeries {
         self
     }
 
+    /// Set the name for a chart series from a cell range.
+    ///
+    /// The [`ChartSeries::set_name()`] method will treat a string that looks
+    /// like a cell reference as a range and any other string as a literal
+    /// name. The `set_name_range()` method always treats the name as a
+    /// reference to a worksheet cell, so that the series name updates when the
+    /// cell changes. If the string can't be parsed as a range an error will be
+    /// raised when the chart is inserted into a worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// * `range` - The range property which can be one of the following generic
+    ///   types:
+    ///    - A string with an Excel like range formula such as `"Sheet1!$A$1"`.
+    ///    - A tuple that can be used to create the range programmatically using
+    ///      a sheet name and zero indexed row and column values like:
+    ///      `("Sheet1", 0, 0)` (this gives the same range as the previous
+    ///      string value).
+    ///
+    pub fn set_name_range<T>(&mut self, range: T) -> &mut ChartSeries
+    where
+        T: IntoChartRange,
+    {
+        self.title.range = range.new_chart_range();
+        self.title.name = String::new();
+        self
+    }
+
+    /// Set the name for a chart series as a literal string.
+    ///
+    /// The `set_name_literal()` method is similar to
+    /// [`ChartSeries::set_name()`] except that the name is always written as
+    /// a literal string, even if it looks like a cell reference such as
+    /// `"Q1!A1"`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The series name.
+    ///
+    pub fn set_name_literal(&mut self, name: impl Into<String>) -> &mut ChartSeries {
+        self.title.range = ChartRange::default();
+        self.title.name = name.into();
+        self
+    }
+
     /// Set the formatting properties for a chart series.
     ///
     /// Set the formatting properties for a chart series via a [`ChartFormat`]
@@ -8002,6 +8941,94 @@ impl ChartSeries {
         self
     }
 
+    /// Set the number of bins for a Histogram or Pareto chart.
+    ///
+    /// By default Excel chooses the bins for a [`ChartType::Histogram`] chart
+    /// automatically from the data. This method sets a fixed number of bins of
+    /// equal width instead. It overrides any bin width set via
+    /// [`ChartSeries::set_bin_width()`].
+    ///
+    /// Note, this property only applies to Histogram charts and to Pareto
+    /// charts that don't have a categories range.
+    ///
+    /// # Parameters
+    ///
+    /// * `count`: The number of bins. It must be greater than 0.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating a Histogram chart with a fixed number of
+    /// bins.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_bin_count.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     let data = [2, 3, 3, 4, 5, 5, 5, 6, 6, 7, 8, 9, 9, 10, 12];
+    /// #     worksheet.write(0, 0, "Scores")?;
+    /// #     worksheet.write_column(1, 0, data)?;
+    /// #
+    ///     // Create a new Histogram chart.
+    ///     let mut chart = Chart::new(ChartType::Histogram);
+    ///
+    ///     // Add a data series and group the values into 5 bins.
+    ///     chart
+    ///         .add_series()
+    ///         .set_name("Sheet1!$A$1")
+    ///         .set_values("Sheet1!$A$2:$A$16")
+    ///         .set_bin_count(5);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_bin_count(&mut self, count: u16) -> &mut ChartSeries {
+        if count == 0 {
+            eprintln!("Chart series bin count must be > 0 in Excel");
+            return self;
+        }
+
+        self.binning = ChartBinning::Count(count);
+        self
+    }
+
+    /// Set the width of the bins for a Histogram or Pareto chart.
+    ///
+    /// By default Excel chooses the bins for a [`ChartType::Histogram`] chart
+    /// automatically from the data. This method sets a fixed bin width
+    /// instead. It overrides any bin count set via
+    /// [`ChartSeries::set_bin_count()`].
+    ///
+    /// Note, this property only applies to Histogram charts and to Pareto
+    /// charts that don't have a categories range.
+    ///
+    /// # Parameters
+    ///
+    /// * `width`: The width of each bin in the units of the data. It must be
+    ///   greater than 0.0.
+    ///
+    pub fn set_bin_width(&mut self, width: f64) -> &mut ChartSeries {
+        if width <= 0.0 {
+            eprintln!("Chart series bin width '{width}' must be > 0.0 in Excel");
+            return self;
+        }
+
+        self.binning = ChartBinning::Width(width);
+        self
+    }
+
     /// Set line type charts to smooth for a series.
     ///
     /// Line and Scatter charts can have a linear or smoothed line connecting
@@ -8245,6 +9272,57 @@ impl ChartSeries {
         self.delete_from_legend = enable;
         self
     }
+
+    // Get the names of any series options that have been set but which aren't
+    // written for chartex charts such as Histogram and Pareto.
+    pub(crate) fn chartex_unsupported_options(&self) -> Vec<&'static str> {
+        let mut options = vec![];
+
+        if self.format.has_formatting() {
+            options.push("set_format()");
+        }
+        if self.marker.is_some() {
+            options.push("set_marker()");
+        }
+        if self.data_label.is_some() {
+            options.push("set_data_label()");
+        }
+        if !self.custom_data_labels.is_empty() {
+            options.push("set_custom_data_labels()");
+        }
+        if !self.points.is_empty() {
+            options.push("set_points()");
+        }
+        if self.trendline.trend_type != ChartTrendlineType::None {
+            options.push("set_trendline()");
+        }
+        if self.y_error_bars.is_some() {
+            options.push("set_y_error_bars()");
+        }
+        if self.x_error_bars.is_some() {
+            options.push("set_x_error_bars()");
+        }
+        if self.overlap.is_some() {
+            options.push("set_overlap()");
+        }
+        if self.gap != 150 {
+            options.push("set_gap()");
+        }
+        if self.smooth.is_some() {
+            options.push("set_smooth()");
+        }
+        if self.invert_if_negative {
+            options.push("set_invert_if_negative()");
+        }
+        if self.secondary_axis {
+            options.push("set_secondary_axis()");
+        }
+        if self.delete_from_legend {
+            options.push("delete_from_legend()");
+        }
+
+        options
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -8560,10 +9638,13 @@ pub(crate) enum ChartRangeCacheDataType {
 ///
 /// The main original chart types are supported, see below.
 ///
-/// Support for newer Excel chart types such as Treemap, Sunburst, Box and
-/// Whisker, Statistical Histogram, Waterfall, Funnel and Maps is not currently
-/// planned since the underlying structure is substantially different from the
-/// implemented chart types.
+/// The newer Excel 2016 statistical Histogram and Pareto chart types are also
+/// supported. These are written in the Excel "chartex" format and have a
+/// smaller set of options than the original chart types, see
+/// [`ChartType::Histogram`] and [`ChartType::Pareto`].
+///
+/// Support for other newer Excel chart types such as Treemap, Sunburst, Box
+/// and Whisker, Waterfall, Funnel and Maps is not currently planned.
 ///
 pub enum ChartType {
     /// An Area chart type.
@@ -8611,6 +9692,13 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_column_percent_stacked.png">
     ColumnPercentStacked,
 
+    /// A clustered 3D Column chart type.
+    ///
+    /// The 3D viewing angle can be adjusted with [`Chart::set_rotation_x()`],
+    /// [`Chart::set_rotation_y()`], [`Chart::set_perspective()`] and
+    /// [`Chart::set_right_angle_axes()`].
+    Column3D,
+
     /// A Doughnut chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_doughnut.png">
@@ -8689,6 +9777,41 @@ pub enum ChartType {
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_stock.png">
     Stock,
+
+    /// A statistical Histogram chart type.
+    ///
+    /// A Histogram groups the values of a single data series into bins and
+    /// shows the frequency of each bin as a column. By default Excel chooses
+    /// the bins automatically. The number or width of the bins can be set with
+    /// [`ChartSeries::set_bin_count()`] or [`ChartSeries::set_bin_width()`].
+    ///
+    /// Histogram charts are a newer Excel 2016 "chartex" chart type. They
+    /// support a single series with a values range, a series name and a chart
+    /// title. Other chart options such as axis, legend and series formatting
+    /// are ignored and a warning is printed to stderr when they are set.
+    Histogram,
+
+    /// A statistical Pareto chart type.
+    ///
+    /// A Pareto chart shows the values of a single data series as columns
+    /// sorted in descending order along with a line showing the cumulative
+    /// percentage of the total on a secondary axis.
+    ///
+    /// If the series has a categories range then the values are aggregated by
+    /// category. Otherwise the values are grouped into bins, in the same way
+    /// as a [`ChartType::Histogram`] chart.
+    ///
+    /// Pareto charts are a newer Excel 2016 "chartex" chart type and support
+    /// the same options as Histogram charts.
+    Pareto,
+}
+
+// The binning options for the series of a Histogram or Pareto chart.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum ChartBinning {
+    Automatic,
+    Count(u16),
+    Width(f64),
 }
 
 // -----------------------------------------------------------------------
@@ -8708,6 +9831,7 @@ pub struct ChartTitle {
     hidden: bool,
     is_horizontal: bool,
     ignore_rich_para: bool,
+    layout: Option<ChartLayout>,
 }
 
 impl ChartTitle {
@@ -8720,6 +9844,7 @@ impl ChartTitle {
             hidden: false,
             is_horizontal: false,
             ignore_rich_para: false,
+            layout: None,
         }
     }
 
@@ -8967,6 +10092,30 @@ impl ChartTitle {
         self.font = font;
         self
     }
+
+    /// Set the position of the chart title manually.
+    ///
+    /// By default Excel positions the title automatically. This method can be
+    /// used to set the position of the top left corner of the title as a
+    /// fraction of the width and height of the chart area.
+    ///
+    /// Excel only supports setting the position, not the size, of a title.
+    ///
+    /// See [`ChartPlotArea::set_layout()`] for an example of setting a manual
+    /// layout.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The horizontal position of the title in the range 0.0 to 1.0.
+    /// * `y` - The vertical position of the title in the range 0.0 to 1.0.
+    ///
+    pub fn set_layout(&mut self, x: f64, y: f64) -> &mut ChartTitle {
+        if let Some(layout) = ChartLayout::new(x, y, None, None) {
+            self.layout = Some(layout);
+        }
+
+        self
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -10474,6 +11623,7 @@ pub struct ChartAxis {
     pub(crate) tick_interval: u16,
     pub(crate) major_tick_type: Option<ChartAxisTickType>,
     pub(crate) minor_tick_type: Option<ChartAxisTickType>,
+    pub(crate) base_unit_date_type: Option<ChartAxisDateUnitType>,
     pub(crate) major_unit_date_type: Option<ChartAxisDateUnitType>,
     pub(crate) minor_unit_date_type: Option<ChartAxisDateUnitType>,
     pub(crate) display_units_type: ChartAxisDisplayUnitType,
@@ -10510,6 +11660,7 @@ impl ChartAxis {
             tick_interval: 0,
             major_tick_type: None,
             minor_tick_type: None,
+            base_unit_date_type: None,
             major_unit_date_type: None,
             minor_unit_date_type: None,
             display_units_type: ChartAxisDisplayUnitType::None,
@@ -10795,6 +11946,11 @@ impl ChartAxis {
     /// a floating point number, a date, a currency value or some other user
     /// defined format.
     ///
+    /// The number format is written as unlinked from the source data,
+    /// `sourceLinked="0"`, so that Excel uses it instead of the format of the
+    /// worksheet cells. See
+    /// [`ChartAxis::set_num_format_linked_to_source()`] to change this.
+    ///
     /// See also [Number Format Categories] and [Number Formats in different
     /// locales] in the documentation for [`Format`](crate::Format).
     ///
@@ -10856,6 +12012,24 @@ impl ChartAxis {
         self
     }
 
+    /// Link the chart axis number format to the source data.
+    ///
+    /// By default a number format set with
+    /// [`ChartAxis::set_num_format()`] is unlinked from the source data so
+    /// that it isn't overridden by the number format of the worksheet cells.
+    /// This method can be used to link the format to the source data, like the
+    /// "Linked to source" option in Excel, so that Excel displays the number
+    /// format of the worksheet cells instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_num_format_linked_to_source(&mut self, enable: bool) -> &mut ChartAxis {
+        self.num_format_linked_to_source = enable;
+        self
+    }
+
     /// Set the category axis as a date axis.
     ///
     /// In general the "Category" axis (usually the X-axis) in Excel charts is
@@ -11488,6 +12662,23 @@ impl ChartAxis {
         self
     }
 
+    /// Set the base unit type of a date axis as days, months or years.
+    ///
+    /// By default Excel chooses the base unit of a date axis automatically
+    /// from the smallest difference between the category dates. This method
+    /// can be used to set it explicitly, for example to plot monthly data
+    /// with a base unit of months. It only applies to axes that have been
+    /// set as date axes with [`ChartAxis::set_date_axis()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `unit` - A [`ChartAxisDateUnitType`] enum value.
+    ///
+    pub fn set_base_unit_date_type(&mut self, unit_type: ChartAxisDateUnitType) -> &mut ChartAxis {
+        self.base_unit_date_type = Some(unit_type);
+        self
+    }
+
     /// Set the major unit type as days, months or years.
     ///
     /// # Parameters
@@ -12130,7 +13321,8 @@ impl ChartAxis {
     ///
     /// # Parameters
     ///
-    /// * `base` - The logarithm base. Should be >= 2.
+    /// * `base` - The logarithm base. Excel allows a range of 2 to 1000. Values
+    ///   outside this range are ignored.
     ///
     /// # Examples
     ///
@@ -12180,9 +13372,12 @@ impl ChartAxis {
     /// src="https://rustxlsxwriter.github.io/images/chart_axis_set_log_base.png">
     ///
     pub fn set_log_base(&mut self, base: u16) -> &mut ChartAxis {
-        if base >= 2 {
-            self.log_base = base;
+        if !(2..=1000).contains(&base) {
+            eprintln!("Chart axis log base '{base}' must be in the Excel range 2-1000");
+            return self;
         }
+
+        self.log_base = base;
         self
     }
 
@@ -12629,6 +13824,7 @@ pub struct ChartLegend {
     pub(crate) format: ChartFormat,
     pub(crate) font: Option<ChartFont>,
     deleted_entries: Vec<usize>,
+    layout: Option<ChartLayout>,
 }
 
 impl ChartLegend {
@@ -12640,6 +13836,7 @@ impl ChartLegend {
             format: ChartFormat::default(),
             font: None,
             deleted_entries: vec![],
+            layout: None,
         }
     }
 
@@ -13002,6 +14199,154 @@ impl ChartLegend {
         self.deleted_entries = entries.to_vec();
         self
     }
+
+    /// Set the position and size of the legend manually.
+    ///
+    /// By default Excel positions the legend automatically based on the
+    /// [`ChartLegend::set_position()`] setting. This method can be used to set
+    /// the position and size of the legend as fractions of the width and
+    /// height of the chart area.
+    ///
+    /// See [`ChartPlotArea::set_layout()`] for an example of setting a manual
+    /// layout.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The horizontal position of the legend in the range 0.0 to 1.0.
+    /// * `y` - The vertical position of the legend in the range 0.0 to 1.0.
+    /// * `width` - The width of the legend in the range 0.0 to 1.0.
+    /// * `height` - The height of the legend in the range 0.0 to 1.0.
+    ///
+    pub fn set_layout(&mut self, x: f64, y: f64, width: f64, height: f64) -> &mut ChartLegend {
+        if let Some(layout) = ChartLayout::new(x, y, Some(width), Some(height)) {
+            self.layout = Some(layout);
+        }
+
+        self
+    }
+}
+
+// -----------------------------------------------------------------------
+// ChartPlotArea
+// -----------------------------------------------------------------------
+
+/// The `ChartPlotArea` struct represents the plot area of a chart.
+///
+/// The plot area is the area of the chart that contains the series data and
+/// the axes. `ChartPlotArea` can be used to configure properties of the plot
+/// area and is obtained via the [`chart.plot_area()`][Chart::plot_area]
+/// method.
+///
+/// It is used in conjunction with the [`Chart`] struct.
+///
+#[derive(Clone)]
+pub struct ChartPlotArea {
+    layout: Option<ChartLayout>,
+}
+
+impl ChartPlotArea {
+    pub(crate) fn new() -> ChartPlotArea {
+        ChartPlotArea { layout: None }
+    }
+
+    /// Set the position and size of the plot area manually.
+    ///
+    /// By default Excel positions and sizes the plot area automatically to fit
+    /// the chart title, axes and legend. This method can be used to set the
+    /// exact position and size of the plot area as fractions of the width and
+    /// height of the chart area. For example an `x` value of 0.25 positions
+    /// the plot area a quarter of the way across the chart.
+    ///
+    /// The dimensions refer to the inner plot area and don't include the axis
+    /// tick labels, like the Excel "Inner" layout option.
+    ///
+    /// Values outside the range 0.0 to 1.0 are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The horizontal position of the plot area in the range 0.0 to
+    ///   1.0.
+    /// * `y` - The vertical position of the plot area in the range 0.0 to 1.0.
+    /// * `width` - The width of the plot area in the range 0.0 to 1.0.
+    /// * `height` - The height of the plot area in the range 0.0 to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// An example of positioning the chart plot area manually.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_plot_area_set_layout.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 60)?;
+    /// #     worksheet.write(2, 0, 30)?;
+    /// #     worksheet.write(3, 0, 10)?;
+    /// #     worksheet.write(4, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     // Position the plot area manually within the chart area.
+    ///     chart.plot_area().set_layout(0.1, 0.2, 0.6, 0.6);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_layout(&mut self, x: f64, y: f64, width: f64, height: f64) -> &mut ChartPlotArea {
+        if let Some(layout) = ChartLayout::new(x, y, Some(width), Some(height)) {
+            self.layout = Some(layout);
+        }
+
+        self
+    }
+}
+
+// The manual layout of a chart element in fractions of the chart area.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ChartLayout {
+    x: f64,
+    y: f64,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl ChartLayout {
+    // Create a new layout, if all the values are in the allowed 0.0-1.0 range.
+    fn new(x: f64, y: f64, width: Option<f64>, height: Option<f64>) -> Option<ChartLayout> {
+        let values = [Some(x), Some(y), width, height];
+
+        if values
+            .iter()
+            .flatten()
+            .all(|value| (0.0..=1.0).contains(value))
+        {
+            Some(ChartLayout {
+                x,
+                y,
+                width,
+                height,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 /// The `ChartLegendPosition` enum defines the [`Chart`] legend positions.
@@ -10,6 +10,11 @@ mod chart_tests {
     use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
     use crate::test_functions::xml_to_vec;
     use crate::ChartRangeCacheDataType;
+    use crate::{
+        ChartAxisDateUnitType, ChartDataLabel, ChartDataLabelPosition, ChartErrorBars,
+        ChartErrorBarsType, ChartFont, ChartFormat, ChartSolidFill, ChartTrendline,
+        ChartTrendlineType, Table, Worksheet,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -294,4 +299,584 @@ mod chart_tests {
         assert_eq!("'Sheet 1'!$A$1:$A$5", range.formula_abs());
         assert_eq!("Sheet 1", range.sheet_name);
     }
+
+    #[test]
+    fn test_manual_layout() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart.plot_area().set_layout(
+            0.13171062992125,
+            0.2643635170603675,
+            0.7397073490813648,
+            0.5713732137649461,
+        );
+        chart.legend().set_layout(0.8, 0.3, 0.15, 0.2);
+        chart.title().set_name("Title").set_layout(0.42, 0.05);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+
+        // Plot area layout.
+        assert!(got.contains(r#"<c:plotArea><c:layout><c:manualLayout><c:layoutTarget val="inner"/><c:xMode val="edge"/><c:yMode val="edge"/><c:x val="0.13171062992125"/><c:y val="0.2643635170603675"/><c:w val="0.7397073490813648"/><c:h val="0.5713732137649461"/></c:manualLayout></c:layout>"#));
+
+        // Legend layout.
+        assert!(got.contains(r#"<c:legendPos val="r"/><c:layout><c:manualLayout><c:xMode val="edge"/><c:yMode val="edge"/><c:x val="0.8"/><c:y val="0.3"/><c:w val="0.15"/><c:h val="0.2"/></c:manualLayout></c:layout>"#));
+
+        // Title layout.
+        assert!(got.contains(r#"</c:tx><c:layout><c:manualLayout><c:xMode val="edge"/><c:yMode val="edge"/><c:x val="0.42"/><c:y val="0.05"/></c:manualLayout></c:layout>"#));
+    }
+
+    #[test]
+    fn test_manual_layout_out_of_range() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        // Values outside the 0.0-1.0 range are ignored.
+        chart.plot_area().set_layout(0.1, 0.1, 1.5, 0.5);
+        chart.legend().set_layout(-0.1, 0.1, 0.5, 0.5);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(!got.contains("c:manualLayout"));
+    }
+
+    #[test]
+    fn test_series_name_literal_and_range() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_name_literal("Q1!A1")
+            .set_values("Sheet1!$A$1:$A$5");
+        chart
+            .add_series()
+            .set_name_range("Sheet1!$B$1")
+            .set_values("Sheet1!$B$2:$B$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+
+        // A literal name is written as a value even if it looks like a range.
+        assert!(got.contains(r#"<c:idx val="0"/><c:order val="0"/><c:tx><c:v>Q1!A1</c:v></c:tx>"#));
+
+        // A range name is written as a string reference.
+        assert!(got.contains(
+            r#"<c:idx val="1"/><c:order val="1"/><c:tx><c:strRef><c:f>Sheet1!$B$1</c:f></c:strRef></c:tx>"#
+        ));
+
+        // A name range that can't be parsed raises an error.
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_name_range("Total")
+            .set_values("Sheet1!$A$1:$A$5");
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_axis_num_format_source_linked() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        // An explicit format is unlinked from the source data by default.
+        chart.y_axis().set_num_format("0.00%");
+        chart
+            .x_axis()
+            .set_num_format("0.0")
+            .set_num_format_linked_to_source(true);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(r#"<c:numFmt formatCode="0.00%" sourceLinked="0"/>"#));
+        assert!(got.contains(r#"<c:numFmt formatCode="0.0" sourceLinked="1"/>"#));
+    }
+
+    #[test]
+    fn test_column_3d_view() {
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart.assemble_xml_file();
+
+        // The default viewing angle.
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(r#"<c:view3D><c:rotX val="15"/><c:rotY val="20"/><c:rAngAx val="1"/></c:view3D><c:plotArea>"#));
+        assert!(got.contains(r#"<c:bar3DChart><c:barDir val="col"/><c:grouping val="clustered"/>"#));
+        assert!(got.contains(r#"</c:ser><c:shape val="box"/><c:axId val="68311296"/><c:axId val="69198208"/><c:axId val="0"/></c:bar3DChart>"#));
+
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(68311296, 69198208);
+
+        // A custom viewing angle. Out of range values are ignored.
+        chart
+            .set_rotation_x(-30)
+            .set_rotation_y(400)
+            .set_rotation_y(45)
+            .set_perspective(250)
+            .set_perspective(60)
+            .set_right_angle_axes(false);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(r#"<c:view3D><c:rotX val="-30"/><c:rotY val="45"/><c:rAngAx val="0"/><c:perspective val="60"/></c:view3D>"#));
+
+        // 2D charts don't have a view.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_rotation_x(30);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(!got.contains("c:view3D"));
+        assert!(got.contains("<c:barChart>"));
+    }
+
+    #[test]
+    fn test_add_series_from_table() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Sales Data").unwrap();
+
+        let table = Table::new().set_name("Sales");
+        worksheet.add_table(2, 1, 6, 3, &table).unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.set_axis_ids(68311296, 69198208);
+        chart
+            .add_series_from_table(&worksheet, "Sales", 0, 2)
+            .unwrap();
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(r#"<c:tx><c:strRef><c:f>'Sales Data'!$D$3</c:f>"#));
+        assert!(got.contains(r#"<c:cat><c:numRef><c:f>'Sales Data'!$B$4:$B$7</c:f>"#));
+        assert!(got.contains(r#"<c:val><c:numRef><c:f>'Sales Data'!$D$4:$D$7</c:f>"#));
+
+        // Tables without a header row don't have a series name.
+        let table = Table::new().set_name("Costs").set_header_row(false);
+        worksheet.add_table(10, 0, 13, 1, &table).unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        let series = chart
+            .add_series_from_table(&worksheet, "Costs", 0, 1)
+            .unwrap();
+
+        assert_eq!(series.value_range.formula_abs(), "'Sales Data'!$B$11:$B$14");
+        assert!(!series.title.range.has_data());
+
+        // Unknown tables and columns outside the table are errors.
+        let result = chart.add_series_from_table(&worksheet, "Missing", 0, 1);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = chart.add_series_from_table(&worksheet, "Sales", 0, 3);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_scatter_trendline() {
+        let mut chart = Chart::new(ChartType::Scatter);
+        chart.set_axis_ids(68311296, 69198208);
+
+        let mut trendline = ChartTrendline::new();
+        trendline
+            .set_type(ChartTrendlineType::Linear)
+            .display_equation(true)
+            .display_r_squared(true)
+            .set_forward_period(0.5);
+
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$5")
+            .set_values("Sheet1!$B$1:$B$5")
+            .set_trendline(&trendline);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(concat!(
+            r#"<c:trendline><c:trendlineType val="linear"/><c:forward val="0.5"/>"#,
+            r#"<c:dispRSqr val="1"/><c:dispEq val="1"/>"#,
+            r#"<c:trendlineLbl><c:layout/><c:numFmt formatCode="General" sourceLinked="0"/></c:trendlineLbl>"#,
+            r#"</c:trendline><c:xVal>"#
+        )));
+    }
+
+    #[test]
+    fn test_column_percentage_error_bars() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$5")
+            .set_y_error_bars(ChartErrorBars::new().set_type(ChartErrorBarsType::Percentage(5.0)));
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(concat!(
+            r#"<c:errBars><c:errBarType val="both"/><c:errValType val="percentage"/>"#,
+            r#"<c:val val="5"/></c:errBars><c:val>"#
+        )));
+    }
+
+    #[test]
+    fn test_bar_percentage_data_labels() {
+        let mut chart = Chart::new(ChartType::Bar);
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$5")
+            .set_data_label(
+                ChartDataLabel::new()
+                    .show_value()
+                    .set_num_format("0.0%")
+                    .set_position(ChartDataLabelPosition::OutsideEnd),
+            );
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        // OutsideEnd is the default position for bar charts so, like Excel,
+        // it isn't written.
+        assert!(got.contains(
+            r#"<c:dLbls><c:numFmt formatCode="0.0%" sourceLinked="0"/><c:showVal val="1"/></c:dLbls>"#
+        ));
+    }
+
+    #[test]
+    fn test_area_fill_transparency() {
+        let mut chart = Chart::new(ChartType::Area);
+        chart.set_axis_ids(57619968, 57621504);
+
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$5")
+            .set_format(
+                ChartFormat::new().set_solid_fill(
+                    ChartSolidFill::new()
+                        .set_color("#4F81BD")
+                        .set_transparency(50),
+                ),
+            );
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(concat!(
+            r#"<c:spPr><a:solidFill><a:srgbClr val="4F81BD"><a:alpha val="50000"/>"#,
+            r#"</a:srgbClr></a:solidFill></c:spPr>"#
+        )));
+    }
+
+    #[test]
+    fn test_legend_font_fill_and_layout() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.set_axis_ids(68311296, 69198208);
+
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+        chart
+            .legend()
+            .set_font(ChartFont::new().set_bold().set_size(9))
+            .set_format(
+                ChartFormat::new().set_solid_fill(
+                    ChartSolidFill::new()
+                        .set_color("#DDEBF7")
+                        .set_transparency(50),
+                ),
+            )
+            .set_layout(0.8, 0.3, 0.15, 0.2);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(
+            r#"<c:legend><c:legendPos val="r"/><c:layout><c:manualLayout><c:xMode val="edge"/><c:yMode val="edge"/><c:x val="0.8"/><c:y val="0.3"/><c:w val="0.15"/><c:h val="0.2"/></c:manualLayout></c:layout><c:spPr><a:solidFill><a:srgbClr val="DDEBF7"><a:alpha val="50000"/></a:srgbClr></a:solidFill></c:spPr><c:txPr><a:bodyPr/><a:lstStyle/><a:p><a:pPr><a:defRPr sz="900" b="1" i="0"/></a:pPr><a:endParaRPr lang="en-US"/></a:p></c:txPr></c:legend>"#
+        ));
+    }
+
+    #[test]
+    fn test_axis_scaling() {
+        let mut chart = Chart::new(ChartType::Line);
+        chart.set_axis_ids(63593856, 63612032);
+
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+        // A fixed 0-100 value axis with a major unit of 20.
+        chart
+            .y_axis()
+            .set_min(0)
+            .set_max(100)
+            .set_major_unit(20)
+            .set_minor_unit(5);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(
+            r#"<c:scaling><c:orientation val="minMax"/><c:max val="100"/><c:min val="0"/></c:scaling>"#
+        ));
+        assert!(got.contains(r#"<c:majorUnit val="20"/><c:minorUnit val="5"/>"#));
+
+        // A log10 value axis. Log bases outside Excel's range are ignored.
+        let mut chart = Chart::new(ChartType::Line);
+        chart.set_axis_ids(63593856, 63612032);
+
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart
+            .y_axis()
+            .set_log_base(10)
+            .set_log_base(1)
+            .set_log_base(1001);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(
+            r#"<c:scaling><c:logBase val="10"/><c:orientation val="minMax"/></c:scaling>"#
+        ));
+    }
+
+    #[test]
+    fn test_title_from_cell() {
+        let mut chart = Chart::new(ChartType::Bar);
+        chart.set_axis_ids(64053248, 64446464);
+
+        chart.add_series().set_values("Sheet1!$B$1:$B$5");
+
+        // A title that refers to a worksheet cell, with a cached string.
+        chart.title().set_name(("Sheet1", 0, 0));
+        chart
+            .title
+            .range
+            .set_cache(&["Sales"], ChartRangeCacheDataType::String);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(
+            r#"<c:title><c:tx><c:strRef><c:f>Sheet1!$A$1</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>Sales</c:v></c:pt></c:strCache></c:strRef></c:tx>"#
+        ));
+    }
+
+    #[test]
+    fn test_date_axis_base_unit() {
+        let mut chart = Chart::new(ChartType::Line);
+        chart.set_axis_ids(51761152, 51762688);
+
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$12")
+            .set_values("Sheet1!$B$1:$B$12");
+
+        // A date axis for monthly data with a major unit of 3 months.
+        chart
+            .x_axis()
+            .set_date_axis(true)
+            .set_num_format("mmm yy")
+            .set_base_unit_date_type(ChartAxisDateUnitType::Months)
+            .set_major_unit(3)
+            .set_major_unit_date_type(ChartAxisDateUnitType::Months);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(got.contains(r#"<c:dateAx><c:axId val="51761152"/>"#));
+        assert!(got.contains(r#"<c:numFmt formatCode="mmm yy" sourceLinked="0"/>"#));
+        assert!(got.contains(
+            r#"<c:lblOffset val="100"/><c:baseTimeUnit val="months"/><c:majorUnit val="3"/><c:majorTimeUnit val="months"/></c:dateAx>"#
+        ));
+    }
+
+    #[test]
+    fn test_histogram_bin_count() {
+        let mut chart = Chart::new(ChartType::Histogram);
+
+        let series = chart
+            .add_series()
+            .set_name("Sheet1!$A$1")
+            .set_values("Sheet1!$A$2:$A$6")
+            .set_bin_count(3);
+
+        series
+            .title
+            .range
+            .set_cache(&["Scores"], ChartRangeCacheDataType::String);
+        series
+            .value_range
+            .set_cache(&["1", "2", "", "4", "5"], ChartRangeCacheDataType::Number);
+
+        chart.title().set_name("Histogram");
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <cx:chartSpace xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex">
+                  <cx:chartData>
+                    <cx:data id="0">
+                      <cx:numDim type="val">
+                        <cx:f>Sheet1!$A$2:$A$6</cx:f>
+                        <cx:lvl ptCount="5" formatCode="General">
+                          <cx:pt idx="0">1</cx:pt>
+                          <cx:pt idx="1">2</cx:pt>
+                          <cx:pt idx="3">4</cx:pt>
+                          <cx:pt idx="4">5</cx:pt>
+                        </cx:lvl>
+                      </cx:numDim>
+                    </cx:data>
+                  </cx:chartData>
+                  <cx:chart>
+                    <cx:title pos="t" align="ctr" overlay="0">
+                      <cx:tx>
+                        <cx:txData>
+                          <cx:v>Histogram</cx:v>
+                        </cx:txData>
+                      </cx:tx>
+                    </cx:title>
+                    <cx:plotArea>
+                      <cx:plotAreaRegion>
+                        <cx:series layoutId="clusteredColumn" uniqueId="{00000001-0000-0000-0000-000000000000}">
+                          <cx:tx>
+                            <cx:txData>
+                              <cx:f>Sheet1!$A$1</cx:f>
+                              <cx:v>Scores</cx:v>
+                            </cx:txData>
+                          </cx:tx>
+                          <cx:dataId val="0"/>
+                          <cx:layoutPr>
+                            <cx:binning intervalClosed="r">
+                              <cx:binCount val="3"/>
+                            </cx:binning>
+                          </cx:layoutPr>
+                        </cx:series>
+                      </cx:plotAreaRegion>
+                      <cx:axis id="0">
+                        <cx:catScaling gapWidth="0"/>
+                        <cx:tickLabels/>
+                      </cx:axis>
+                      <cx:axis id="1">
+                        <cx:valScaling/>
+                        <cx:majorGridlines/>
+                        <cx:tickLabels/>
+                      </cx:axis>
+                    </cx:plotArea>
+                  </cx:chart>
+                </cx:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_pareto_with_categories() {
+        let mut chart = Chart::new(ChartType::Pareto);
+
+        let series = chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$2")
+            .set_values("Sheet1!$B$1:$B$2")
+            .set_bin_width(2.5);
+
+        series
+            .category_range
+            .set_cache(&["Late", "Damaged"], ChartRangeCacheDataType::String);
+        series
+            .value_range
+            .set_cache(&["12", "5"], ChartRangeCacheDataType::Number);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+
+        // Categories are aggregated, so the bin width is ignored.
+        assert!(got.contains(
+            r#"<cx:strDim type="cat"><cx:f>Sheet1!$A$1:$A$2</cx:f><cx:lvl ptCount="2"><cx:pt idx="0">Late</cx:pt><cx:pt idx="1">Damaged</cx:pt></cx:lvl></cx:strDim>"#
+        ));
+        assert!(got.contains(
+            r#"<cx:dataId val="0"/><cx:layoutPr><cx:aggregation/></cx:layoutPr><cx:axisId val="1"/></cx:series>"#
+        ));
+        assert!(got.contains(
+            r#"<cx:series layoutId="paretoLine" ownerIdx="0" uniqueId="{00000001-0001-0000-0000-000000000000}"><cx:axisId val="2"/></cx:series>"#
+        ));
+        assert!(got.contains(
+            r#"<cx:axis id="2"><cx:valScaling max="1" min="0"/><cx:units unit="percentage"/><cx:tickLabels/></cx:axis>"#
+        ));
+
+        // Without categories the values are binned.
+        let mut chart = Chart::new(ChartType::Pareto);
+        chart
+            .add_series()
+            .set_values("Sheet1!$B$1:$B$2")
+            .set_bin_width(2.5);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        assert!(
+            got.contains(r#"<cx:binning intervalClosed="r"><cx:binSize val="2.5"/></cx:binning>"#)
+        );
+    }
+
+    #[test]
+    fn test_chartex_validation() {
+        // Check for Histogram chart with more than one series.
+        let mut chart = Chart::new(ChartType::Histogram);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.add_series().set_values("Sheet1!$B$1:$B$5");
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check for Pareto chart combined with another chart.
+        let mut line_chart = Chart::new(ChartType::Line);
+        line_chart.add_series().set_values("Sheet1!$B$1:$B$5");
+
+        let mut chart = Chart::new(ChartType::Pareto);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.combine(&line_chart);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check that invalid bin options are ignored.
+        let mut series = ChartSeries::new();
+        series.set_bin_count(0).set_bin_width(-1.0);
+        assert!(series.binning == crate::chart::ChartBinning::Automatic);
+
+        // Check the series options that aren't supported for chartex charts.
+        let mut series = ChartSeries::new();
+        series.set_values("Sheet1!$A$1:$A$5").set_bin_count(4);
+        assert!(series.chartex_unsupported_options().is_empty());
+
+        series.set_gap(50).set_smooth(true);
+        assert_eq!(
+            series.chartex_unsupported_options(),
+            vec!["set_gap()", "set_smooth()"]
+        );
+
+        // Unsupported options are ignored rather than raising an error.
+        let mut chart = Chart::new(ChartType::Histogram);
+        chart.push_series(&series);
+        chart.set_style(5);
+        assert!(chart.validate().is_ok());
+    }
 }
@@ -0,0 +1,171 @@
+// comment - A module for creating the Excel comments.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use std::collections::BTreeMap;
+
+use crate::xmlwriter::XMLWriter;
+use crate::{utility, ColNum, Note, RowNum};
+
+pub struct Comment {
+    pub(crate) writer: XMLWriter,
+    pub(crate) notes: BTreeMap<(RowNum, ColNum), Note>,
+    pub(crate) default_author: String,
+    authors: Vec<String>,
+}
+
+impl Comment {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new Comment struct.
+    pub(crate) fn new() -> Comment {
+        let writer = XMLWriter::new();
+
+        Comment {
+            writer,
+            notes: BTreeMap::new(),
+            default_author: String::new(),
+            authors: vec![],
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // Get the author of a note, or the default author if it isn't set.
+    fn note_author(&self, note: &Note) -> String {
+        match &note.author {
+            Some(author) => author.clone(),
+            None => self.default_author.clone(),
+        }
+    }
+
+    // Get the index of the author of a note in the authors list.
+    fn author_id(&self, note: &Note) -> usize {
+        let author = self.note_author(note);
+
+        self.authors
+            .iter()
+            .position(|name| *name == author)
+            .unwrap_or_default()
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Store the unique note authors in the order that they are used.
+        for note in self.notes.values() {
+            let author = self.note_author(note);
+            if !self.authors.contains(&author) {
+                self.authors.push(author);
+            }
+        }
+
+        // Write the comments element.
+        self.write_comments();
+
+        // Write the authors element.
+        self.write_authors();
+
+        // Write the commentList element.
+        self.write_comment_list();
+
+        // Close the comments tag.
+        self.writer.xml_end_tag("comments");
+    }
+
+    // Write the <comments> element.
+    fn write_comments(&mut self) {
+        let attributes = [(
+            "xmlns",
+            "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+        )];
+
+        self.writer.xml_start_tag("comments", &attributes);
+    }
+
+    // Write the <authors> element.
+    fn write_authors(&mut self) {
+        self.writer.xml_start_tag_only("authors");
+
+        for author in &self.authors.clone() {
+            // Write the author element.
+            self.writer.xml_data_element_only("author", author);
+        }
+
+        self.writer.xml_end_tag("authors");
+    }
+
+    // Write the <commentList> element.
+    fn write_comment_list(&mut self) {
+        self.writer.xml_start_tag_only("commentList");
+
+        for ((row, col), note) in &self.notes.clone() {
+            // Write the comment element.
+            self.write_comment(*row, *col, note);
+        }
+
+        self.writer.xml_end_tag("commentList");
+    }
+
+    // Write the <comment> element.
+    fn write_comment(&mut self, row: RowNum, col: ColNum, note: &Note) {
+        let attributes = [
+            ("ref", utility::row_col_to_cell(row, col)),
+            ("authorId", self.author_id(note).to_string()),
+        ];
+
+        self.writer.xml_start_tag("comment", &attributes);
+
+        // Write the text element.
+        self.write_text(&note.text);
+
+        self.writer.xml_end_tag("comment");
+    }
+
+    // Write the <text> element.
+    fn write_text(&mut self, text: &str) {
+        self.writer.xml_start_tag_only("text");
+        self.writer.xml_start_tag_only("r");
+
+        // Write the rPr element.
+        self.write_run_properties();
+
+        // Write the t element.
+        let attributes = if text.starts_with(['\t', '\n', ' ']) || text.ends_with(['\t', '\n', ' '])
+        {
+            vec![("xml:space", "preserve")]
+        } else {
+            vec![]
+        };
+
+        self.writer.xml_data_element("t", text, &attributes);
+
+        self.writer.xml_end_tag("r");
+        self.writer.xml_end_tag("text");
+    }
+
+    // Write the <rPr> element. This is the default Tahoma 8 note font.
+    fn write_run_properties(&mut self) {
+        self.writer.xml_start_tag_only("rPr");
+
+        self.writer.xml_empty_tag("sz", &[("val", "8")]);
+        self.writer.xml_empty_tag("color", &[("indexed", "81")]);
+        self.writer.xml_empty_tag("rFont", &[("val", "Tahoma")]);
+        self.writer.xml_empty_tag("family", &[("val", "2")]);
+
+        self.writer.xml_end_tag("rPr");
+    }
+}
@@ -0,0 +1,86 @@
+// comment unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod comment_tests {
+
+    use crate::comment::Comment;
+    use crate::test_functions::xml_to_vec;
+    use crate::Note;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut comment = Comment::new();
+        comment.default_author = "John".to_string();
+
+        comment
+            .notes
+            .insert((1, 1), Note::new("Some text").set_author("Anne"));
+        comment.notes.insert((0, 0), Note::new(" Padded "));
+        comment.notes.insert((2, 0), Note::new("More text"));
+
+        comment.assemble_xml_file();
+
+        let got = comment.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+              <authors>
+                <author>John</author>
+                <author>Anne</author>
+              </authors>
+              <commentList>
+                <comment ref="A1" authorId="0">
+                  <text>
+                    <r>
+                      <rPr>
+                        <sz val="8"/>
+                        <color indexed="81"/>
+                        <rFont val="Tahoma"/>
+                        <family val="2"/>
+                      </rPr>
+                      <t xml:space="preserve"> Padded </t>
+                    </r>
+                  </text>
+                </comment>
+                <comment ref="B2" authorId="1">
+                  <text>
+                    <r>
+                      <rPr>
+                        <sz val="8"/>
+                        <color indexed="81"/>
+                        <rFont val="Tahoma"/>
+                        <family val="2"/>
+                      </rPr>
+                      <t>Some text</t>
+                    </r>
+                  </text>
+                </comment>
+                <comment ref="A3" authorId="0">
+                  <text>
+                    <r>
+                      <rPr>
+                        <sz val="8"/>
+                        <color indexed="81"/>
+                        <rFont val="Tahoma"/>
+                        <family val="2"/>
+                      </rPr>
+                      <t>More text</t>
+                    </r>
+                  </text>
+                </comment>
+              </commentList>
+            </comments>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+}
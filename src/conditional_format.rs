@@ -2179,6 +2179,14 @@ impl ConditionalFormatFormula {
     ///   in Formula conditional formats.
     /// - The formula should be in English with US style punctuation. See
     ///   [`Formula`] for details.
+    /// - Cell references in the formula are written as given and Excel
+    ///   evaluates them relative to the top-left cell of the range the
+    ///   conditional format is applied to. For example a rule of `=$A1>$B1`
+    ///   applied to `A2:D10` compares `A2` and `B2` for the first row of the
+    ///   range, not `A1` and `B1`. The rule should therefore be written in
+    ///   terms of the first cell in the range. See [Relative and absolute
+    ///   references in conditional
+    ///   formats](crate::conditional_format#relative-and-absolute-references-in-conditional-formats).
     ///
     /// If you encounter any issues you should verify that the formula works in
     /// Excel before transferring it to `rust_xlsxwriter`.
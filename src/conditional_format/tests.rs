@@ -5227,4 +5227,64 @@ mod conditional_format_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn conditional_format_25() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet.write_row_matrix(0, 0, [[5, 1], [2, 8]])?;
+
+        // Highlight rows where column A exceeds column B. The references are
+        // relative to the first cell in the range, A1.
+        let conditional_format = ConditionalFormatFormula::new().set_rule("=$A1>$B1");
+
+        worksheet.add_conditional_format(0, 0, 1, 1, &conditional_format)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:B2"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:2">
+                  <c r="A1">
+                    <v>5</v>
+                  </c>
+                  <c r="B1">
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:2">
+                  <c r="A2">
+                    <v>2</v>
+                  </c>
+                  <c r="B2">
+                    <v>8</v>
+                  </c>
+                </row>
+              </sheetData>
+              <conditionalFormatting sqref="A1:B2">
+                <cfRule type="expression" priority="1">
+                  <formula>$A1&gt;$B1</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
 }
@@ -82,6 +82,15 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a chartsheet to the ContentTypes overrides.
+    pub(crate) fn add_chartsheet_name(&mut self, index: u16) {
+        let content_type =
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml";
+        let part_name = format!("/xl/chartsheets/sheet{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the name of a drawing to the ContentTypes overrides.
     pub(crate) fn add_drawing_name(&mut self, index: u16) {
         let content_type = "application/vnd.openxmlformats-officedocument.drawing+xml";
@@ -98,6 +107,15 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a chartex chart, such as a Histogram, to the
+    // ContentTypes overrides.
+    pub(crate) fn add_chartex_name(&mut self, index: u16) {
+        let content_type = "application/vnd.ms-office.chartex+xml";
+        let part_name = format!("/xl/charts/chartEx{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the name of a table to the ContentTypes overrides.
     pub(crate) fn add_table_name(&mut self, index: u16) {
         let content_type = "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml";
@@ -106,6 +124,31 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a comments file to the ContentTypes overrides.
+    pub(crate) fn add_comment_name(&mut self, index: u16) {
+        let content_type =
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml";
+        let part_name = format!("/xl/comments{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
+    // Add the name of a threaded comments file to the ContentTypes overrides.
+    pub(crate) fn add_threaded_comment_name(&mut self, index: u16) {
+        let content_type = "application/vnd.ms-excel.threadedcomments+xml";
+        let part_name = format!("/xl/threadedComments/threadedComment{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
+    // Add the threaded comments persons file to the ContentTypes overrides.
+    pub(crate) fn add_persons(&mut self) {
+        self.add_override(
+            "/xl/persons/person.xml",
+            "application/vnd.ms-excel.person+xml",
+        );
+    }
+
     // Add the sharedStrings link to the ContentTypes overrides.
     pub(crate) fn add_share_strings(&mut self) {
         self.add_override(
@@ -142,6 +185,31 @@ impl ContentTypes {
         );
     }
 
+    // Add the shared workbook revision files to the ContentTypes overrides.
+    pub(crate) fn add_revisions(&mut self) {
+        self.add_override(
+            "/xl/revisions/revisionHeaders.xml",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionHeaders+xml",
+        );
+        self.add_override(
+            "/xl/revisions/revisionLog1.xml",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionLog+xml",
+        );
+        self.add_override(
+            "/xl/revisions/userNames.xml",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.userNames+xml",
+        );
+    }
+
+    // Add the name of a custom XML item properties file to the ContentTypes
+    // overrides.
+    pub(crate) fn add_custom_xml_properties(&mut self, index: u16) {
+        let content_type = "application/vnd.openxmlformats-officedocument.customXmlProperties+xml";
+        let part_name = format!("/customXml/itemProps{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the custom properties to the ContentTypes overrides.
     pub(crate) fn add_custom_properties(&mut self) {
         self.add_override(
@@ -148,7 +148,11 @@ impl Core {
     // Write the <dcterms:modified> element.
     fn write_dcterms_modified(&mut self) {
         let attributes = [("xsi:type", "dcterms:W3CDTF")];
-        let datetime = self.properties.creation_time.clone();
+        let datetime = if self.properties.modified_time.is_empty() {
+            self.properties.creation_time.clone()
+        } else {
+            self.properties.modified_time.clone()
+        };
 
         self.writer
             .xml_data_element("dcterms:modified", &datetime, &attributes);
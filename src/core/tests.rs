@@ -42,4 +42,39 @@ mod core_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_modified() {
+        let created = ExcelDateTime::from_ymd(2010, 1, 1).unwrap();
+        let modified = ExcelDateTime::from_ymd(2011, 2, 3)
+            .unwrap()
+            .and_hms(4, 5, 6)
+            .unwrap();
+        let properties = DocProperties::new()
+            .set_author("A User")
+            .set_creation_datetime(&created)
+            .set_modified_datetime(&modified);
+
+        let mut core = Core::new();
+        core.properties = properties;
+
+        core.assemble_xml_file();
+
+        let got = core.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+              <dc:creator>A User</dc:creator>
+              <cp:lastModifiedBy>A User</cp:lastModifiedBy>
+              <dcterms:created xsi:type="dcterms:W3CDTF">2010-01-01T00:00:00Z</dcterms:created>
+              <dcterms:modified xsi:type="dcterms:W3CDTF">2011-02-03T04:05:06Z</dcterms:modified>
+            </cp:coreProperties>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
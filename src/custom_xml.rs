@@ -0,0 +1,96 @@
+// custom_xml - A module for creating the Excel customXml/itemProps.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct CustomXmlProperties {
+    pub(crate) writer: XMLWriter,
+    pub(crate) item_id: String,
+}
+
+impl CustomXmlProperties {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new CustomXmlProperties struct.
+    pub(crate) fn new() -> CustomXmlProperties {
+        let writer = XMLWriter::new();
+
+        CustomXmlProperties {
+            writer,
+            item_id: String::new(),
+        }
+    }
+
+    // Create a pseudo GUID for a custom XML item. It is derived from the XML
+    // data and the index of the item so that the output file remains
+    // reproducible.
+    pub(crate) fn item_id(xml: &str, index: usize) -> String {
+        let hash1 = Self::fnv_hash(xml.as_bytes(), 0xCBF2_9CE4_8422_2325);
+        let hash2 = Self::fnv_hash(&index.to_le_bytes(), hash1);
+        let guid = format!("{hash1:016X}{hash2:016X}");
+
+        format!(
+            "{{{}-{}-{}-{}-{}}}",
+            &guid[0..8],
+            &guid[8..12],
+            &guid[12..16],
+            &guid[16..20],
+            &guid[20..32]
+        )
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // A simple FNV-1a hash function, used since the std hasher isn't
+    // guaranteed to be stable between Rust versions.
+    fn fnv_hash(bytes: &[u8], seed: u64) -> u64 {
+        let mut hash = seed;
+
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01B3);
+        }
+
+        hash
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the ds:datastoreItem element.
+        self.write_datastore_item();
+
+        // Write the ds:schemaRefs element.
+        self.writer.xml_empty_tag_only("ds:schemaRefs");
+
+        // Close the final tag.
+        self.writer.xml_end_tag("ds:datastoreItem");
+    }
+
+    // Write the <ds:datastoreItem> element.
+    fn write_datastore_item(&mut self) {
+        let attributes = [
+            ("ds:itemID", self.item_id.as_str()),
+            (
+                "xmlns:ds",
+                "http://schemas.openxmlformats.org/officeDocument/2006/customXml",
+            ),
+        ];
+
+        self.writer.xml_start_tag("ds:datastoreItem", &attributes);
+    }
+}
@@ -0,0 +1,48 @@
+// custom_xml unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod custom_xml_tests {
+
+    use crate::custom_xml::CustomXmlProperties;
+    use crate::test_functions::xml_to_vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut custom_xml = CustomXmlProperties::new();
+        custom_xml.item_id = "{6E963533-3A3B-4E52-A9A6-2B7A8D0A7C00}".to_string();
+
+        custom_xml.assemble_xml_file();
+
+        let got = custom_xml.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <ds:datastoreItem ds:itemID="{6E963533-3A3B-4E52-A9A6-2B7A8D0A7C00}" xmlns:ds="http://schemas.openxmlformats.org/officeDocument/2006/customXml">
+              <ds:schemaRefs/>
+            </ds:datastoreItem>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_item_id() {
+        let id1 = CustomXmlProperties::item_id("<root/>", 1);
+        let id2 = CustomXmlProperties::item_id("<root/>", 2);
+
+        // The id is a GUID style string that is reproducible and unique to
+        // each item.
+        assert_eq!(38, id1.len());
+        assert!(id1.starts_with('{') && id1.ends_with('}'));
+        assert_eq!(id1, CustomXmlProperties::item_id("<root/>", 1));
+        assert_ne!(id1, id2);
+    }
+}
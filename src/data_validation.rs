@@ -0,0 +1,819 @@
+// data_validation - A module for representing Excel data validations.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use std::fmt;
+
+use crate::{utility, xmlwriter::XMLWriter, ColNum, ExcelDateTime, Formula, RowNum, XlsxError};
+
+/// The `DataValidation` struct represents a data validation in Excel.
+///
+/// Data validation is a feature of Excel which allows you to restrict the data
+/// that a user enters in a cell and to display associated help and warning
+/// messages. It also allows you to restrict input to values in a dropdown
+/// list.
+///
+/// A typical use case might be to restrict data in a cell to integer values in
+/// a certain range, to provide a help message to indicate the required value
+/// and to issue a warning if the input data doesn't meet the stated criteria.
+///
+/// A data validation is added to a worksheet cell or range via the
+/// [`Worksheet::add_data_validation()`](crate::Worksheet::add_data_validation)
+/// method.
+///
+/// # Examples
+///
+/// The following example demonstrates setting a data validation dropdown list
+/// whose values are taken from a range on another worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_data_validation_allow_list_range.rs
+/// #
+/// use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Add a worksheet for the input data and one for the lookup list.
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write(1, 0, "Select value in cell C2:")?;
+///
+///     let data_validation = DataValidation::new()
+///         .allow_list_range("My Lists", 0, 0, 4, 0)
+///         .set_input_title("Select a fruit")
+///         .set_input_message("Choose a value from the dropdown list.");
+///
+///     worksheet.add_data_validation(1, 2, 1, 2, &data_validation)?;
+///
+///     let lists = workbook.add_worksheet().set_name("My Lists")?;
+///     lists.write_column(0, 0, ["Apple", "Banana", "Cherry", "Damson", "Elder"])?;
+///
+///     // Save the file to disk.
+///     workbook.save("data_validation.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct DataValidation {
+    pub(crate) validation_type: DataValidationType,
+    pub(crate) rule: DataValidationRuleInternal,
+    pub(crate) ignore_blank: bool,
+    pub(crate) show_dropdown: bool,
+    pub(crate) show_input_message: bool,
+    pub(crate) show_error_message: bool,
+    pub(crate) multi_range: String,
+    pub(crate) input_title: String,
+    pub(crate) input_message: String,
+    pub(crate) error_title: String,
+    pub(crate) error_message: String,
+    pub(crate) error_style: DataValidationErrorStyle,
+}
+
+impl DataValidation {
+    /// Create a new `DataValidation` struct instance.
+    ///
+    /// The default data validation type is "Any value", which allows any input
+    /// and is mainly used to display an input message. Use one of the
+    /// `allow_*()` methods to set a restriction.
+    ///
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> DataValidation {
+        DataValidation {
+            validation_type: DataValidationType::Any,
+            rule: DataValidationRuleInternal::None,
+            ignore_blank: true,
+            show_dropdown: true,
+            show_input_message: true,
+            show_error_message: true,
+            multi_range: String::new(),
+            input_title: String::new(),
+            input_message: String::new(),
+            error_title: String::new(),
+            error_message: String::new(),
+            error_style: DataValidationErrorStyle::Stop,
+        }
+    }
+
+    /// Restrict cell input to whole/integer numbers.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with `i32` values.
+    ///
+    pub fn allow_whole_number(mut self, rule: DataValidationRule<i32>) -> DataValidation {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Whole;
+        self
+    }
+
+    /// Restrict cell input to whole/integer numbers based on cell references or
+    /// formulas.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with [`Formula`] values.
+    ///
+    pub fn allow_whole_number_formula(
+        mut self,
+        rule: DataValidationRule<Formula>,
+    ) -> DataValidation {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Whole;
+        self
+    }
+
+    /// Restrict cell input to decimal numbers.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with `f64` values.
+    ///
+    pub fn allow_decimal_number(mut self, rule: DataValidationRule<f64>) -> DataValidation {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Decimal;
+        self
+    }
+
+    /// Restrict cell input to decimal numbers based on cell references or
+    /// formulas.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with [`Formula`] values.
+    ///
+    pub fn allow_decimal_number_formula(
+        mut self,
+        rule: DataValidationRule<Formula>,
+    ) -> DataValidation {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Decimal;
+        self
+    }
+
+    /// Restrict cell input to dates.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with [`ExcelDateTime`], Chrono
+    ///   date or [`Formula`] values.
+    ///
+    pub fn allow_date<T>(mut self, rule: DataValidationRule<T>) -> DataValidation
+    where
+        T: IntoDataValidationValue,
+    {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Date;
+        self
+    }
+
+    /// Restrict cell input to times.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with [`ExcelDateTime`], Chrono
+    ///   time or [`Formula`] values.
+    ///
+    pub fn allow_time<T>(mut self, rule: DataValidationRule<T>) -> DataValidation
+    where
+        T: IntoDataValidationValue,
+    {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::Time;
+        self
+    }
+
+    /// Restrict cell input to strings of a certain length.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`DataValidationRule`] with `u32` values.
+    ///
+    pub fn allow_text_length(mut self, rule: DataValidationRule<u32>) -> DataValidation {
+        self.rule = rule.to_internal_rule();
+        self.validation_type = DataValidationType::TextLength;
+        self
+    }
+
+    /// Restrict cell input to a list of strings in a dropdown.
+    ///
+    /// The strings are stored in the data validation itself and are limited by
+    /// Excel to a total of 255 characters, including the separating commas.
+    /// For longer lists use [`DataValidation::allow_list_range()`] or
+    /// [`DataValidation::allow_list_formula()`] to refer to a range of cells
+    /// instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `list` - A list of string like values.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::DataValidationError`] - The length of the list, plus
+    ///   separators, exceeds Excel's limit of 255 characters.
+    ///
+    pub fn allow_list_strings(
+        mut self,
+        list: &[impl AsRef<str>],
+    ) -> Result<DataValidation, XlsxError> {
+        let joined_list = list
+            .iter()
+            .map(|s| s.as_ref().replace('"', "\"\""))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let length = joined_list.chars().count();
+        if length > 255 {
+            let error = format!(
+                "Validation list length '{length}' including commas is greater than Excel's limit of 255 characters: {joined_list}"
+            );
+            return Err(XlsxError::DataValidationError(error));
+        }
+
+        self.rule = DataValidationRuleInternal::ListSource(format!("\"{joined_list}\""));
+        self.validation_type = DataValidationType::List;
+        Ok(self)
+    }
+
+    /// Restrict cell input to a dropdown list of values from a cell range.
+    ///
+    /// The range can be on the same worksheet or, more commonly, on a separate,
+    /// possibly hidden, lookup worksheet. The worksheet name is quoted, if
+    /// required, in the same way as Excel so names with spaces or other
+    /// special characters like `My Lists` don't need to be quoted by the
+    /// caller. The range is converted to an absolute reference like
+    /// `'My Lists'!$A$1:$A$5`.
+    ///
+    /// # Parameters
+    ///
+    /// * `sheet_name` - The name of the worksheet that contains the list
+    ///   values.
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first column of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last column of the range.
+    ///
+    pub fn allow_list_range(
+        mut self,
+        sheet_name: &str,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> DataValidation {
+        let range = utility::chart_range_abs(sheet_name, first_row, first_col, last_row, last_col);
+
+        self.rule = DataValidationRuleInternal::ListSource(range);
+        self.validation_type = DataValidationType::List;
+        self
+    }
+
+    /// Restrict cell input to a dropdown list of values from a cell range or
+    /// formula.
+    ///
+    /// This is similar to [`DataValidation::allow_list_range()`] but it takes
+    /// a formula string such as `=$E$1:$E$5`, `='My Lists'!$A$1:$A$5` or a
+    /// defined name. Formulas are written as given so worksheet names that
+    /// contain spaces or other special characters must be single quoted, as
+    /// in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`Formula`] value representing the list source.
+    ///
+    pub fn allow_list_formula(mut self, rule: Formula) -> DataValidation {
+        let formula = rule.expand_formula(true).to_string();
+
+        self.rule = DataValidationRuleInternal::ListSource(formula);
+        self.validation_type = DataValidationType::List;
+        self
+    }
+
+    /// Restrict cell input based on a custom formula that evaluates to `TRUE`
+    /// or `FALSE`.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - A [`Formula`] value such as `=ISTEXT(A1)`.
+    ///
+    pub fn allow_custom(mut self, rule: Formula) -> DataValidation {
+        let formula = rule.expand_formula(true).to_string();
+
+        self.rule = DataValidationRuleInternal::CustomFormula(formula);
+        self.validation_type = DataValidationType::Custom;
+        self
+    }
+
+    /// Allow any input in the cell.
+    ///
+    /// This is the default validation type and is mainly used to turn off a
+    /// previous restriction while retaining the input message.
+    ///
+    pub fn allow_any_value(mut self) -> DataValidation {
+        self.rule = DataValidationRuleInternal::None;
+        self.validation_type = DataValidationType::Any;
+        self
+    }
+
+    /// Ignore blank cells when applying the data validation.
+    ///
+    /// This is on by default, as in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn ignore_blank(mut self, enable: bool) -> DataValidation {
+        self.ignore_blank = enable;
+        self
+    }
+
+    /// Show the in-cell dropdown for list data validations.
+    ///
+    /// This is on by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn show_dropdown(mut self, enable: bool) -> DataValidation {
+        self.show_dropdown = enable;
+        self
+    }
+
+    /// Show the input message when the cell is selected.
+    ///
+    /// This is on by default but the message is only displayed if it has been
+    /// set with [`DataValidation::set_input_message()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn show_input_message(mut self, enable: bool) -> DataValidation {
+        self.show_input_message = enable;
+        self
+    }
+
+    /// Show the error message when the input data is invalid.
+    ///
+    /// This is on by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn show_error_message(mut self, enable: bool) -> DataValidation {
+        self.show_error_message = enable;
+        self
+    }
+
+    /// Set the title of the input message shown when the cell is selected.
+    ///
+    /// Excel limits the title to 32 characters. A longer title will cause
+    /// [`Worksheet::add_data_validation()`](crate::Worksheet::add_data_validation)
+    /// to return an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - Title string.
+    ///
+    pub fn set_input_title(mut self, text: impl Into<String>) -> DataValidation {
+        self.input_title = text.into();
+        self
+    }
+
+    /// Set the input message shown when the cell is selected.
+    ///
+    /// Excel limits the message to 255 characters. A longer message will cause
+    /// [`Worksheet::add_data_validation()`](crate::Worksheet::add_data_validation)
+    /// to return an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - Message string.
+    ///
+    pub fn set_input_message(mut self, text: impl Into<String>) -> DataValidation {
+        self.input_message = text.into();
+        self
+    }
+
+    /// Set the title of the error message shown for invalid input.
+    ///
+    /// Excel limits the title to 32 characters. A longer title will cause
+    /// [`Worksheet::add_data_validation()`](crate::Worksheet::add_data_validation)
+    /// to return an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - Title string.
+    ///
+    pub fn set_error_title(mut self, text: impl Into<String>) -> DataValidation {
+        self.error_title = text.into();
+        self
+    }
+
+    /// Set the error message shown for invalid input.
+    ///
+    /// Excel limits the message to 255 characters. A longer message will cause
+    /// [`Worksheet::add_data_validation()`](crate::Worksheet::add_data_validation)
+    /// to return an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - Message string.
+    ///
+    pub fn set_error_message(mut self, text: impl Into<String>) -> DataValidation {
+        self.error_message = text.into();
+        self
+    }
+
+    /// Set the style of the error dialog shown for invalid input.
+    ///
+    /// # Parameters
+    ///
+    /// * `error_style` - A [`DataValidationErrorStyle`] enum value. The
+    ///   default is [`DataValidationErrorStyle::Stop`].
+    ///
+    pub fn set_error_style(mut self, error_style: DataValidationErrorStyle) -> DataValidation {
+        self.error_style = error_style;
+        self
+    }
+
+    /// Set an additional multi-cell range for the data validation.
+    ///
+    /// The `set_multi_range()` method is used to extend a data validation over
+    /// non-contiguous ranges like `"B3 D6:H10"`. The range passed to
+    /// `add_data_validation()` is replaced by this range.
+    ///
+    /// # Parameters
+    ///
+    /// * `range` - A string like type representing an Excel range separated
+    ///   by spaces.
+    ///
+    pub fn set_multi_range(mut self, range: impl Into<String>) -> DataValidation {
+        self.multi_range = range.into().replace('$', "");
+        self
+    }
+
+    // Validate the data validation properties.
+    pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        if let DataValidationRuleInternal::ListSource(list) = &self.rule {
+            if list.is_empty() {
+                return Err(XlsxError::DataValidationError(
+                    "Validation list source cannot be blank".to_string(),
+                ));
+            }
+        }
+
+        // Check the title and message lengths against the Excel limits.
+        let limits = [
+            ("input title", &self.input_title, 32),
+            ("input message", &self.input_message, 255),
+            ("error title", &self.error_title, 32),
+            ("error message", &self.error_message, 255),
+        ];
+
+        for (name, text, limit) in limits {
+            let length = text.chars().count();
+            if length > limit {
+                return Err(XlsxError::DataValidationError(format!(
+                    "Validation {name} length '{length}' exceeds Excel's limit of {limit} characters"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Return the data validation as an XML string.
+    pub(crate) fn rule(&self, range: &str) -> String {
+        let mut writer = XMLWriter::new();
+        let mut attributes = vec![];
+
+        if self.validation_type != DataValidationType::Any {
+            attributes.push(("type", self.validation_type.to_string()));
+        }
+
+        match &self.rule {
+            DataValidationRuleInternal::EqualTo(_) => {
+                attributes.push(("operator", "equal".to_string()));
+            }
+            DataValidationRuleInternal::NotEqualTo(_) => {
+                attributes.push(("operator", "notEqual".to_string()));
+            }
+            DataValidationRuleInternal::GreaterThan(_) => {
+                attributes.push(("operator", "greaterThan".to_string()));
+            }
+            DataValidationRuleInternal::GreaterThanOrEqualTo(_) => {
+                attributes.push(("operator", "greaterThanOrEqual".to_string()));
+            }
+            DataValidationRuleInternal::LessThan(_) => {
+                attributes.push(("operator", "lessThan".to_string()));
+            }
+            DataValidationRuleInternal::LessThanOrEqualTo(_) => {
+                attributes.push(("operator", "lessThanOrEqual".to_string()));
+            }
+            DataValidationRuleInternal::NotBetween(_, _) => {
+                attributes.push(("operator", "notBetween".to_string()));
+            }
+            // Excel omits the default "between" operator.
+            _ => {}
+        }
+
+        match self.error_style {
+            DataValidationErrorStyle::Warning => {
+                attributes.push(("errorStyle", "warning".to_string()));
+            }
+            DataValidationErrorStyle::Information => {
+                attributes.push(("errorStyle", "information".to_string()));
+            }
+            DataValidationErrorStyle::Stop => {}
+        }
+
+        if self.ignore_blank {
+            attributes.push(("allowBlank", "1".to_string()));
+        }
+
+        // Note, Excel uses an inverted "showDropDown" flag to hide the list.
+        if !self.show_dropdown {
+            attributes.push(("showDropDown", "1".to_string()));
+        }
+
+        if self.show_input_message {
+            attributes.push(("showInputMessage", "1".to_string()));
+        }
+
+        if self.show_error_message {
+            attributes.push(("showErrorMessage", "1".to_string()));
+        }
+
+        if !self.error_title.is_empty() {
+            attributes.push(("errorTitle", self.error_title.clone()));
+        }
+
+        if !self.error_message.is_empty() {
+            attributes.push(("error", self.error_message.clone()));
+        }
+
+        if !self.input_title.is_empty() {
+            attributes.push(("promptTitle", self.input_title.clone()));
+        }
+
+        if !self.input_message.is_empty() {
+            attributes.push(("prompt", self.input_message.clone()));
+        }
+
+        attributes.push(("sqref", range.to_string()));
+
+        if self.validation_type == DataValidationType::Any {
+            writer.xml_empty_tag("dataValidation", &attributes);
+        } else {
+            writer.xml_start_tag("dataValidation", &attributes);
+
+            match &self.rule {
+                DataValidationRuleInternal::EqualTo(value)
+                | DataValidationRuleInternal::NotEqualTo(value)
+                | DataValidationRuleInternal::GreaterThan(value)
+                | DataValidationRuleInternal::GreaterThanOrEqualTo(value)
+                | DataValidationRuleInternal::LessThan(value)
+                | DataValidationRuleInternal::LessThanOrEqualTo(value)
+                | DataValidationRuleInternal::ListSource(value)
+                | DataValidationRuleInternal::CustomFormula(value) => {
+                    writer.xml_data_element_only("formula1", value);
+                }
+                DataValidationRuleInternal::Between(min, max)
+                | DataValidationRuleInternal::NotBetween(min, max) => {
+                    writer.xml_data_element_only("formula1", min);
+                    writer.xml_data_element_only("formula2", max);
+                }
+                DataValidationRuleInternal::None => {}
+            }
+
+            writer.xml_end_tag("dataValidation");
+        }
+
+        writer.read_to_string()
+    }
+}
+
+// -----------------------------------------------------------------------
+// DataValidationRule
+// -----------------------------------------------------------------------
+
+/// The `DataValidationRule` enum defines the comparison rule for a
+/// [`DataValidation`].
+///
+/// The rule is applied to the value types supported by the `allow_*()`
+/// methods such as integers, decimals, dates, times and text lengths.
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DataValidationRule<T: IntoDataValidationValue> {
+    /// Restrict cell input to values that are equal to the target value.
+    EqualTo(T),
+
+    /// Restrict cell input to values that are not equal to the target value.
+    NotEqualTo(T),
+
+    /// Restrict cell input to values that are greater than the target value.
+    GreaterThan(T),
+
+    /// Restrict cell input to values that are greater than or equal to the
+    /// target value.
+    GreaterThanOrEqualTo(T),
+
+    /// Restrict cell input to values that are less than the target value.
+    LessThan(T),
+
+    /// Restrict cell input to values that are less than or equal to the target
+    /// value.
+    LessThanOrEqualTo(T),
+
+    /// Restrict cell input to values that are between the target values.
+    Between(T, T),
+
+    /// Restrict cell input to values that are not between the target values.
+    NotBetween(T, T),
+}
+
+impl<T: IntoDataValidationValue> DataValidationRule<T> {
+    // Convert the generic rule values to strings for storage.
+    fn to_internal_rule(&self) -> DataValidationRuleInternal {
+        match self {
+            DataValidationRule::EqualTo(value) => {
+                DataValidationRuleInternal::EqualTo(value.to_string_value())
+            }
+            DataValidationRule::NotEqualTo(value) => {
+                DataValidationRuleInternal::NotEqualTo(value.to_string_value())
+            }
+            DataValidationRule::GreaterThan(value) => {
+                DataValidationRuleInternal::GreaterThan(value.to_string_value())
+            }
+            DataValidationRule::GreaterThanOrEqualTo(value) => {
+                DataValidationRuleInternal::GreaterThanOrEqualTo(value.to_string_value())
+            }
+            DataValidationRule::LessThan(value) => {
+                DataValidationRuleInternal::LessThan(value.to_string_value())
+            }
+            DataValidationRule::LessThanOrEqualTo(value) => {
+                DataValidationRuleInternal::LessThanOrEqualTo(value.to_string_value())
+            }
+            DataValidationRule::Between(min, max) => {
+                DataValidationRuleInternal::Between(min.to_string_value(), max.to_string_value())
+            }
+            DataValidationRule::NotBetween(min, max) => {
+                DataValidationRuleInternal::NotBetween(min.to_string_value(), max.to_string_value())
+            }
+        }
+    }
+}
+
+// Internal representation of the data validation rules with the values
+// converted to formula strings.
+#[derive(Clone)]
+pub(crate) enum DataValidationRuleInternal {
+    EqualTo(String),
+    NotEqualTo(String),
+    GreaterThan(String),
+    GreaterThanOrEqualTo(String),
+    LessThan(String),
+    LessThanOrEqualTo(String),
+    Between(String, String),
+    NotBetween(String, String),
+    ListSource(String),
+    CustomFormula(String),
+    None,
+}
+
+// -----------------------------------------------------------------------
+// IntoDataValidationValue
+// -----------------------------------------------------------------------
+
+/// Trait to map rust types into data validation types.
+///
+/// The `IntoDataValidationValue` trait is used to map Rust types like numbers,
+/// dates, times and formulas into a string representation that can be used in
+/// a [`DataValidationRule`].
+///
+pub trait IntoDataValidationValue {
+    /// Function to turn types into a string value.
+    fn to_string_value(&self) -> String;
+}
+
+macro_rules! data_validation_value_from_number {
+    ($($t:ty)*) => ($(
+        impl IntoDataValidationValue for $t {
+            fn to_string_value(&self) -> String {
+                self.to_string()
+            }
+        }
+    )*)
+}
+data_validation_value_from_number!(u8 i8 u16 i16 u32 i32 f32 f64);
+
+impl IntoDataValidationValue for Formula {
+    fn to_string_value(&self) -> String {
+        self.expand_formula(true).to_string()
+    }
+}
+
+impl IntoDataValidationValue for ExcelDateTime {
+    fn to_string_value(&self) -> String {
+        self.to_excel().to_string()
+    }
+}
+
+impl IntoDataValidationValue for &ExcelDateTime {
+    fn to_string_value(&self) -> String {
+        self.to_excel().to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoDataValidationValue for &NaiveDate {
+    fn to_string_value(&self) -> String {
+        ExcelDateTime::chrono_date_to_excel(self).to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoDataValidationValue for &NaiveDateTime {
+    fn to_string_value(&self) -> String {
+        ExcelDateTime::chrono_datetime_to_excel(self).to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoDataValidationValue for &NaiveTime {
+    fn to_string_value(&self) -> String {
+        ExcelDateTime::chrono_time_to_excel(self).to_string()
+    }
+}
+
+// -----------------------------------------------------------------------
+// DataValidationType
+// -----------------------------------------------------------------------
+
+// The Excel data validation types.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DataValidationType {
+    Whole,
+    Decimal,
+    Date,
+    Time,
+    TextLength,
+    List,
+    Custom,
+    Any,
+}
+
+impl fmt::Display for DataValidationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            Self::Date => write!(f, "date"),
+            Self::List => write!(f, "list"),
+            Self::Time => write!(f, "time"),
+            Self::Whole => write!(f, "whole"),
+            Self::Custom => write!(f, "custom"),
+            Self::Decimal => write!(f, "decimal"),
+            Self::TextLength => write!(f, "textLength"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// DataValidationErrorStyle
+// -----------------------------------------------------------------------
+
+/// The `DataValidationErrorStyle` enum defines the type of error dialog that
+/// is shown when there is an error in a data validation.
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DataValidationErrorStyle {
+    /// Show a "Stop" dialog that prevents the user from entering invalid data.
+    /// This is the default.
+    Stop,
+
+    /// Show a "Warning" dialog that allows the user to accept the invalid
+    /// data.
+    Warning,
+
+    /// Show an "Information" dialog that allows the user to accept the invalid
+    /// data.
+    Information,
+}
@@ -0,0 +1,219 @@
+// data_validation unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod data_validation_tests {
+
+    use crate::test_functions::xml_to_vec;
+    use crate::worksheet::*;
+    use crate::DataValidation;
+    use crate::DataValidationErrorStyle;
+    use crate::DataValidationRule;
+    use crate::ExcelDateTime;
+    use crate::Formula;
+    use crate::XlsxError;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn data_validation_list_range01() {
+        let data_validation = DataValidation::new().allow_list_range("Sheet2", 0, 0, 4, 0);
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>Sheet2!$A$1:$A$5</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_list_range02() {
+        // Sheet names with spaces must be quoted.
+        let data_validation = DataValidation::new().allow_list_range("My Lists", 0, 0, 4, 0);
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>'My Lists'!$A$1:$A$5</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_list_range03() {
+        // Sheet names with single quotes must be doubly quoted.
+        let data_validation = DataValidation::new().allow_list_range("Bob's List", 0, 0, 4, 0);
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>'Bob''s List'!$A$1:$A$5</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_list_formula01() {
+        let data_validation =
+            DataValidation::new().allow_list_formula(Formula::new("='My Lists'!$A$1:$A$5"));
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>'My Lists'!$A$1:$A$5</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_list_strings01() -> Result<(), XlsxError> {
+        let data_validation = DataValidation::new().allow_list_strings(&["Foo", "Bar", "Baz"])?;
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>"Foo,Bar,Baz"</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_validation_whole_number01() {
+        let data_validation =
+            DataValidation::new().allow_whole_number(DataValidationRule::Between(1, 10));
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="whole" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>1</formula1><formula2>10</formula2></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_whole_number02() {
+        let data_validation = DataValidation::new()
+            .allow_whole_number(DataValidationRule::GreaterThan(5))
+            .set_error_style(DataValidationErrorStyle::Warning)
+            .set_error_title("Invalid")
+            .set_error_message("Value must be > 5");
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="whole" operator="greaterThan" errorStyle="warning" allowBlank="1" showInputMessage="1" showErrorMessage="1" errorTitle="Invalid" error="Value must be &gt; 5" sqref="B3"><formula1>5</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_date01() -> Result<(), XlsxError> {
+        let date = ExcelDateTime::from_ymd(2025, 1, 1)?;
+        let data_validation =
+            DataValidation::new().allow_date(DataValidationRule::GreaterThanOrEqualTo(date));
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation type="date" operator="greaterThanOrEqual" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3"><formula1>45658</formula1></dataValidation>"#;
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_validation_any01() {
+        let data_validation = DataValidation::new().set_input_message("Enter a value");
+
+        let got = data_validation.rule("B3");
+        let expected = r#"<dataValidation allowBlank="1" showInputMessage="1" showErrorMessage="1" prompt="Enter a value" sqref="B3"/>"#;
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn data_validation_errors() {
+        let mut worksheet = Worksheet::new();
+
+        // List strings are limited to 255 characters.
+        let list = ["ABCDEFGHIJ"; 26];
+        let result = DataValidation::new().allow_list_strings(&list);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Range order is reversed.
+        let data_validation = DataValidation::new();
+        let result = worksheet.add_data_validation(5, 0, 0, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        // Range exceeds Excel limits.
+        let result = worksheet.add_data_validation(0, 0, ROW_MAX, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn data_validation_worksheet01() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        let data_validation = DataValidation::new().allow_list_range("My Lists", 0, 0, 4, 0);
+        worksheet.add_data_validation(2, 1, 2, 1, &data_validation)?;
+
+        let data_validation =
+            DataValidation::new().allow_list_formula(Formula::new("=Lists!$A$1:$A$20"));
+        worksheet.add_data_validation(3, 1, 5, 1, &data_validation)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <dataValidations count="2">
+                <dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B3">
+                  <formula1>'My Lists'!$A$1:$A$5</formula1>
+                </dataValidation>
+                <dataValidation type="list" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B4:B6">
+                  <formula1>Lists!$A$1:$A$20</formula1>
+                </dataValidation>
+              </dataValidations>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_validation_message_lengths() {
+        let mut worksheet = Worksheet::new();
+
+        // Titles are limited to 32 characters.
+        let data_validation = DataValidation::new().set_input_title("a".repeat(33));
+        let result = worksheet.add_data_validation(0, 0, 0, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        let data_validation = DataValidation::new().set_error_title("a".repeat(33));
+        let result = worksheet.add_data_validation(0, 0, 0, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Messages are limited to 255 characters.
+        let data_validation = DataValidation::new().set_input_message("a".repeat(256));
+        let result = worksheet.add_data_validation(0, 0, 0, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        let data_validation = DataValidation::new().set_error_message("a".repeat(256));
+        let result = worksheet.add_data_validation(0, 0, 0, 0, &data_validation);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Lengths at the limits are allowed. The limit is in characters, not bytes.
+        let data_validation = DataValidation::new()
+            .set_input_title("é".repeat(32))
+            .set_input_message("é".repeat(255))
+            .set_error_title("a".repeat(32))
+            .set_error_message("a".repeat(255));
+        let result = worksheet.add_data_validation(0, 0, 0, 0, &data_validation);
+        assert!(result.is_ok());
+    }
+}
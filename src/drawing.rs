@@ -11,6 +11,8 @@ use crate::{xmlwriter::XMLWriter, ObjectMovement, Url};
 pub struct Drawing {
     pub(crate) writer: XMLWriter,
     pub(crate) drawings: Vec<DrawingInfo>,
+    pub(crate) is_chartsheet: bool,
+    pub(crate) is_portrait: bool,
 }
 
 impl Drawing {
@@ -25,6 +27,8 @@ impl Drawing {
         Drawing {
             writer,
             drawings: vec![],
+            is_chartsheet: false,
+            is_portrait: false,
         }
     }
 
@@ -40,8 +44,13 @@ impl Drawing {
         self.write_ws_dr();
 
         for (index, drawing) in self.drawings.clone().iter().enumerate() {
-            // Write the xdr:twoCellAnchor element.
-            self.write_two_cell_anchor((index + 1) as u32, drawing);
+            if self.is_chartsheet {
+                // Write the xdr:absoluteAnchor element.
+                self.write_absolute_anchor((index + 1) as u32, drawing);
+            } else {
+                // Write the xdr:twoCellAnchor element.
+                self.write_two_cell_anchor((index + 1) as u32, drawing);
+            }
         }
 
         // Close the end tag.
@@ -87,12 +96,51 @@ impl Drawing {
         match drawing_info.drawing_type {
             DrawingType::Image => self.write_pic(index, drawing_info),
             DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
+            DrawingType::ChartEx => self.write_chartex_alternate_content(index, drawing_info),
+            // Notes are written to a VML file and not to a drawing.
+            DrawingType::Note => {}
         }
 
         self.writer.xml_empty_tag_only("xdr:clientData");
         self.writer.xml_end_tag("xdr:twoCellAnchor");
     }
 
+    // Write the <xdr:absoluteAnchor> element. This is used for the chart in a
+    // chartsheet and the dimensions are the Excel defaults for a full page.
+    fn write_absolute_anchor(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        self.writer.xml_start_tag_only("xdr:absoluteAnchor");
+
+        if self.is_portrait {
+            self.write_pos(0, -47625);
+            self.write_xdr_ext(6_162_675, 6_124_575);
+        } else {
+            self.write_pos(0, 0);
+            self.write_xdr_ext(9_308_969, 6_078_325);
+        }
+
+        match drawing_info.drawing_type {
+            DrawingType::ChartEx => self.write_chartex_alternate_content(index, drawing_info),
+            _ => self.write_graphic_frame(index, drawing_info),
+        }
+
+        self.writer.xml_empty_tag_only("xdr:clientData");
+        self.writer.xml_end_tag("xdr:absoluteAnchor");
+    }
+
+    // Write the <xdr:pos> element.
+    fn write_pos(&mut self, x: i32, y: i32) {
+        let attributes = [("x", x.to_string()), ("y", y.to_string())];
+
+        self.writer.xml_empty_tag("xdr:pos", &attributes);
+    }
+
+    // Write the <xdr:ext> element.
+    fn write_xdr_ext(&mut self, cx: u32, cy: u32) {
+        let attributes = [("cx", cx.to_string()), ("cy", cy.to_string())];
+
+        self.writer.xml_empty_tag("xdr:ext", &attributes);
+    }
+
     // Write the <xdr:from> element.
     fn write_from(&mut self, coords: &DrawingCoordinates) {
         self.writer.xml_start_tag_only("xdr:from");
@@ -335,11 +383,122 @@ impl Drawing {
         self.write_xfrm();
 
         // Write the a:graphic element.
-        self.write_a_graphic(drawing_info.rel_id);
+        if matches!(drawing_info.drawing_type, DrawingType::ChartEx) {
+            self.write_chartex_a_graphic(drawing_info.rel_id);
+        } else {
+            self.write_a_graphic(drawing_info.rel_id);
+        }
 
         self.writer.xml_end_tag("xdr:graphicFrame");
     }
 
+    // Write the <mc:AlternateContent> element for chartex charts such as
+    // Histograms. Older versions of Excel display the fallback text box instead
+    // of the chart.
+    fn write_chartex_alternate_content(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        let attributes = [(
+            "xmlns:mc",
+            "http://schemas.openxmlformats.org/markup-compatibility/2006",
+        )];
+
+        self.writer
+            .xml_start_tag("mc:AlternateContent", &attributes);
+
+        let attributes = [
+            (
+                "xmlns:cx1",
+                "http://schemas.microsoft.com/office/drawing/2015/9/8/chartex",
+            ),
+            ("Requires", "cx1"),
+        ];
+
+        self.writer.xml_start_tag("mc:Choice", &attributes);
+
+        // Write the xdr:graphicFrame element.
+        self.write_graphic_frame(index, drawing_info);
+
+        self.writer.xml_end_tag("mc:Choice");
+
+        self.writer.xml_start_tag_only("mc:Fallback");
+
+        // Write the xdr:sp element.
+        self.write_chartex_fallback_sp(drawing_info);
+
+        self.writer.xml_end_tag("mc:Fallback");
+        self.writer.xml_end_tag("mc:AlternateContent");
+    }
+
+    // Write the <a:graphic> element for chartex charts.
+    fn write_chartex_a_graphic(&mut self, index: u32) {
+        self.writer.xml_start_tag_only("a:graphic");
+
+        let attributes = [(
+            "uri",
+            "http://schemas.microsoft.com/office/drawing/2014/chartex",
+        )];
+
+        self.writer.xml_start_tag("a:graphicData", &attributes);
+
+        // Write the cx:chart element.
+        let attributes = [
+            (
+                "xmlns:cx",
+                "http://schemas.microsoft.com/office/drawing/2014/chartex".to_string(),
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
+            ),
+            ("r:id", format!("rId{index}")),
+        ];
+
+        self.writer.xml_empty_tag("cx:chart", &attributes);
+
+        self.writer.xml_end_tag("a:graphicData");
+        self.writer.xml_end_tag("a:graphic");
+    }
+
+    // Write the <xdr:sp> fallback text box element for chartex charts.
+    fn write_chartex_fallback_sp(&mut self, drawing_info: &DrawingInfo) {
+        let attributes = [("macro", ""), ("textlink", "")];
+
+        self.writer.xml_start_tag("xdr:sp", &attributes);
+
+        // Write the xdr:nvSpPr element.
+        self.writer.xml_start_tag_only("xdr:nvSpPr");
+        self.writer
+            .xml_empty_tag("xdr:cNvPr", &[("id", "0"), ("name", "")]);
+        self.writer.xml_start_tag_only("xdr:cNvSpPr");
+        self.writer
+            .xml_empty_tag("a:spLocks", &[("noTextEdit", "1")]);
+        self.writer.xml_end_tag("xdr:cNvSpPr");
+        self.writer.xml_end_tag("xdr:nvSpPr");
+
+        // Write the xdr:spPr element.
+        self.write_sp_pr(drawing_info);
+
+        // Write the xdr:txBody element.
+        self.writer.xml_start_tag_only("xdr:txBody");
+        self.writer.xml_empty_tag(
+            "a:bodyPr",
+            &[("vertOverflow", "clip"), ("horzOverflow", "clip")],
+        );
+        self.writer.xml_empty_tag_only("a:lstStyle");
+        self.writer.xml_start_tag_only("a:p");
+        self.writer.xml_start_tag_only("a:r");
+        self.writer
+            .xml_empty_tag("a:rPr", &[("lang", "en-US"), ("sz", "1100")]);
+        self.writer.xml_data_element_only(
+            "a:t",
+            "This chart isn't available in your version of Excel.",
+        );
+        self.writer.xml_end_tag("a:r");
+        self.writer.xml_end_tag("a:p");
+        self.writer.xml_end_tag("xdr:txBody");
+
+        self.writer.xml_end_tag("xdr:sp");
+    }
+
     // Write the <xdr:nvGraphicFramePr> element.
     fn write_nv_graphic_frame_pr(&mut self, index: u32, drawing_info: &DrawingInfo) {
         self.writer.xml_start_tag_only("xdr:nvGraphicFramePr");
@@ -355,7 +514,17 @@ impl Drawing {
 
     // Write the <xdr:cNvGraphicFramePr> element.
     fn write_c_nv_graphic_frame_pr(&mut self) {
-        self.writer.xml_empty_tag_only("xdr:cNvGraphicFramePr");
+        if self.is_chartsheet {
+            self.writer.xml_start_tag_only("xdr:cNvGraphicFramePr");
+
+            let attributes = [("noGrp", "1")];
+            self.writer
+                .xml_empty_tag("a:graphicFrameLocks", &attributes);
+
+            self.writer.xml_end_tag("xdr:cNvGraphicFramePr");
+        } else {
+            self.writer.xml_empty_tag_only("xdr:cNvGraphicFramePr");
+        }
     }
 
     // Write the <xdr:xfrm> element.
@@ -460,6 +629,8 @@ pub(crate) struct DrawingInfo {
 pub(crate) enum DrawingType {
     Image,
     Chart,
+    ChartEx,
+    Note,
 }
 
 // Trait for object such as Images and Charts that translate to a Drawing object.
@@ -100,4 +100,187 @@ mod drawing_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_chartex() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 2,
+            row: 0,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 9,
+            row: 14,
+            col_offset: 304800.0,
+            row_offset: 76200.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 1219200,
+            row_absolute: 0,
+            width: 4572000.0,
+            height: 2743200.0,
+            name: String::new(),
+            description: String::new(),
+            decorative: false,
+            rel_id: 1,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::ChartEx,
+            url: None,
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor>
+                    <xdr:from>
+                    <xdr:col>2</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>0</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>9</xdr:col>
+                    <xdr:colOff>304800</xdr:colOff>
+                    <xdr:row>14</xdr:row>
+                    <xdr:rowOff>76200</xdr:rowOff>
+                    </xdr:to>
+                    <mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+                    <mc:Choice xmlns:cx1="http://schemas.microsoft.com/office/drawing/2015/9/8/chartex" Requires="cx1">
+                        <xdr:graphicFrame macro="">
+                        <xdr:nvGraphicFramePr>
+                            <xdr:cNvPr id="2" name="Chart 1"/>
+                            <xdr:cNvGraphicFramePr/>
+                        </xdr:nvGraphicFramePr>
+                        <xdr:xfrm>
+                            <a:off x="0" y="0"/>
+                            <a:ext cx="0" cy="0"/>
+                        </xdr:xfrm>
+                        <a:graphic>
+                            <a:graphicData uri="http://schemas.microsoft.com/office/drawing/2014/chartex">
+                            <cx:chart xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1"/>
+                            </a:graphicData>
+                        </a:graphic>
+                        </xdr:graphicFrame>
+                    </mc:Choice>
+                    <mc:Fallback>
+                        <xdr:sp macro="" textlink="">
+                        <xdr:nvSpPr>
+                            <xdr:cNvPr id="0" name=""/>
+                            <xdr:cNvSpPr>
+                            <a:spLocks noTextEdit="1"/>
+                            </xdr:cNvSpPr>
+                        </xdr:nvSpPr>
+                        <xdr:spPr>
+                            <a:xfrm>
+                            <a:off x="1219200" y="0"/>
+                            <a:ext cx="4572000" cy="2743200"/>
+                            </a:xfrm>
+                            <a:prstGeom prst="rect">
+                            <a:avLst/>
+                            </a:prstGeom>
+                        </xdr:spPr>
+                        <xdr:txBody>
+                            <a:bodyPr vertOverflow="clip" horzOverflow="clip"/>
+                            <a:lstStyle/>
+                            <a:p>
+                            <a:r>
+                                <a:rPr lang="en-US" sz="1100"/>
+                                <a:t>This chart isn't available in your version of Excel.</a:t>
+                            </a:r>
+                            </a:p>
+                        </xdr:txBody>
+                        </xdr:sp>
+                    </mc:Fallback>
+                    </mc:AlternateContent>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_chartsheet() {
+        let mut drawing = Drawing::new();
+        drawing.is_chartsheet = true;
+
+        let coordinates = DrawingCoordinates {
+            col: 0,
+            row: 0,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from: coordinates.clone(),
+            to: coordinates,
+            col_absolute: 0,
+            row_absolute: 0,
+            width: 4572000.0,
+            height: 2743200.0,
+            name: String::new(),
+            description: String::new(),
+            decorative: false,
+            rel_id: 1,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::Chart,
+            url: None,
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                  <xdr:absoluteAnchor>
+                    <xdr:pos x="0" y="0"/>
+                    <xdr:ext cx="9308969" cy="6078325"/>
+                    <xdr:graphicFrame macro="">
+                      <xdr:nvGraphicFramePr>
+                        <xdr:cNvPr id="2" name="Chart 1"/>
+                        <xdr:cNvGraphicFramePr>
+                          <a:graphicFrameLocks noGrp="1"/>
+                        </xdr:cNvGraphicFramePr>
+                      </xdr:nvGraphicFramePr>
+                      <xdr:xfrm>
+                        <a:off x="0" y="0"/>
+                        <a:ext cx="0" cy="0"/>
+                      </xdr:xfrm>
+                      <a:graphic>
+                        <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart">
+                          <c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1"/>
+                        </a:graphicData>
+                      </a:graphic>
+                    </xdr:graphicFrame>
+                    <xdr:clientData/>
+                  </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
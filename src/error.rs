@@ -69,6 +69,10 @@ pub enum XlsxError {
     /// The table range overlaps a previous table range.
     TableRangeOverlaps(String, String),
 
+    /// The worksheet autofilter range overlaps a table range. Tables have
+    /// their own autofilter so the two can't be applied to the same cells.
+    AutofilterOverlapsTable(String, String),
+
     /// URL string exceeds Excel's url of 2080 characters.
     MaxUrlLengthExceeded,
 
@@ -140,6 +144,10 @@ pub enum XlsxError {
     /// incorrect or missing.
     ConditionalFormatError(String),
 
+    /// A general error that is raised when a data validation parameter is
+    /// incorrect or missing.
+    DataValidationError(String),
+
     /// A customizable error that can be used by third parties to raise errors
     /// or to convert other Error types to.
     CustomError(String),
@@ -244,6 +252,13 @@ impl fmt::Display for XlsxError {
                 )
             }
 
+            XlsxError::AutofilterOverlapsTable(autofilter, table) => {
+                write!(
+                    f,
+                    "Autofilter range {autofilter} overlaps with table range {table}. Use the table autofilter instead."
+                )
+            }
+
             XlsxError::MaxUrlLengthExceeded => {
                 write!(f, "URL string exceeds Excel's limit of 2083 characters.")
             }
@@ -291,6 +306,10 @@ impl fmt::Display for XlsxError {
                 write!(f, "Conditional format error: '{error}'.")
             }
 
+            XlsxError::DataValidationError(error) => {
+                write!(f, "Data validation error: '{error}'.")
+            }
+
             XlsxError::CustomError(error) => {
                 write!(f, "{error}")
             }
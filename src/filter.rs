@@ -6,6 +6,8 @@
 
 #![warn(missing_docs)]
 
+use crate::XlsxError;
+
 /// The `FilterCondition` struct is used to define autofilter rules.
 ///
 /// Autofilter rules are associated with ranges created using
@@ -27,6 +29,10 @@
 /// [`add_list_filter()`](FilterCondition::add_list_filter) and
 /// [`add_custom_filter()`](FilterCondition::add_custom_filter) methods.
 ///
+/// Excel also supports "Top 10" filters to show the largest or smallest
+/// numbers, or percentage of numbers, in a column. These are set via the
+/// [`add_top_filter()`](FilterCondition::add_top_filter) and related methods.
+///
 ///
 ///
 /// # Examples
@@ -401,6 +407,10 @@ pub struct FilterCondition {
     pub(crate) list: Vec<FilterData>,
     pub(crate) custom1: Option<FilterData>,
     pub(crate) custom2: Option<FilterData>,
+    pub(crate) is_top10_filter: bool,
+    pub(crate) top10_value: u16,
+    pub(crate) top10_is_bottom: bool,
+    pub(crate) top10_is_percent: bool,
 }
 
 #[allow(clippy::new_without_default)]
@@ -420,6 +430,10 @@ impl FilterCondition {
             list: vec![],
             custom1: None,
             custom2: None,
+            is_top10_filter: false,
+            top10_value: 0,
+            top10_is_bottom: false,
+            top10_is_percent: false,
         }
     }
 
@@ -496,6 +510,7 @@ impl FilterCondition {
         self.list
             .push(value.new_filter_data(FilterCriteria::EqualTo));
         self.is_list_filter = true;
+        self.is_top10_filter = false;
         self
     }
 
@@ -624,6 +639,7 @@ impl FilterCondition {
     pub fn add_list_blanks_filter(mut self) -> FilterCondition {
         self.should_match_blanks = true;
         self.is_list_filter = true;
+        self.is_top10_filter = false;
         self
     }
 
@@ -709,6 +725,7 @@ impl FilterCondition {
         }
 
         self.is_list_filter = false;
+        self.is_top10_filter = false;
         self
     }
 
@@ -724,6 +741,137 @@ impl FilterCondition {
         self.is_list_filter = false;
         self
     }
+
+    /// Add a "Top 10" filter condition to show the largest values.
+    ///
+    /// Add a "Top 10" style filter to a column in an autofilter range to show
+    /// the rows with the `n` largest numbers in the column. Like Excel, rows
+    /// with values that are equal to the smallest matching number are also
+    /// shown.
+    ///
+    /// A "Top 10" filter replaces any list or custom filter conditions in the
+    /// `FilterCondition`.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of top items to show, in the range 1-500.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting an autofilter to show the
+    /// top 3 values in a column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_filter_column8.rs
+    /// #
+    /// # use rust_xlsxwriter::{FilterCondition, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet with some sample data to filter.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_string(0, 0, "Region")?;
+    /// #     worksheet.write_string(1, 0, "East")?;
+    /// #     worksheet.write_string(2, 0, "West")?;
+    /// #     worksheet.write_string(3, 0, "East")?;
+    /// #     worksheet.write_string(4, 0, "North")?;
+    /// #     worksheet.write_string(5, 0, "South")?;
+    /// #     worksheet.write_string(6, 0, "West")?;
+    /// #
+    /// #     worksheet.write_string(0, 1, "Sales")?;
+    /// #     worksheet.write_number(1, 1, 3000)?;
+    /// #     worksheet.write_number(2, 1, 8000)?;
+    /// #     worksheet.write_number(3, 1, 5000)?;
+    /// #     worksheet.write_number(4, 1, 4000)?;
+    /// #     worksheet.write_number(5, 1, 7000)?;
+    /// #     worksheet.write_number(6, 1, 9000)?;
+    /// #
+    /// #     // Set the autofilter.
+    /// #     worksheet.autofilter(0, 0, 6, 1)?;
+    /// #
+    ///     // Set a filter condition to only show the top 3 sales values.
+    ///     let filter_condition = FilterCondition::new().add_top_filter(3);
+    ///     worksheet.filter_column(1, &filter_condition)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_top_filter(self, n: u16) -> FilterCondition {
+        self.set_top10_filter(n, false, false)
+    }
+
+    /// Add a "Top 10" filter condition to show the smallest values.
+    ///
+    /// This is the same as
+    /// [`add_top_filter()`](FilterCondition::add_top_filter) except that it
+    /// shows the rows with the `n` smallest numbers in the column.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of bottom items to show, in the range 1-500.
+    ///
+    pub fn add_bottom_filter(self, n: u16) -> FilterCondition {
+        self.set_top10_filter(n, true, false)
+    }
+
+    /// Add a "Top 10" filter condition to show the largest percent of values.
+    ///
+    /// This is the same as
+    /// [`add_top_filter()`](FilterCondition::add_top_filter) except that it
+    /// shows the rows with the largest `n` percent of the numbers in the
+    /// column.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The percentage of top items to show, in the range 1-100.
+    ///
+    pub fn add_top_percent_filter(self, n: u16) -> FilterCondition {
+        self.set_top10_filter(n, false, true)
+    }
+
+    /// Add a "Top 10" filter condition to show the smallest percent of values.
+    ///
+    /// This is the same as
+    /// [`add_top_filter()`](FilterCondition::add_top_filter) except that it
+    /// shows the rows with the smallest `n` percent of the numbers in the
+    /// column.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The percentage of bottom items to show, in the range 1-100.
+    ///
+    pub fn add_bottom_percent_filter(self, n: u16) -> FilterCondition {
+        self.set_top10_filter(n, true, true)
+    }
+
+    // Set the properties of the "Top 10" filter variants.
+    fn set_top10_filter(mut self, n: u16, is_bottom: bool, is_percent: bool) -> FilterCondition {
+        self.is_top10_filter = true;
+        self.is_list_filter = false;
+        self.top10_value = n;
+        self.top10_is_bottom = is_bottom;
+        self.top10_is_percent = is_percent;
+        self
+    }
+
+    // Check the "Top 10" filter value is in the range allowed by Excel.
+    pub(crate) fn validate_top10(&self) -> Result<(), XlsxError> {
+        let max_value = if self.top10_is_percent { 100 } else { 500 };
+
+        if self.top10_value == 0 || self.top10_value > max_value {
+            let error = format!(
+                "Top 10 filter value '{}' must be in the range 1-{max_value}",
+                self.top10_value
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        Ok(())
+    }
 }
 
 /// The `FilterCriteria` enum defines logical filter criteria used in an
@@ -141,6 +141,12 @@ use std::{collections::HashMap, fmt, hash::Hash, sync::OnceLock};
 ///
 /// <img src="https://rustxlsxwriter.github.io/images/format_clone.png">
 ///
+/// Formats aren't tied to the workbook or worksheet that they are used in.
+/// The workbook stores its own copy of each unique format when it is used, so
+/// a set of prepared formats can be reused to generate any number of
+/// workbooks. Duplicate formats are merged into a single Excel style in each
+/// workbook.
+///
 ///
 /// # Format methods and Format properties
 ///
@@ -410,6 +416,13 @@ use std::{collections::HashMap, fmt, hash::Hash, sync::OnceLock};
 /// use US locale formatting which will then be rendered in the settings of your
 /// host OS.
 ///
+/// # Format equality
+///
+/// `Format` implements [`PartialEq`], [`Eq`] and [`Hash`] based on its
+/// visible properties only. Two independently created formats with the same
+/// properties compare as equal and have the same hash. This allows formats to
+/// be used as keys in a [`HashMap`] or [`HashSet`](std::collections::HashSet).
+///
 #[derive(Debug, Clone, Eq)]
 
 pub struct Format {
@@ -529,6 +542,47 @@ impl Format {
         }
     }
 
+    /// Create a new Format object with the text number format.
+    ///
+    /// This is a shortcut for `Format::new().set_num_format_index(49)`, which
+    /// is Excel's built-in `@` text format. The text format tells Excel to treat the cell contents as text so that
+    /// strings such as IDs with leading zeros, like "00123", or strings that
+    /// look like dates aren't converted to numbers when the cell is edited.
+    ///
+    /// This is different from [`Format::set_quote_prefix()`], which only
+    /// affects how a string is handled after editing. The two can be combined
+    /// to handle all cases where data should be kept as text.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a text format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_text.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::text();
+    ///
+    ///     // The ID will be displayed as written and will stay as text if the
+    ///     // cell is edited in Excel.
+    ///     worksheet.write_string_with_format(0, 0, "00123", &format)?;
+    ///
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn text() -> Format {
+        Format::new().set_num_format_index(49)
+    }
+
     // -----------------------------------------------------------------------
     // Crate private methods.
     // -----------------------------------------------------------------------
@@ -1049,12 +1103,48 @@ impl Format {
 
     /// Set the Format font scheme property.
     ///
-    /// This function is implemented for completeness but is rarely used in
-    /// practice.
+    /// The font scheme links a font to the "minor" (body) or "major"
+    /// (headings) font of the workbook theme, rather than to a fixed font
+    /// name. Excel then uses the theme font, so the format follows the active
+    /// theme. The default font, Calibri, uses the minor scheme.
+    ///
+    /// The scheme is cleared by [`Format::set_font_name()`] for fonts other
+    /// than Calibri, so it should be set after the font name. The font name
+    /// can be set to an empty string, `set_font_name("")`, to omit it from
+    /// the file so that Excel only uses the theme font.
     ///
     /// # Parameters
     ///
-    /// * `font_scheme` - The font scheme property.
+    /// * `font_scheme` - The font scheme property. This can be a
+    ///   [`FormatFontScheme`] value or the equivalent Excel string such as
+    ///   `"major"`.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the font scheme for a
+    /// format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_set_font_scheme.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatFontScheme, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new()
+    ///         .set_font_name("Cambria")
+    ///         .set_font_scheme(FormatFontScheme::Major);
+    ///
+    ///     worksheet.write_string_with_format(0, 0, "Heading", &format)?;
+    ///
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
     ///
     pub fn set_font_scheme(mut self, font_scheme: impl Into<String>) -> Format {
         self.font.scheme = font_scheme.into();
@@ -1337,11 +1427,15 @@ impl Format {
     /// in conjunction with the [Left](FormatAlign::Left),
     /// [Right](FormatAlign::Right) and [Distributed](FormatAlign::Distributed)
     /// alignments. It will override any other horizontal properties that don't
-    /// support indentation.
+    /// support indentation. In these cases the alignment defaults to left, or
+    /// to right if the format has a right to left reading direction, see
+    /// [`Format::set_reading_direction()`].
     ///
     /// # Parameters
     ///
-    /// * `indent` - The indentation level for the cell.
+    /// * `indent` - The indentation level for the cell. Excel allows a
+    ///   maximum indent of 250. Larger values are clamped to 250 with a
+    ///   warning.
     ///
     /// # Examples
     ///
@@ -1376,7 +1470,13 @@ impl Format {
     /// <img src="https://rustxlsxwriter.github.io/images/format_set_indent.png">
     ///
     pub fn set_indent(mut self, indent: u8) -> Format {
-        self.alignment.indent = indent;
+        if indent > 250 {
+            eprintln!("Indent '{indent}' outside Excel range: 0 <= indent <= 250. Setting to 250.");
+            self.alignment.indent = 250;
+        } else {
+            self.alignment.indent = indent;
+        }
+
         self
     }
 
@@ -1501,10 +1601,59 @@ impl Format {
         self
     }
 
+    /// Set the Format text reading order property using a named value.
+    ///
+    /// This is the same as [`Format::set_reading_direction()`] except that the
+    /// reading order is set with a [`FormatReadingOrder`] value instead of a
+    /// number. It can be used in conjunction with
+    /// [`Worksheet::set_right_to_left()`](crate::Worksheet::set_right_to_left())
+    /// which changes the layout direction of the whole worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// * `reading_order` - A [`FormatReadingOrder`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the text reading order.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_set_reading_order.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatReadingOrder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.set_column_width(0, 25)?;
+    /// #
+    ///     let format = Format::new().set_reading_order(FormatReadingOrder::RightToLeft);
+    ///
+    ///     worksheet.set_right_to_left(true);
+    ///     worksheet.write_string_with_format(0, 0, "نص عربي / English text", &format)?;
+    ///
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_reading_order(mut self, reading_order: FormatReadingOrder) -> Format {
+        self.alignment.reading_direction = reading_order as u8;
+        self
+    }
+
     /// Set the Format shrink property.
     ///
     /// This method can be used to shrink text so that it fits in a cell
     ///
+    /// In Excel shrink to fit is mutually exclusive with text wrap and with the
+    /// [Fill](FormatAlign::Fill), [Justify](FormatAlign::Justify) and
+    /// [Distributed](FormatAlign::Distributed) horizontal alignments. If any of
+    /// these are also set on the format then they take precedence and the
+    /// shrink property is ignored, regardless of the order in which they were
+    /// set. Use [`Format::unset_shrink()`] to turn the property off.
     ///
     /// # Examples
     ///
@@ -2399,6 +2548,88 @@ impl From<String> for Format {
     }
 }
 
+/// The `NumFormat` struct provides helpers for generating Excel number format
+/// strings that are difficult to remember.
+///
+/// The methods return a `String` that can be passed to
+/// [`Format::set_num_format()`].
+///
+/// # Examples
+///
+/// The following example demonstrates using the `NumFormat` helpers to create
+/// fraction number formats.
+///
+/// ```
+/// # // This code is available in examples/doc_numformat_fraction.rs
+/// #
+/// # use rust_xlsxwriter::{Format, NumFormat, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Fractions with up to 2 digits in the denominator, like "3 11/16".
+///     let format1 = Format::new().set_num_format(NumFormat::fraction(2));
+///
+///     // Fractions in halves, like "3 1/2".
+///     let format2 = Format::new().set_num_format(NumFormat::fraction_fixed(2));
+///
+///     worksheet.write_number_with_format(0, 0, 3.6875, &format1)?;
+///     worksheet.write_number_with_format(1, 0, 3.6875, &format2)?;
+/// #
+/// #     workbook.save("formats.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub struct NumFormat;
+
+impl NumFormat {
+    /// Create a fraction number format with a variable denominator.
+    ///
+    /// Excel displays the number as a fraction with a denominator of up to
+    /// `denominator_digits` digits, for example `# ?/?` for 1 digit,
+    /// `# ??/??` for 2 digits and `# ???/???` for 3 digits. These are the "Up
+    /// to one/two/three digits" fraction formats in Excel's format dialog.
+    ///
+    /// # Parameters
+    ///
+    /// - `denominator_digits`: The maximum number of digits in the
+    ///   denominator, in the range 1-5 supported by Excel. Values outside
+    ///   this range are clamped to it.
+    ///
+    pub fn fraction(denominator_digits: u8) -> String {
+        let digits = "?".repeat(usize::from(denominator_digits.clamp(1, 5)));
+
+        format!("# {digits}/{digits}")
+    }
+
+    /// Create a fraction number format with a fixed denominator.
+    ///
+    /// Excel displays the number as a fraction of the given denominator, for
+    /// example `# ?/2` for halves, `# ?/4` for quarters, and `# ??/16` for
+    /// sixteenths. These are the "As halves/quarters/etc." fraction formats in
+    /// Excel's format dialog.
+    ///
+    /// # Parameters
+    ///
+    /// - `denominator`: The fixed denominator of the fraction, in the range
+    ///   2-99999 supported by Excel. Values outside this range are clamped to
+    ///   it.
+    ///
+    pub fn fraction_fixed(denominator: u32) -> String {
+        let denominator = denominator.clamp(2, 99_999);
+
+        // The numerator is always less than the denominator so it needs one
+        // placeholder for each digit of the largest possible numerator.
+        let digits = "?".repeat((denominator - 1).to_string().len());
+
+        format!("# {digits}/{denominator}")
+    }
+}
+
 /// The `Color` enum defines Excel colors that can be used throughout the
 /// `rust_xlsxwriter` APIs.
 ///
@@ -2422,6 +2653,14 @@ impl From<String> for Format {
 /// Note, there are no plans to support anything other than the default Excel
 /// "Office" theme.
 ///
+/// `Color` doesn't have an alpha channel since Excel doesn't support
+/// transparency in cell fonts, borders or fills. Chart fills and lines do
+/// support transparency via [`ChartSolidFill::set_transparency()`] and
+/// [`ChartLine::set_transparency()`].
+///
+/// [`ChartSolidFill::set_transparency()`]: crate::ChartSolidFill::set_transparency
+/// [`ChartLine::set_transparency()`]: crate::ChartLine::set_transparency
+///
 /// # Examples
 ///
 /// The following example demonstrates using different `Color` enum values to
@@ -2813,6 +3052,22 @@ impl Color {
     }
 }
 
+/// Convert an RGB tuple of `(red, green, blue)` values into a [`Color`].
+impl From<(u8, u8, u8)> for Color {
+    fn from(value: (u8, u8, u8)) -> Color {
+        let (red, green, blue) = value;
+        Color::RGB(u32::from(red) << 16 | u32::from(green) << 8 | u32::from(blue))
+    }
+}
+
+/// Convert an RGB array of `[red, green, blue]` values into a [`Color`].
+impl From<[u8; 3]> for Color {
+    fn from(value: [u8; 3]) -> Color {
+        let [red, green, blue] = value;
+        Color::from((red, green, blue))
+    }
+}
+
 /// Trait to map types into an `Color` value.
 ///
 /// The `IntoColor` trait is used to map strings and other types, including
@@ -2827,6 +3082,8 @@ impl Color {
 ///   - Theme colors such as `Color::Theme(4, 3)`.
 /// - Html string variants such as `"#6495ED"` or `"6495ED"`.
 /// - [u32] variants such as 0xDAA520.
+/// - RGB tuples and arrays of [u8] values such as `(218, 165, 32)` or
+///   `[218, 165, 32]`.
 ///
 /// See the example below.
 ///
@@ -2912,6 +3169,18 @@ impl IntoColor for u32 {
     }
 }
 
+impl IntoColor for (u8, u8, u8) {
+    fn new_color(self) -> Color {
+        Color::from(self)
+    }
+}
+
+impl IntoColor for [u8; 3] {
+    fn new_color(self) -> Color {
+        Color::from(self)
+    }
+}
+
 impl IntoColor for &str {
     fn new_color(self) -> Color {
         let color = if let Some(hex_string) = self.strip_prefix('#') {
@@ -3191,6 +3460,52 @@ pub enum FormatScript {
     Subscript,
 }
 
+/// The `FormatReadingOrder` enum defines the [`Format`] text reading order.
+///
+/// It is used with [`Format::set_reading_order()`].
+///
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+pub enum FormatReadingOrder {
+    /// The reading order is determined by Excel depending on the text. This
+    /// is the default.
+    #[default]
+    ContextDependent = 0,
+
+    /// The text is displayed left to right, like English.
+    LeftToRight = 1,
+
+    /// The text is displayed right to left, like Hebrew or Arabic.
+    RightToLeft = 2,
+}
+
+/// The `FormatFontScheme` enum defines the [`Format`] font scheme property.
+///
+/// It is used with [`Format::set_font_scheme()`] to link a font to the
+/// minor or major font of the workbook theme.
+///
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+pub enum FormatFontScheme {
+    /// The font isn't linked to a theme font.
+    #[default]
+    None,
+
+    /// The font is linked to the theme body font.
+    Minor,
+
+    /// The font is linked to the theme headings font.
+    Major,
+}
+
+impl From<FormatFontScheme> for String {
+    fn from(value: FormatFontScheme) -> String {
+        match value {
+            FormatFontScheme::None => String::new(),
+            FormatFontScheme::Minor => "minor".to_string(),
+            FormatFontScheme::Major => "major".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
 /// The `FormatAlign` enum defines the vertical and horizontal alignment properties
 /// of a [`Format`].
@@ -9,6 +9,8 @@ mod format_tests {
 
     use crate::Color;
     use crate::Format;
+    use crate::IntoColor;
+    use crate::NumFormat;
 
     #[test]
     fn test_hex_value() {
@@ -54,4 +56,70 @@ mod format_tests {
 
         assert_eq!(format1, format2);
     }
+
+    #[test]
+    fn test_num_format_fraction() {
+        assert_eq!("# ?/?", NumFormat::fraction(0));
+        assert_eq!("# ?/?", NumFormat::fraction(1));
+        assert_eq!("# ??/??", NumFormat::fraction(2));
+        assert_eq!("# ???/???", NumFormat::fraction(3));
+        assert_eq!("# ?????/?????", NumFormat::fraction(5));
+        assert_eq!("# ?????/?????", NumFormat::fraction(255));
+
+        assert_eq!("# ?/2", NumFormat::fraction_fixed(0));
+        assert_eq!("# ?/2", NumFormat::fraction_fixed(2));
+        assert_eq!("# ?/4", NumFormat::fraction_fixed(4));
+        assert_eq!("# ?/8", NumFormat::fraction_fixed(8));
+        assert_eq!("# ?/10", NumFormat::fraction_fixed(10));
+        assert_eq!("# ??/16", NumFormat::fraction_fixed(16));
+        assert_eq!("# ??/100", NumFormat::fraction_fixed(100));
+        assert_eq!("# ?????/99999", NumFormat::fraction_fixed(99_999));
+        assert_eq!("# ?????/99999", NumFormat::fraction_fixed(u32::MAX));
+    }
+
+    #[test]
+    fn test_color_from_rgb_tuple_and_array() {
+        assert_eq!(Color::RGB(0xFF0000), Color::from((255, 0, 0)));
+        assert_eq!(Color::RGB(0xDAA520), Color::from([218, 165, 32]));
+
+        assert_eq!("FF6495ED", (100, 149, 237).new_color().argb_hex_value());
+        assert_eq!("FF6495ED", [100, 149, 237].new_color().argb_hex_value());
+        assert_eq!("FF000000", (0, 0, 0).new_color().argb_hex_value());
+
+        // The Format color setters accept the tuple and array forms.
+        let format = Format::new()
+            .set_background_color((255, 0, 0))
+            .set_font_color([0x4F, 0x02, 0x6A]);
+
+        assert_eq!(Color::RGB(0xFF0000), format.fill.background_color);
+        assert_eq!(Color::RGB(0x4F026A), format.font.color);
+    }
+
+    #[test]
+    fn test_format_eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(format: &Format) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            format.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let format1 = Format::new().set_bold().set_font_color("#FF0000");
+        let mut format2 = Format::new().set_font_color("#FF0000").set_bold();
+        let format3 = Format::new().set_bold();
+
+        // Internal indices aren't part of the comparison.
+        format2.dxf_index = 5;
+        format2.set_font_index(3, true);
+
+        assert_eq!(format1, format2);
+        assert_eq!(hash_of(&format1), hash_of(&format2));
+        assert_ne!(format1, format3);
+
+        let formats = HashSet::from([format1, format2, format3]);
+        assert_eq!(formats.len(), 2);
+    }
 }
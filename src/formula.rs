@@ -803,6 +803,16 @@ impl Formula {
     /// Results](#formula-results) it is occasionally necessary to specify the
     /// result of a formula. This can be done using the `set_result()` method.
     ///
+    /// The result is stored as a number if it can be parsed as one. The
+    /// uppercase strings `"TRUE"` and `"FALSE"` are stored as Excel boolean
+    /// results. All other values, including other cases such as `"True"`, are
+    /// stored as string results.
+    ///
+    /// Note, in versions before the boolean handling was added `"TRUE"` and
+    /// `"FALSE"` were stored as strings. Applications that read the cached
+    /// result will now see a boolean value instead. This is the same as a file
+    /// saved by Excel.
+    ///
     /// # Parameters
     ///
     /// `result` - The formula result, as a string or string like type.
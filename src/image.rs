@@ -728,6 +728,40 @@ impl Image {
         self.height_dpi
     }
 
+    /// Get the displayed size of the image in Excel, in pixels.
+    ///
+    /// The `size_scaled()` method returns the `(width, height)` of the image
+    /// as it will be displayed in Excel. This takes into account the image
+    /// DPI, relative to Excel's default of 96, and any scaling set with
+    /// [`Image::set_scale_width()`], [`Image::set_scale_height()`] or
+    /// [`Image::set_scale_to_size()`]. It can be used to calculate the column
+    /// widths or row heights needed to fit the image before it is inserted.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the displayed size of a
+    /// scaled image.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_size_scaled.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let image = Image::new("examples/rust_logo.png")?
+    ///         .set_scale_width(2.0)
+    ///         .set_scale_height(0.5);
+    ///
+    ///     assert_eq!((212.0, 53.0), image.size_scaled());
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn size_scaled(&self) -> (f64, f64) {
+        (self.width_scaled(), self.height_scaled())
+    }
+
     // Get the image width as used by header/footer VML.
     pub(crate) fn vml_width(&self) -> f64 {
         // Scale the height/width by the resolution, relative to 72dpi.
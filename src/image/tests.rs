@@ -78,6 +78,28 @@ mod image_tests {
         }
     }
 
+    #[test]
+    fn size_scaled() {
+        // A 96 DPI image isn't scaled by the DPI.
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        assert_eq!((32.0, 32.0), image.size_scaled());
+
+        let image = Image::new("tests/input/images/red.png")
+            .unwrap()
+            .set_scale_width(2.0)
+            .set_scale_height(0.5);
+        assert_eq!((64.0, 16.0), image.size_scaled());
+
+        // A 72 DPI image is scaled up relative to 96 DPI.
+        let image = Image::new("tests/input/images/black_72.jpg").unwrap();
+        assert_eq!((64.0, 64.0), (image.width(), image.height()));
+        assert_eq!((72.0, 72.0), (image.width_dpi(), image.height_dpi()));
+        assert_eq!(
+            (64.0 * 96.0 / 72.0, 64.0 * 96.0 / 72.0),
+            image.size_scaled()
+        );
+    }
+
     #[test]
     fn unknown_file_format() {
         let filename = "tests/input/images/unknown.img".to_string();
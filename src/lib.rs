@@ -70,6 +70,9 @@
 //!   objects.
 //!  - [`Table`]: The interface for worksheet tables.
 //! - [`Image`]: The interface for images used in worksheets.
+//! - [`Note`]: The interface for cell notes used in worksheets.
+//! - [`ThreadedComment`]: The interface for threaded comments used in
+//!   worksheets.
 //! - [`Conditional Formats`](crate::conditional_format): Working with
 //!   conditional formatting in worksheets.
 //! - [`Sparklines`](crate::sparkline): Working with Sparklines.
@@ -180,9 +183,12 @@
 //!   formatting for 5,000,000 numeric cells.
 //!
 mod app;
+mod comment;
 mod content_types;
 mod core;
 mod custom;
+mod custom_xml;
+mod data_validation;
 mod datetime;
 mod drawing;
 mod error;
@@ -191,10 +197,14 @@ mod format;
 mod formula;
 mod image;
 mod metadata;
+mod note;
 mod packager;
+mod person_list;
 mod properties;
 mod protection;
 mod relationship;
+mod revision_headers;
+mod revision_log;
 mod rich_value;
 mod rich_value_rel;
 mod rich_value_structure;
@@ -204,7 +214,9 @@ mod shared_strings_table;
 mod styles;
 mod table;
 mod theme;
+mod threaded_comments;
 mod url;
+mod user_names;
 mod vml;
 mod xmlwriter;
 
@@ -225,12 +237,14 @@ pub mod worksheet;
 mod test_functions;
 
 // Re-export the public APIs.
+pub use data_validation::*;
 pub use datetime::*;
 pub use error::*;
 pub use filter::*;
 pub use format::*;
 pub use formula::*;
 pub use image::*;
+pub use note::*;
 pub use properties::*;
 pub use protection::*;
 pub use table::*;
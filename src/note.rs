@@ -0,0 +1,333 @@
+// note - A module for representing Excel cell notes and threaded comments.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use crate::drawing::{DrawingObject, DrawingType};
+use crate::{Color, IntoColor, ObjectMovement};
+
+/// The `Note` struct represents a worksheet note.
+///
+/// Notes are a feature of Excel that allows a user to add a text annotation to
+/// a cell. The note is indicated by a small red triangle in the top right
+/// corner of the cell and it is displayed when the user hovers over the cell.
+///
+/// In versions of Excel prior to Office 365 Notes were referred to as
+/// "Comments". The name Comment is now used for a newer style threaded
+/// comment and Note is used for the older non threaded version. Threaded
+/// comments are represented by the [`ThreadedComment`] struct.
+///
+/// Notes are added to a worksheet using the
+/// [`Worksheet::insert_note()`](crate::Worksheet::insert_note) method.
+///
+/// # Examples
+///
+/// The following example demonstrates adding notes to worksheet cells.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_insert_note.rs
+/// #
+/// use rust_xlsxwriter::{Note, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Write some data.
+///     worksheet.write(0, 0, "Price")?;
+///     worksheet.write(1, 0, 17.5)?;
+///
+///     // Add notes to the cells.
+///     let note = Note::new("Price excludes tax.").set_author("Finance");
+///     worksheet.insert_note(1, 0, &note)?;
+///
+///     let note = Note::new("Prices are in EUR.").set_visible(true);
+///     worksheet.insert_note(0, 0, &note)?;
+///
+///     workbook.save("notes.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Note {
+    pub(crate) text: String,
+    pub(crate) author: Option<String>,
+    pub(crate) is_visible: bool,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) background_color: Color,
+    pub(crate) x_offset: u32,
+    pub(crate) y_offset: u32,
+}
+
+impl Note {
+    /// Create a new Note object to represent an Excel cell note.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text of the note. Excel limits the text to 32,767
+    ///   characters.
+    ///
+    pub fn new(text: impl Into<String>) -> Note {
+        Note {
+            text: text.into(),
+            author: None,
+            is_visible: false,
+            width: 128,
+            height: 74,
+            background_color: Color::RGB(0xFFFFE1),
+            x_offset: 0,
+            y_offset: 0,
+        }
+    }
+
+    /// Set the author of the note.
+    ///
+    /// Excel stores an author name with each note. If it isn't set for an
+    /// individual note then the worksheet default author is used, see
+    /// [`Worksheet::set_default_note_author()`](crate::Worksheet::set_default_note_author).
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The note author name.
+    ///
+    pub fn set_author(mut self, name: impl Into<String>) -> Note {
+        self.author = Some(name.into());
+        self
+    }
+
+    /// Make the note visible when the file is opened.
+    ///
+    /// By default notes are hidden and only appear when the user hovers over
+    /// the parent cell. This method makes the note visible when the file is
+    /// opened.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_visible(mut self, enable: bool) -> Note {
+        self.is_visible = enable;
+        self
+    }
+
+    /// Set the width of the note box in pixels.
+    ///
+    /// # Parameters
+    ///
+    /// * `width` - The note width in pixels. The default is 128.
+    ///
+    pub fn set_width(mut self, width: u32) -> Note {
+        if width == 0 {
+            return self;
+        }
+
+        self.width = width;
+        self
+    }
+
+    /// Set the height of the note box in pixels.
+    ///
+    /// # Parameters
+    ///
+    /// * `height` - The note height in pixels. The default is 74.
+    ///
+    pub fn set_height(mut self, height: u32) -> Note {
+        if height == 0 {
+            return self;
+        }
+
+        self.height = height;
+        self
+    }
+
+    /// Set the background color of the note box.
+    ///
+    /// The default background color is the Excel note yellow `#FFFFE1`.
+    ///
+    /// # Parameters
+    ///
+    /// * `color` - The background color property defined by a [`Color`] enum
+    ///   value or a type that implements the [`IntoColor`] trait. Only RGB
+    ///   colors are supported in notes, theme colors are ignored.
+    ///
+    pub fn set_background_color(mut self, color: impl IntoColor) -> Note {
+        let color = color.new_color();
+        if color.is_valid()
+            && !matches!(color, Color::Theme(..) | Color::Automatic | Color::Default)
+        {
+            self.background_color = color;
+        }
+
+        self
+    }
+}
+
+// Trait for object such as Images and Charts that translate to a Drawing
+// object. Notes are stored in a VML file but the positioning is the same.
+impl DrawingObject for Note {
+    fn x_offset(&self) -> u32 {
+        self.x_offset
+    }
+
+    fn y_offset(&self) -> u32 {
+        self.y_offset
+    }
+
+    fn width_scaled(&self) -> f64 {
+        f64::from(self.width)
+    }
+
+    fn height_scaled(&self) -> f64 {
+        f64::from(self.height)
+    }
+
+    fn object_movement(&self) -> ObjectMovement {
+        ObjectMovement::MoveAndSizeWithCells
+    }
+
+    fn name(&self) -> String {
+        String::new()
+    }
+
+    fn alt_text(&self) -> String {
+        String::new()
+    }
+
+    fn decorative(&self) -> bool {
+        false
+    }
+
+    fn drawing_type(&self) -> DrawingType {
+        DrawingType::Note
+    }
+}
+
+/// The `ThreadedComment` struct represents a worksheet threaded comment.
+///
+/// Threaded comments are the newer style of Excel comment that supports a
+/// conversation of replies. They are added to a worksheet using the
+/// [`Worksheet::insert_threaded_comment()`](crate::Worksheet::insert_threaded_comment)
+/// method. The older style of comment is represented by [`Note`].
+///
+/// Each comment and reply is attributed to an author. The authors are stored
+/// in the workbook "persons" list.
+///
+/// Versions of Excel that don't support threaded comments display them as a
+/// note with the text of the comment and the replies.
+///
+/// # Examples
+///
+/// The following example demonstrates adding a threaded comment with a reply
+/// to a worksheet cell.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_insert_threaded_comment.rs
+/// #
+/// use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Write some data.
+///     worksheet.write(0, 0, "Budget")?;
+///     worksheet.write(1, 0, 5000)?;
+///
+///     // Add a threaded comment with a reply.
+///     let reply = ThreadedComment::new("Agreed, it is approved.").set_author("Finance");
+///     let comment = ThreadedComment::new("Is this the final figure?")
+///         .set_author("Sales")
+///         .add_reply(&reply);
+///
+///     worksheet.insert_threaded_comment(1, 0, &comment)?;
+///
+///     workbook.save("threaded_comments.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ThreadedComment {
+    pub(crate) text: String,
+    pub(crate) author: Option<String>,
+    pub(crate) replies: Vec<ThreadedComment>,
+}
+
+impl ThreadedComment {
+    /// Create a new ThreadedComment object to represent an Excel threaded
+    /// comment.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text of the comment. Excel limits the text to 32,767
+    ///   characters.
+    ///
+    pub fn new(text: impl Into<String>) -> ThreadedComment {
+        ThreadedComment {
+            text: text.into(),
+            author: None,
+            replies: vec![],
+        }
+    }
+
+    /// Set the author of the comment.
+    ///
+    /// If the author isn't set for a comment or reply then the worksheet
+    /// default author is used, see
+    /// [`Worksheet::set_default_note_author()`](crate::Worksheet::set_default_note_author).
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The comment author name.
+    ///
+    pub fn set_author(mut self, name: impl Into<String>) -> ThreadedComment {
+        self.author = Some(name.into());
+        self
+    }
+
+    /// Add a reply to the comment.
+    ///
+    /// Replies are displayed in the order that they are added. Excel doesn't
+    /// nest replies so any replies to the `reply` are added after it in the
+    /// same thread.
+    ///
+    /// # Parameters
+    ///
+    /// * `reply` - A [`ThreadedComment`] instance that represents the reply.
+    ///
+    pub fn add_reply(mut self, reply: &ThreadedComment) -> ThreadedComment {
+        let mut reply = reply.clone();
+        let mut nested_replies = std::mem::take(&mut reply.replies);
+
+        self.replies.push(reply);
+        self.replies.append(&mut nested_replies);
+        self
+    }
+}
+
+/// The `NotePrintPosition` enum defines how notes and threaded comments are
+/// printed with [`Worksheet::set_print_notes()`](crate::Worksheet::set_print_notes).
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NotePrintPosition {
+    /// Don't print notes or comments. This is the default.
+    None,
+
+    /// Print notes and threaded comments on a page at the end of the sheet.
+    AtEnd,
+
+    /// Print notes where they are displayed on the sheet. Only notes made
+    /// visible with [`Note::set_visible()`] are printed. Threaded comments
+    /// can't be displayed on the sheet so if the worksheet only has threaded
+    /// comments they are printed at the end of the sheet instead.
+    AsDisplayed,
+}
@@ -0,0 +1,182 @@
+// note unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod note_tests {
+
+    use crate::test_functions::read_xlsx_files;
+    use crate::worksheet::*;
+    use crate::{HeaderImagePosition, Image, Note, ThreadedComment, Workbook, XlsxError};
+
+    #[test]
+    fn note_errors() {
+        let mut worksheet = Worksheet::new();
+        let note = Note::new("Note");
+
+        // Row or column exceeds Excel's limits.
+        let result = worksheet.insert_note(ROW_MAX, 0, &note);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.insert_note(0, COL_MAX, &note);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Note text exceeds Excel's limit.
+        let note = Note::new("a".repeat(32_768));
+        let result = worksheet.insert_note(0, 0, &note);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+    }
+
+    #[test]
+    fn note_package_files() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet
+            .insert_note(1, 1, &Note::new("Some text"))
+            .unwrap();
+        worksheet.set_default_note_author("John");
+
+        let files = read_xlsx_files(&mut workbook);
+
+        let xml = &files["xl/worksheets/sheet1.xml"];
+        assert!(xml.contains(r#"<legacyDrawing r:id="rId1"/></worksheet>"#));
+
+        let xml = &files["xl/worksheets/_rels/sheet1.xml.rels"];
+        assert!(xml.contains(r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing" Target="../drawings/vmlDrawing1.vml"/>"#));
+        assert!(xml.contains(r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments1.xml"/>"#));
+
+        let xml = &files["[Content_Types].xml"];
+        assert!(xml.contains(r#"<Default Extension="vml" ContentType="application/vnd.openxmlformats-officedocument.vmlDrawing"/>"#));
+        assert!(xml.contains(r#"<Override PartName="/xl/comments1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml"/>"#));
+
+        let xml = &files["xl/comments1.xml"];
+        assert!(xml.contains(r#"<authors><author>John</author></authors>"#));
+        assert!(xml.contains(r#"<comment ref="B2" authorId="0">"#));
+
+        let xml = &files["xl/drawings/vmlDrawing1.vml"];
+        assert!(xml.contains(r#"<x:Anchor>2, 15, 0, 10, 4, 15, 4, 4</x:Anchor>"#));
+    }
+
+    #[test]
+    fn note_with_header_image() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        // The notes VML file is numbered before the header image VML file.
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+        worksheet.set_header("&L&[Picture]");
+        worksheet
+            .set_header_image(&image, HeaderImagePosition::Left)
+            .unwrap();
+        worksheet
+            .insert_note(0, 0, &Note::new("Some text"))
+            .unwrap();
+
+        let files = read_xlsx_files(&mut workbook);
+
+        let xml = &files["xl/worksheets/sheet1.xml"];
+        assert!(xml.contains(r#"<legacyDrawing r:id="rId1"/><legacyDrawingHF r:id="rId2"/>"#));
+
+        let xml = &files["xl/worksheets/_rels/sheet1.xml.rels"];
+        assert!(xml.contains(r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing" Target="../drawings/vmlDrawing1.vml"/>"#));
+        assert!(xml.contains(r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing" Target="../drawings/vmlDrawing2.vml"/>"#));
+        assert!(xml.contains(r#"<Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments1.xml"/>"#));
+
+        let xml = &files["xl/drawings/vmlDrawing1.vml"];
+        assert!(xml.contains(r#"<o:idmap v:ext="edit" data="1"/>"#));
+        assert!(xml.contains(r#"<x:Anchor>1, 15, 0, 2, 3, 15, 3, 16</x:Anchor>"#));
+
+        let xml = &files["xl/drawings/vmlDrawing2.vml"];
+        assert!(xml.contains(r#"<o:idmap v:ext="edit" data="2"/>"#));
+
+        assert!(files.contains_key("xl/drawings/_rels/vmlDrawing2.vml.rels"));
+    }
+
+    #[test]
+    fn threaded_comment_errors() {
+        let mut worksheet = Worksheet::new();
+        let comment = ThreadedComment::new("Comment");
+
+        // Row or column exceeds Excel's limits.
+        let result = worksheet.insert_threaded_comment(ROW_MAX, 0, &comment);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Reply text exceeds Excel's limit.
+        let reply = ThreadedComment::new("a".repeat(32_768));
+        let comment = ThreadedComment::new("Comment").add_reply(&reply);
+        let result = worksheet.insert_threaded_comment(0, 0, &comment);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+    }
+
+    #[test]
+    fn threaded_comment_replies() {
+        let reply1 = ThreadedComment::new("Reply 1");
+        let reply2 = ThreadedComment::new("Reply 2");
+        let reply3 = ThreadedComment::new("Reply 3").add_reply(&reply2);
+
+        // Replies to a reply are added to the same thread.
+        let comment = ThreadedComment::new("Comment")
+            .add_reply(&reply1)
+            .add_reply(&reply3);
+
+        let replies: Vec<&str> = comment.replies.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(replies, vec!["Reply 1", "Reply 3", "Reply 2"]);
+        assert!(comment.replies.iter().all(|reply| reply.replies.is_empty()));
+    }
+
+    #[test]
+    fn threaded_comment_package_files() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_default_note_author("John");
+
+        // A threaded comment replaces a note in the same cell.
+        worksheet.insert_note(0, 0, &Note::new("Note")).unwrap();
+        worksheet.insert_note(1, 1, &Note::new("Note")).unwrap();
+
+        let reply = ThreadedComment::new("Reply").set_author("Anne");
+        let comment = ThreadedComment::new("Comment").add_reply(&reply);
+        worksheet.insert_threaded_comment(1, 1, &comment).unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        let comment = ThreadedComment::new("Other").set_author("Anne");
+        worksheet.insert_threaded_comment(0, 0, &comment).unwrap();
+
+        let files = read_xlsx_files(&mut workbook);
+
+        let xml = &files["xl/worksheets/_rels/sheet1.xml.rels"];
+        assert!(xml.contains(r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments1.xml"/>"#));
+        assert!(xml.contains(r#"<Relationship Id="rId3" Type="http://schemas.microsoft.com/office/2017/10/relationships/threadedComment" Target="../threadedComments/threadedComment1.xml"/>"#));
+
+        let xml = &files["xl/_rels/workbook.xml.rels"];
+        assert!(xml.contains(r#"Type="http://schemas.microsoft.com/office/2017/10/relationships/person" Target="persons/person.xml"/>"#));
+
+        let xml = &files["[Content_Types].xml"];
+        assert!(xml.contains(r#"<Override PartName="/xl/threadedComments/threadedComment2.xml" ContentType="application/vnd.ms-excel.threadedcomments+xml"/>"#));
+        assert!(xml.contains(r#"<Override PartName="/xl/persons/person.xml" ContentType="application/vnd.ms-excel.person+xml"/>"#));
+
+        // The unique authors are stored once for the workbook.
+        let xml = &files["xl/persons/person.xml"];
+        assert!(xml.contains(r#"<person displayName="John" "#));
+        assert_eq!(xml.matches(r#"<person displayName="Anne" "#).count(), 1);
+
+        // The reply is linked to the first comment in the thread.
+        let xml = &files["xl/threadedComments/threadedComment1.xml"];
+        assert_eq!(xml.matches(r#"<threadedComment ref="B2" "#).count(), 2);
+        assert_eq!(xml.matches("parentId=").count(), 1);
+        assert!(xml.contains("<text>Comment</text>"));
+        assert!(xml.contains("<text>Reply</text>"));
+
+        // The comment is also stored as a note for older versions of Excel.
+        let xml = &files["xl/comments1.xml"];
+        assert!(xml.contains(r#"<authors><author>John</author><author>tc={"#));
+        assert!(xml.contains("Comment:\n    Comment\nReply:\n    Reply</t>"));
+
+        let xml = &files["xl/comments2.xml"];
+        assert!(xml.contains("Comment:\n    Other</t>"));
+    }
+}
@@ -50,12 +50,17 @@ use zip::write::SimpleFileOptions;
 use zip::{DateTime, ZipWriter};
 
 use crate::app::App;
+use crate::comment::Comment;
 use crate::content_types::ContentTypes;
 use crate::core::Core;
 use crate::custom::Custom;
+use crate::custom_xml::CustomXmlProperties;
 use crate::error::XlsxError;
 use crate::metadata::Metadata;
+use crate::person_list::PersonList;
 use crate::relationship::Relationship;
+use crate::revision_headers::RevisionHeaders;
+use crate::revision_log::RevisionLog;
 use crate::rich_value::RichValue;
 use crate::rich_value_rel::RichValueRel;
 use crate::rich_value_structure::RichValueStructure;
@@ -64,6 +69,8 @@ use crate::shared_strings::SharedStrings;
 use crate::shared_strings_table::SharedStringsTable;
 use crate::styles::Styles;
 use crate::theme::Theme;
+use crate::threaded_comments::ThreadedComments;
+use crate::user_names::UserNames;
 use crate::vml::Vml;
 use crate::workbook::Workbook;
 use crate::worksheet::Worksheet;
@@ -139,11 +146,26 @@ impl<W: Write + Seek + Send> Packager<W> {
             worksheet.assemble_xml_file();
         }
 
-        // Write the worksheet file and and associated rel files.
-        for (index, worksheet) in workbook.worksheets.iter_mut().enumerate() {
-            self.write_worksheet_file(worksheet, index + 1)?;
+        // Write the worksheet file and and associated rel files. Worksheets
+        // and chartsheets are numbered separately.
+        let mut worksheet_index = 1;
+        let mut chartsheet_index = 1;
+        for worksheet in &mut workbook.worksheets {
+            let index = if worksheet.is_chartsheet {
+                chartsheet_index
+            } else {
+                worksheet_index
+            };
+
+            self.write_worksheet_file(worksheet, index)?;
             if worksheet.has_relationships() {
-                self.write_worksheet_rels_file(worksheet, index + 1)?;
+                self.write_worksheet_rels_file(worksheet, index)?;
+            }
+
+            if worksheet.is_chartsheet {
+                chartsheet_index += 1;
+            } else {
+                worksheet_index += 1;
             }
         }
 
@@ -157,6 +179,8 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.write_drawing_files(workbook)?;
         self.write_vml_files(workbook)?;
+        self.write_comment_files(workbook)?;
+        self.write_threaded_comment_files(workbook, options)?;
         self.write_image_files(workbook)?;
         self.write_chart_files(workbook)?;
         self.write_table_files(workbook)?;
@@ -169,6 +193,10 @@ impl<W: Write + Seek + Send> Packager<W> {
                 self.write_drawing_rels_file(&worksheet.drawing_relationships, image_index)?;
                 image_index += 1;
             }
+            // The notes VML file doesn't have a rels file but it is counted.
+            if worksheet.has_notes() {
+                vml_index += 1;
+            }
             if !worksheet.vml_drawing_relationships.is_empty() {
                 self.write_vml_drawing_rels_file(&worksheet.vml_drawing_relationships, vml_index)?;
                 vml_index += 1;
@@ -184,6 +212,18 @@ impl<W: Write + Seek + Send> Packager<W> {
             self.write_rich_value_files(workbook, options)?;
         }
 
+        if options.num_threaded_comments > 0 {
+            self.write_person_file(options)?;
+        }
+
+        if options.has_revisions {
+            self.write_revision_files(options)?;
+        }
+
+        if options.num_custom_xml_parts > 0 {
+            self.write_custom_xml_files(workbook)?;
+        }
+
         // Close the zip file.
         self.zip.finish()?;
 
@@ -202,6 +242,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_worksheet_name(i + 1);
         }
 
+        for i in 0..options.num_chartsheets {
+            content_types.add_chartsheet_name(i + 1);
+        }
+
         for i in 0..options.num_drawings {
             content_types.add_drawing_name(i + 1);
         }
@@ -210,10 +254,26 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_chart_name(i + 1);
         }
 
+        for i in 0..options.num_chartex {
+            content_types.add_chartex_name(i + 1);
+        }
+
         for i in 0..options.num_tables {
             content_types.add_table_name(i + 1);
         }
 
+        for i in 0..options.num_comments {
+            content_types.add_comment_name(i + 1);
+        }
+
+        for i in 0..options.num_threaded_comments {
+            content_types.add_threaded_comment_name(i + 1);
+        }
+
+        if options.num_threaded_comments > 0 {
+            content_types.add_persons();
+        }
+
         if options.has_sst_table {
             content_types.add_share_strings();
         }
@@ -226,6 +286,14 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_rich_value();
         }
 
+        if options.has_revisions {
+            content_types.add_revisions();
+        }
+
+        for i in 0..options.num_custom_xml_parts {
+            content_types.add_custom_xml_properties(i + 1);
+        }
+
         if options.has_vml {
             content_types.add_default(
                 "vml",
@@ -284,12 +352,25 @@ impl<W: Write + Seek + Send> Packager<W> {
     fn write_workbook_rels_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
         let mut rels = Relationship::new();
 
-        for worksheet_index in 1..=options.num_worksheets {
-            rels.add_document_relationship(
-                "worksheet",
-                format!("worksheets/sheet{worksheet_index}.xml").as_str(),
-                "",
-            );
+        // The sheet relationships are in workbook order.
+        let mut worksheet_index = 1;
+        let mut chartsheet_index = 1;
+        for is_chartsheet in &options.chartsheet_flags {
+            if *is_chartsheet {
+                rels.add_document_relationship(
+                    "chartsheet",
+                    format!("chartsheets/sheet{chartsheet_index}.xml").as_str(),
+                    "",
+                );
+                chartsheet_index += 1;
+            } else {
+                rels.add_document_relationship(
+                    "worksheet",
+                    format!("worksheets/sheet{worksheet_index}.xml").as_str(),
+                    "",
+                );
+                worksheet_index += 1;
+            }
         }
 
         rels.add_document_relationship("theme", "theme/theme1.xml", "");
@@ -328,6 +409,20 @@ impl<W: Write + Seek + Send> Packager<W> {
             );
         }
 
+        if options.num_threaded_comments > 0 {
+            rels.add_office_relationship("2017/10", "person", "persons/person.xml", "");
+        }
+
+        if options.has_revisions {
+            rels.add_document_relationship("revisionHeaders", "revisions/revisionHeaders.xml", "");
+            rels.add_document_relationship("usernames", "revisions/userNames.xml", "");
+        }
+
+        for index in 1..=options.num_custom_xml_parts {
+            let target = format!("../customXml/item{index}.xml");
+            rels.add_document_relationship("customXml", &target, "");
+        }
+
         self.zip
             .start_file("xl/_rels/workbook.xml.rels", self.zip_options)?;
 
@@ -343,7 +438,11 @@ impl<W: Write + Seek + Send> Packager<W> {
         worksheet: &mut Worksheet,
         index: usize,
     ) -> Result<(), XlsxError> {
-        let filename = format!("xl/worksheets/sheet{index}.xml");
+        let filename = if worksheet.is_chartsheet {
+            format!("xl/chartsheets/sheet{index}.xml")
+        } else {
+            format!("xl/worksheets/sheet{index}.xml")
+        };
         self.zip.start_file(filename, self.zip_options)?;
         self.zip.write_all(worksheet.writer.xmlfile.get_ref())?;
 
@@ -370,7 +469,24 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
 
-        let filename = format!("xl/worksheets/_rels/sheet{index}.xml.rels");
+        for relationship in &worksheet.comment_relationships {
+            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+        }
+
+        for relationship in &worksheet.threaded_comment_relationships {
+            rels.add_office_relationship(
+                "2017/10",
+                &relationship.0,
+                &relationship.1,
+                &relationship.2,
+            );
+        }
+
+        let filename = if worksheet.is_chartsheet {
+            format!("xl/chartsheets/_rels/sheet{index}.xml.rels")
+        } else {
+            format!("xl/worksheets/_rels/sheet{index}.xml.rels")
+        };
 
         self.zip.start_file(filename, self.zip_options)?;
 
@@ -389,7 +505,17 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut rels = Relationship::new();
 
         for relationship in relationships {
-            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+            // Chartex charts use an Office 2014 relationship type.
+            if relationship.0 == "chartEx" {
+                rels.add_office_relationship(
+                    "2014",
+                    &relationship.0,
+                    &relationship.1,
+                    &relationship.2,
+                );
+            } else {
+                rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+            }
         }
 
         let filename = format!("xl/drawings/_rels/drawing{index}.xml.rels");
@@ -558,6 +684,21 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         app.add_heading_pair("Worksheets", num_worksheets);
 
+        // Chartsheets are listed after the worksheets.
+        let mut num_chartsheets = 0;
+
+        for sheet_name in &options.chartsheet_names {
+            // Ignore veryHidden chartsheets
+            if !sheet_name.is_empty() {
+                app.add_part_name(sheet_name);
+                num_chartsheets += 1;
+            }
+        }
+
+        if num_chartsheets > 0 {
+            app.add_heading_pair("Charts", num_chartsheets);
+        }
+
         if !options.defined_names.is_empty() {
             app.add_heading_pair("Named Ranges", options.defined_names.len() as u16);
 
@@ -589,6 +730,93 @@ impl<W: Write + Seek + Send> Packager<W> {
         Ok(())
     }
 
+    // Write the shared workbook revision files and their associated rels file.
+    fn write_revision_files(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
+        let mut revision_headers = RevisionHeaders::new();
+        revision_headers.num_worksheets = options.num_worksheets + options.num_chartsheets;
+        revision_headers
+            .date_time
+            .clone_from(&options.properties.creation_time);
+
+        // The revisions are attributed to the document author, if set.
+        revision_headers.user_name = if options.properties.author.is_empty() {
+            "rust_xlsxwriter".to_string()
+        } else {
+            options.properties.author.clone()
+        };
+
+        revision_headers.generate_guid();
+
+        self.zip
+            .start_file("xl/revisions/revisionHeaders.xml", self.zip_options)?;
+
+        revision_headers.assemble_xml_file();
+        self.zip
+            .write_all(revision_headers.writer.xmlfile.get_ref())?;
+
+        let mut revision_log = RevisionLog::new();
+
+        self.zip
+            .start_file("xl/revisions/revisionLog1.xml", self.zip_options)?;
+
+        revision_log.assemble_xml_file();
+        self.zip.write_all(revision_log.writer.xmlfile.get_ref())?;
+
+        let mut user_names = UserNames::new();
+
+        self.zip
+            .start_file("xl/revisions/userNames.xml", self.zip_options)?;
+
+        user_names.assemble_xml_file();
+        self.zip.write_all(user_names.writer.xmlfile.get_ref())?;
+
+        let mut rels = Relationship::new();
+        rels.add_document_relationship("revisionLog", "revisionLog1.xml", "");
+
+        self.zip.start_file(
+            "xl/revisions/_rels/revisionHeaders.xml.rels",
+            self.zip_options,
+        )?;
+
+        rels.assemble_xml_file();
+        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+
+        Ok(())
+    }
+
+    // Write the custom XML data files and their associated properties and rels
+    // files.
+    fn write_custom_xml_files(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
+        for (index, (xml, item_id)) in workbook.custom_xml_parts.iter().enumerate() {
+            let index = index + 1;
+
+            let filename = format!("customXml/item{index}.xml");
+            self.zip.start_file(filename, self.zip_options)?;
+            self.zip.write_all(xml.as_bytes())?;
+
+            let mut properties = CustomXmlProperties::new();
+            properties.item_id.clone_from(item_id);
+
+            let filename = format!("customXml/itemProps{index}.xml");
+            self.zip.start_file(filename, self.zip_options)?;
+
+            properties.assemble_xml_file();
+            self.zip.write_all(properties.writer.xmlfile.get_ref())?;
+
+            let mut rels = Relationship::new();
+            let target = format!("itemProps{index}.xml");
+            rels.add_document_relationship("customXmlProps", &target, "");
+
+            let filename = format!("customXml/_rels/item{index}.xml.rels");
+            self.zip.start_file(filename, self.zip_options)?;
+
+            rels.assemble_xml_file();
+            self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        }
+
+        Ok(())
+    }
+
     // Write the various RichValue files.
     fn write_rich_value_files(
         &mut self,
@@ -685,7 +913,26 @@ impl<W: Write + Seek + Send> Packager<W> {
     // Write the vml files.
     fn write_vml_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         let mut index = 1;
+        let mut data_id = 1;
         for worksheet in &mut workbook.worksheets {
+            if worksheet.has_notes() {
+                let filename = format!("xl/drawings/vmlDrawing{index}.vml");
+                self.zip.start_file(filename, self.zip_options)?;
+
+                let num_notes = worksheet.note_vml_info.len() as u32;
+                let mut vml = Vml::new();
+                vml.notes.append(&mut worksheet.note_vml_info);
+                vml.data_id = data_id;
+                vml.shape_id = 1024 * data_id;
+                vml.assemble_xml_file();
+
+                self.zip.write_all(vml.writer.xmlfile.get_ref())?;
+                index += 1;
+
+                // Each data id covers a block of 1024 shapes.
+                data_id += 1 + num_notes / 1024;
+            }
+
             if worksheet.has_header_footer_images() {
                 let filename = format!("xl/drawings/vmlDrawing{index}.vml");
                 self.zip.start_file(filename, self.zip_options)?;
@@ -693,18 +940,84 @@ impl<W: Write + Seek + Send> Packager<W> {
                 let mut vml = Vml::new();
                 vml.header_images
                     .append(&mut worksheet.header_footer_vml_info);
-                vml.data_id = index;
-                vml.shape_id = 1024 * index;
+                vml.data_id = data_id;
+                vml.shape_id = 1024 * data_id;
                 vml.assemble_xml_file();
 
                 self.zip.write_all(vml.writer.xmlfile.get_ref())?;
                 index += 1;
+                data_id += 1;
             }
         }
 
         Ok(())
     }
 
+    // Write the comment files.
+    fn write_comment_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
+        let mut index = 1;
+        for worksheet in &mut workbook.worksheets {
+            if worksheet.has_notes() {
+                let filename = format!("xl/comments{index}.xml");
+                self.zip.start_file(filename, self.zip_options)?;
+
+                let mut comment = Comment::new();
+                comment.notes = worksheet.legacy_notes();
+                comment
+                    .default_author
+                    .clone_from(&worksheet.default_note_author);
+                comment.assemble_xml_file();
+
+                self.zip.write_all(comment.writer.xmlfile.get_ref())?;
+                index += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the threaded comment files.
+    fn write_threaded_comment_files(
+        &mut self,
+        workbook: &mut Workbook,
+        options: &PackagerOptions,
+    ) -> Result<(), XlsxError> {
+        let mut index = 1;
+        for worksheet in &mut workbook.worksheets {
+            if !worksheet.threaded_comments.is_empty() {
+                let filename = format!("xl/threadedComments/threadedComment{index}.xml");
+                self.zip.start_file(filename, self.zip_options)?;
+
+                let mut threaded_comments = ThreadedComments::new();
+                threaded_comments.comments = worksheet.threaded_comment_info();
+                threaded_comments
+                    .date_time
+                    .clone_from(&options.properties.creation_time);
+                threaded_comments.assemble_xml_file();
+
+                self.zip
+                    .write_all(threaded_comments.writer.xmlfile.get_ref())?;
+                index += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the threaded comment persons file.
+    fn write_person_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
+        let mut person_list = PersonList::new();
+        person_list.authors.clone_from(&options.persons);
+
+        self.zip
+            .start_file("xl/persons/person.xml", self.zip_options)?;
+
+        person_list.assemble_xml_file();
+        self.zip.write_all(person_list.writer.xmlfile.get_ref())?;
+
+        Ok(())
+    }
+
     // Write the image files.
     fn write_image_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         let mut index = 1;
@@ -753,15 +1066,17 @@ impl<W: Write + Seek + Send> Packager<W> {
 
     // Write the chart files.
     fn write_chart_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
-        let mut index = 1;
-
         for worksheet in &mut workbook.worksheets {
             for chart in worksheet.charts.values_mut() {
-                let filename = format!("xl/charts/chart{index}.xml");
+                let filename = if chart.is_chartex() {
+                    format!("xl/charts/chartEx{}.xml", chart.id)
+                } else {
+                    format!("xl/charts/chart{}.xml", chart.id)
+                };
+
                 self.zip.start_file(filename, self.zip_options)?;
                 chart.assemble_xml_file();
                 self.zip.write_all(chart.writer.xmlfile.get_ref())?;
-                index += 1;
             }
         }
 
@@ -793,13 +1108,22 @@ pub(crate) struct PackagerOptions {
     pub(crate) has_dynamic_functions: bool,
     pub(crate) has_embedded_images: bool,
     pub(crate) has_vml: bool,
+    pub(crate) has_revisions: bool,
     pub(crate) num_worksheets: u16,
+    pub(crate) num_chartsheets: u16,
     pub(crate) num_drawings: u16,
     pub(crate) num_charts: u16,
+    pub(crate) num_chartex: u16,
     pub(crate) num_tables: u16,
+    pub(crate) num_comments: u16,
+    pub(crate) num_threaded_comments: u16,
+    pub(crate) num_custom_xml_parts: u16,
     pub(crate) doc_security: u8,
     pub(crate) worksheet_names: Vec<String>,
+    pub(crate) chartsheet_names: Vec<String>,
+    pub(crate) chartsheet_flags: Vec<bool>,
     pub(crate) defined_names: Vec<String>,
+    pub(crate) persons: Vec<String>,
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
     pub(crate) properties: DocProperties,
     pub(crate) num_embedded_images: u32,
@@ -815,13 +1139,22 @@ impl PackagerOptions {
             has_dynamic_functions: false,
             has_embedded_images: false,
             has_vml: false,
+            has_revisions: false,
             num_worksheets: 0,
+            num_chartsheets: 0,
             num_drawings: 0,
             num_charts: 0,
+            num_chartex: 0,
             num_tables: 0,
+            num_comments: 0,
+            num_threaded_comments: 0,
+            num_custom_xml_parts: 0,
             doc_security: 0,
             worksheet_names: vec![],
+            chartsheet_names: vec![],
+            chartsheet_flags: vec![],
             defined_names: vec![],
+            persons: vec![],
             image_types: [false; NUM_IMAGE_FORMATS],
             properties: DocProperties::new(),
             num_embedded_images: 0,
@@ -0,0 +1,86 @@
+// person_list - A module for creating the Excel person.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::utility;
+use crate::xmlwriter::XMLWriter;
+
+pub struct PersonList {
+    pub(crate) writer: XMLWriter,
+    pub(crate) authors: Vec<String>,
+}
+
+impl PersonList {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new PersonList struct.
+    pub(crate) fn new() -> PersonList {
+        let writer = XMLWriter::new();
+
+        PersonList {
+            writer,
+            authors: vec![],
+        }
+    }
+
+    // Get the id of a threaded comment author. It is generated from the author
+    // name so that the same author has the same id in each worksheet.
+    pub(crate) fn person_id(author: &str) -> String {
+        utility::hash_to_guid(&("person", author))
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the personList element.
+        self.write_person_list();
+
+        for author in &self.authors.clone() {
+            // Write the person element.
+            self.write_person(author);
+        }
+
+        // Close the personList tag.
+        self.writer.xml_end_tag("personList");
+    }
+
+    // Write the <personList> element.
+    fn write_person_list(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ];
+
+        self.writer.xml_start_tag("personList", &attributes);
+    }
+
+    // Write the <person> element. The authors aren't linked to a user account
+    // so the provider is "None" and the user id is the name.
+    fn write_person(&mut self, author: &str) {
+        let attributes = [
+            ("displayName", author.to_string()),
+            ("id", Self::person_id(author)),
+            ("userId", author.to_string()),
+            ("providerId", "None".to_string()),
+        ];
+
+        self.writer.xml_empty_tag("person", &attributes);
+    }
+}
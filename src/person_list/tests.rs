@@ -0,0 +1,51 @@
+// person_list unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod person_list_tests {
+
+    use crate::person_list::PersonList;
+    use crate::test_functions::xml_to_vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut person_list = PersonList::new();
+        person_list.authors = vec!["Sales".to_string(), "Finance".to_string()];
+
+        person_list.assemble_xml_file();
+
+        let got = person_list.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(&format!(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                  <person displayName="Sales" id="{}" userId="Sales" providerId="None"/>
+                  <person displayName="Finance" id="{}" userId="Finance" providerId="None"/>
+                </personList>
+                "#,
+            PersonList::person_id("Sales"),
+            PersonList::person_id("Finance"),
+        ));
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_person_id() {
+        // The id is stable for an author and differs between authors.
+        assert_eq!(
+            PersonList::person_id("Sales"),
+            PersonList::person_id("Sales")
+        );
+        assert_ne!(
+            PersonList::person_id("Sales"),
+            PersonList::person_id("Finance")
+        );
+    }
+}
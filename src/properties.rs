@@ -354,6 +354,7 @@ pub struct DocProperties {
     pub(crate) keywords: String,
     pub(crate) hyperlink_base: String,
     pub(crate) creation_time: String,
+    pub(crate) modified_time: String,
     pub(crate) custom_properties: Vec<CustomProperty>,
 }
 
@@ -378,6 +379,7 @@ impl DocProperties {
             keywords: String::new(),
             hyperlink_base: String::new(),
             creation_time: ExcelDateTime::utc_now(),
+            modified_time: String::new(),
             custom_properties: vec![],
         }
     }
@@ -555,6 +557,59 @@ impl DocProperties {
         self
     }
 
+    /// Set the modified date/time for the document.
+    ///
+    /// Excel sets the modified date and time of a document each time it is
+    /// saved. By default `rust_xlsxwriter` uses the creation date/time, see
+    /// [`DocProperties::set_creation_datetime()`], for the modified date/time
+    /// as well. This method can be used to set a different modified time.
+    ///
+    /// # Parameters
+    ///
+    /// * `datetime` - The modified date property. A type that implements
+    ///   [`IntoCustomDateTimeUtc`].
+    ///
+    /// # Examples
+    ///
+    /// An example of setting constant creation and modified dates in the
+    /// workbook document properties.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_properties_modified.rs
+    /// #
+    /// use rust_xlsxwriter::{DocProperties, ExcelDateTime, Workbook, XlsxError};
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Create the creation and modified dates for the file.
+    ///     let created = ExcelDateTime::from_ymd(2023, 1, 1)?;
+    ///     let modified = ExcelDateTime::from_ymd(2024, 6, 30)?.and_hms(12, 30, 0)?;
+    ///
+    ///     // Add them to the document metadata.
+    ///     let properties = DocProperties::new()
+    ///         .set_creation_datetime(&created)
+    ///         .set_modified_datetime(&modified);
+    ///
+    ///     workbook.set_properties(&properties);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     workbook.save("properties.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_modified_datetime(
+        mut self,
+        modified_time: impl IntoCustomDateTimeUtc,
+    ) -> DocProperties {
+        self.modified_time = modified_time.utc_datetime();
+        self
+    }
+
     /// Set a custom document property.
     ///
     /// Set a user defined property that will appear in the Custom section of
@@ -0,0 +1,128 @@
+// revision_headers - A module for creating the Excel revisionHeaders.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::utility;
+use crate::xmlwriter::XMLWriter;
+
+pub struct RevisionHeaders {
+    pub(crate) writer: XMLWriter,
+    pub(crate) num_worksheets: u16,
+    pub(crate) user_name: String,
+    pub(crate) date_time: String,
+    pub(crate) guid: String,
+}
+
+impl RevisionHeaders {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new RevisionHeaders struct.
+    pub(crate) fn new() -> RevisionHeaders {
+        let writer = XMLWriter::new();
+
+        RevisionHeaders {
+            writer,
+            num_worksheets: 0,
+            user_name: String::new(),
+            date_time: String::new(),
+            guid: String::new(),
+        }
+    }
+
+    // Create a pseudo GUID for the revision history. Excel uses the guid to
+    // match the revision history of copies of a shared workbook so it should
+    // differ between workbooks. It is generated from the user name and
+    // creation time, rather than randomly, so that the output file is
+    // reproducible when the creation time is fixed.
+    pub(crate) fn generate_guid(&mut self) {
+        self.guid = utility::hash_to_guid(&(&self.user_name, &self.date_time, self.num_worksheets));
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the headers element.
+        self.write_headers();
+
+        // Write the header element.
+        self.write_header();
+
+        // Close the final tag.
+        self.writer.xml_end_tag("headers");
+    }
+
+    // Write the <headers> element.
+    fn write_headers(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main".to_string(),
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
+            ),
+            ("guid", self.guid.clone()),
+            ("lastGuid", self.guid.clone()),
+            ("shared", "1".to_string()),
+            ("diskRevisions", "1".to_string()),
+            ("revisionId", "1".to_string()),
+            ("version", "2".to_string()),
+        ];
+
+        self.writer.xml_start_tag("headers", &attributes);
+    }
+
+    // Write the <header> element.
+    fn write_header(&mut self) {
+        // The dateTime attribute is an xsd:dateTime without the UTC suffix.
+        let date_time = self.date_time.trim_end_matches('Z').to_string();
+
+        let attributes = [
+            ("guid", self.guid.clone()),
+            ("dateTime", date_time),
+            ("maxSheetId", (self.num_worksheets + 1).to_string()),
+            ("userName", self.user_name.clone()),
+            ("r:id", "rId1".to_string()),
+        ];
+
+        self.writer.xml_start_tag("header", &attributes);
+
+        // Write the sheetIdMap element.
+        self.write_sheet_id_map();
+
+        self.writer.xml_end_tag("header");
+    }
+
+    // Write the <sheetIdMap> element.
+    fn write_sheet_id_map(&mut self) {
+        let attributes = [("count", self.num_worksheets.to_string())];
+
+        self.writer.xml_start_tag("sheetIdMap", &attributes);
+
+        for sheet_id in 1..=self.num_worksheets {
+            // Write the sheetId element.
+            self.write_sheet_id(sheet_id);
+        }
+
+        self.writer.xml_end_tag("sheetIdMap");
+    }
+
+    // Write the <sheetId> element.
+    fn write_sheet_id(&mut self, sheet_id: u16) {
+        let attributes = [("val", sheet_id.to_string())];
+
+        self.writer.xml_empty_tag("sheetId", &attributes);
+    }
+}
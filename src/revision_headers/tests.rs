@@ -0,0 +1,72 @@
+// revision_headers unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod revision_headers_tests {
+
+    use crate::revision_headers::RevisionHeaders;
+    use crate::test_functions::xml_to_vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut revision_headers = RevisionHeaders::new();
+        revision_headers.num_worksheets = 2;
+        revision_headers.user_name = "A. User".to_string();
+        revision_headers.date_time = "2024-01-01T12:00:00Z".to_string();
+        revision_headers.guid = "{3C4D5E6F-0A1B-4C2D-8E3F-405162738495}".to_string();
+
+        revision_headers.assemble_xml_file();
+
+        let got = revision_headers.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <headers xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" guid="{3C4D5E6F-0A1B-4C2D-8E3F-405162738495}" lastGuid="{3C4D5E6F-0A1B-4C2D-8E3F-405162738495}" shared="1" diskRevisions="1" revisionId="1" version="2">
+              <header guid="{3C4D5E6F-0A1B-4C2D-8E3F-405162738495}" dateTime="2024-01-01T12:00:00" maxSheetId="3" userName="A. User" r:id="rId1">
+                <sheetIdMap count="2">
+                  <sheetId val="1"/>
+                  <sheetId val="2"/>
+                </sheetIdMap>
+              </header>
+            </headers>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_generate_guid() {
+        let mut revision_headers = RevisionHeaders::new();
+        revision_headers.num_worksheets = 1;
+        revision_headers.user_name = "A. User".to_string();
+        revision_headers.date_time = "2024-01-01T12:00:00Z".to_string();
+
+        revision_headers.generate_guid();
+        let guid = revision_headers.guid.clone();
+
+        // The guid has the Excel {8-4-4-4-12} hex digit format.
+        let lengths: Vec<usize> = guid
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split('-')
+            .map(str::len)
+            .collect();
+        assert_eq!(lengths, vec![8, 4, 4, 4, 12]);
+        assert!(guid.starts_with('{') && guid.ends_with('}'));
+
+        // The same data gives the same guid and different data doesn't.
+        revision_headers.generate_guid();
+        assert_eq!(guid, revision_headers.guid);
+
+        revision_headers.date_time = "2024-01-01T12:00:01Z".to_string();
+        revision_headers.generate_guid();
+        assert_ne!(guid, revision_headers.guid);
+    }
+}
@@ -0,0 +1,53 @@
+// revision_log - A module for creating the Excel revisionLog.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct RevisionLog {
+    pub(crate) writer: XMLWriter,
+}
+
+impl RevisionLog {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new RevisionLog struct.
+    pub(crate) fn new() -> RevisionLog {
+        let writer = XMLWriter::new();
+
+        RevisionLog { writer }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file. The log is always empty since
+    // rust_xlsxwriter doesn't record revisions.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the revisions element.
+        self.write_revisions();
+    }
+
+    // Write the <revisions> element.
+    fn write_revisions(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+        ];
+
+        self.writer.xml_empty_tag("revisions", &attributes);
+    }
+}
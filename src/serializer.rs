@@ -1958,7 +1958,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{ColNum, Format, RowNum, Table, TableStyle, Worksheet, XlsxError};
-use serde::de::Visitor;
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
 use serde::{ser, Deserialize, Deserializer, Serialize};
 
 // Convenience tuple struct Table data used for serialization formatting.
@@ -4252,6 +4252,190 @@ where
     }
 }
 
+// -----------------------------------------------------------------------
+// Worksheet Deserializer. This is a simplified implementation of the
+// Deserializer trait to map worksheet cell values, keyed by their header names,
+// back to the fields of a Serde derived struct.
+// -----------------------------------------------------------------------
+
+// A worksheet cell value in a form that can be deserialized.
+pub(crate) enum DeserializerCell {
+    Empty,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+}
+
+// A worksheet row of cell values with their associated field names.
+pub(crate) struct DeserializerRow {
+    pub(crate) cells: Vec<(String, DeserializerCell)>,
+}
+
+impl<'de> Deserializer<'de> for DeserializerRow {
+    type Error = XlsxError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(DeserializerRowMap {
+            cells: self.cells.into_iter(),
+            value: None,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+// Map the row cells to struct field/value pairs.
+struct DeserializerRowMap {
+    cells: std::vec::IntoIter<(String, DeserializerCell)>,
+    value: Option<DeserializerCell>,
+}
+
+impl<'de> MapAccess<'de> for DeserializerRowMap {
+    type Error = XlsxError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.cells.next() {
+            Some((field_name, value)) => {
+                self.value = Some(value);
+                seed.deserialize(field_name.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(XlsxError::SerdeError(
+                "Deserialization value requested before field name".to_string(),
+            )),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for DeserializerCell {
+    type Error = XlsxError;
+
+    // Map the cell value to the closest Serde data type. Whole numbers are
+    // passed as integers so that they can be deserialized to integer fields.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            DeserializerCell::Empty => visitor.visit_unit(),
+            DeserializerCell::Boolean(boolean) => visitor.visit_bool(boolean),
+            DeserializerCell::Number(number) => {
+                if number.fract() == 0.0 && number.abs() < 9_007_199_254_740_992.0 {
+                    if number < 0.0 {
+                        visitor.visit_i64(number as i64)
+                    } else {
+                        visitor.visit_u64(number as u64)
+                    }
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            DeserializerCell::String(string) => visitor.visit_string(string),
+        }
+    }
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            DeserializerCell::Number(number) => visitor.visit_f64(number),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_string(visitor)
+    }
+
+    // Numbers and booleans are converted to strings for string fields.
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            DeserializerCell::Empty => visitor.visit_string(String::new()),
+            DeserializerCell::Boolean(boolean) => visitor.visit_string(boolean.to_string()),
+            DeserializerCell::Number(number) => visitor.visit_string(number.to_string()),
+            DeserializerCell::String(string) => visitor.visit_string(string),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            DeserializerCell::Empty => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Only unit variant enums, stored as strings, are supported.
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            DeserializerCell::String(string) => visitor.visit_enum(string.into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 char bytes
+        byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
 // -----------------------------------------------------------------------
 // XlsxSerializer trait. Trait to map `#[xlsx()]` attributes to
 // `SerializeFieldOptions` options.
@@ -163,8 +163,11 @@ impl<'a> Styles<'a> {
         self.write_font_color(font, dxf_format);
 
         if !dxf_format {
-            // Write the name element.
-            self.write_font_name(font);
+            // Write the name element. The name can be omitted for a theme
+            // scheme font, in which case Excel uses the theme font.
+            if !font.name.is_empty() || font.scheme.is_empty() {
+                self.write_font_name(font);
+            }
 
             // Write the family element.
             if font.family > 0 {
@@ -612,7 +615,7 @@ impl<'a> Styles<'a> {
 
         // Indent is only allowed for some alignment properties. If it is
         // defined for any other alignment or no alignment has been set then
-        // default to left alignment.
+        // default to left alignment, or right alignment for right to left text.
         if alignment.indent > 0
             && horizontal_align != FormatAlign::Left
             && horizontal_align != FormatAlign::Right
@@ -621,7 +624,11 @@ impl<'a> Styles<'a> {
             && alignment.vertical != FormatAlign::Bottom
             && alignment.vertical != FormatAlign::Distributed
         {
-            horizontal_align = FormatAlign::Left;
+            if alignment.reading_direction == 2 {
+                horizontal_align = FormatAlign::Right;
+            } else {
+                horizontal_align = FormatAlign::Left;
+            }
         }
 
         // Check for properties that are mutually exclusive with "shrink".
@@ -9,7 +9,7 @@ mod styles_tests {
 
     use crate::styles::Styles;
     use crate::test_functions::xml_to_vec;
-    use crate::Format;
+    use crate::{Color, Format, FormatBorder, FormatFontScheme, FormatReadingOrder};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -74,4 +74,212 @@ mod styles_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_partial_dxf() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        // Conditional format dxfs should only contain the properties that are
+        // explicitly set, so that the other cell properties are unchanged.
+        let fill_only = Format::new().set_background_color(Color::RGB(0xFFC7CE));
+        let font_color_only = Format::new().set_font_color(Color::RGB(0x9C0006));
+
+        let xf_formats = vec![xf_format];
+        let dxf_formats = vec![fill_only, font_color_only];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 1, 2, 1, vec![], false, false);
+
+        styles.assemble_xml_file();
+
+        let got = styles.writer.read_to_str();
+        assert!(got.contains(
+            r#"<dxfs count="2"><dxf><fill><patternFill><bgColor rgb="FFFFC7CE"/></patternFill></fill></dxf><dxf><font><color rgb="FF9C0006"/></font></dxf></dxfs>"#
+        ));
+    }
+
+    #[test]
+    fn test_font_scheme_without_name() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        // The font name is omitted for a scheme font without a name.
+        let format = Format::new()
+            .set_font_name("")
+            .set_font_scheme(FormatFontScheme::Major);
+        styles.write_font(&format.font, false);
+
+        // The font name is still written, even if empty, without a scheme.
+        let format = Format::new().set_font_name("");
+        styles.write_font(&format.font, false);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            concat!(
+                r#"<font><sz val="11"/><color theme="1"/><family val="2"/><scheme val="major"/></font>"#,
+                r#"<font><sz val="11"/><color theme="1"/><name val=""/><family val="2"/></font>"#
+            )
+        );
+    }
+
+    #[test]
+    fn set_font_scheme() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        let format1 = Format::new()
+            .set_font_name("Cambria")
+            .set_font_scheme(FormatFontScheme::Major);
+        let format2 = Format::new().set_font_scheme(FormatFontScheme::None);
+
+        styles.write_font(&format1.font, false);
+        styles.write_font(&format2.font, false);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            concat!(
+                r#"<font><sz val="11"/><color theme="1"/><name val="Cambria"/>"#,
+                r#"<family val="2"/><scheme val="major"/></font>"#,
+                r#"<font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/></font>"#
+            )
+        );
+    }
+
+    #[test]
+    fn format_theme_colors() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        // An accent 1 background with a tinted white font.
+        let format = Format::new()
+            .set_pattern(crate::FormatPattern::Solid)
+            .set_foreground_color(Color::Theme(4, 0))
+            .set_font_color(Color::Theme(0, 1));
+
+        styles.write_font(&format.font, false);
+        styles.write_fill(&format.fill, false);
+
+        let got = styles.writer.read_to_str();
+
+        assert!(got.contains(r#"<color theme="0" tint="-4.9989318521683403E-2"/>"#));
+        assert!(got.contains(
+            r#"<patternFill patternType="solid"><fgColor theme="4"/><bgColor indexed="64"/></patternFill>"#
+        ));
+    }
+
+    #[test]
+    fn merge_range_border() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        let format = Format::new().set_border(FormatBorder::Thin);
+        styles.write_border(&format.borders, false);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            concat!(
+                r#"<border><left style="thin"><color auto="1"/></left>"#,
+                r#"<right style="thin"><color auto="1"/></right>"#,
+                r#"<top style="thin"><color auto="1"/></top>"#,
+                r#"<bottom style="thin"><color auto="1"/></bottom><diagonal/></border>"#
+            )
+        );
+    }
+
+    #[test]
+    fn format_text() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        // The "@" format is a built-in Excel format with index 49.
+        let format = Format::text();
+        styles.write_cell_xf(&format);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            r#"<xf numFmtId="49" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#
+        );
+    }
+
+    #[test]
+    fn format_indent() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        // Indented text defaults to left aligned, or right aligned for right
+        // to left text. The indent is limited to 250.
+        let format1 = Format::new().set_indent(2);
+        let format2 = Format::new().set_indent(3).set_reading_direction(2);
+        let format3 = Format::new().set_indent(255);
+
+        styles.write_alignment(format1.alignment);
+        styles.write_alignment(format2.alignment);
+        styles.write_alignment(format3.alignment);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            concat!(
+                r#"<alignment horizontal="left" indent="2"/>"#,
+                r#"<alignment horizontal="right" indent="3" readingOrder="2"/>"#,
+                r#"<alignment horizontal="left" indent="250"/>"#
+            )
+        );
+    }
+
+    #[test]
+    fn format_shrink_and_text_wrap() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        // Text wrap takes precedence over shrink to fit.
+        let format1 = Format::new().set_shrink();
+        let format2 = Format::new().set_shrink().set_text_wrap();
+
+        styles.write_alignment(format1.alignment);
+        styles.write_alignment(format2.alignment);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            r#"<alignment shrinkToFit="1"/><alignment wrapText="1"/>"#
+        );
+    }
+
+    #[test]
+    fn format_set_reading_order() {
+        let xf_formats = vec![];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(&xf_formats, &dxf_formats, 0, 0, 0, vec![], false, false);
+
+        let format1 = Format::new().set_reading_order(FormatReadingOrder::LeftToRight);
+        let format2 = Format::new().set_reading_order(FormatReadingOrder::RightToLeft);
+
+        styles.write_alignment(format1.alignment);
+        styles.write_alignment(format2.alignment);
+
+        let got = styles.writer.read_to_str();
+
+        assert_eq!(
+            got,
+            r#"<alignment readingOrder="1"/><alignment readingOrder="2"/>"#
+        );
+    }
 }
@@ -580,4 +580,76 @@ mod table_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble11() {
+        // A dark style with banded columns and the default banded rows.
+        let mut table = Table::new()
+            .set_style(crate::TableStyle::Dark3)
+            .set_banded_columns(true);
+
+        let worksheet = Worksheet::new();
+
+        table.cell_range.first_row = 2;
+        table.cell_range.first_col = 1;
+        table.cell_range.last_row = 6;
+        table.cell_range.last_col = 2;
+        table.index = 1;
+
+        let default_headers = worksheet.default_table_headers(
+            table.cell_range.first_row,
+            table.cell_range.first_col,
+            table.cell_range.last_col,
+            table.show_header_row,
+        );
+
+        table.initialize_columns(&default_headers).unwrap();
+        table.assemble_xml_file();
+
+        let got = table.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Table1" displayName="Table1" ref="B3:C7" totalsRowShown="0">
+                <autoFilter ref="B3:C7"/>
+                <tableColumns count="2">
+                    <tableColumn id="1" name="Column1"/>
+                    <tableColumn id="2" name="Column2"/>
+                </tableColumns>
+                <tableStyleInfo name="TableStyleDark3" showFirstColumn="0" showLastColumn="0" showRowStripes="1" showColumnStripes="1"/>
+                </table>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_autofilter_overlaps_table() {
+        let mut worksheet = Worksheet::new();
+        let table = Table::new();
+
+        worksheet.add_table(2, 2, 6, 4, &table).unwrap();
+
+        // An autofilter over the table range is an error.
+        let result = worksheet.autofilter(0, 0, 10, 2);
+        assert!(matches!(
+            result,
+            Err(XlsxError::AutofilterOverlapsTable(_, _))
+        ));
+
+        // A table over the autofilter range is also an error.
+        worksheet.autofilter(10, 0, 20, 1).unwrap();
+        let result = worksheet.add_table(20, 1, 25, 3, &table);
+        assert!(matches!(
+            result,
+            Err(XlsxError::AutofilterOverlapsTable(_, _))
+        ));
+
+        // Adjacent ranges are allowed.
+        let result = worksheet.add_table(21, 0, 25, 3, &table);
+        assert!(result.is_ok());
+    }
 }
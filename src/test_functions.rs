@@ -4,7 +4,9 @@
 //
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
-use crate::static_regex;
+use crate::{static_regex, Workbook};
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
 
 // Convert XML string/doc into a vector for comparison testing.
 pub(crate) fn xml_to_vec(xml_string: &str) -> Vec<String> {
@@ -45,3 +47,24 @@ pub(crate) fn vml_to_vec(vml_string: &str) -> Vec<String> {
 
     xml_to_vec(&vml_string)
 }
+
+// Save a workbook to a buffer and read back the files in the xlsx container.
+// This is only needed for tests of the workbook packaging. Tests of a single
+// component should use its `assemble_xml_file()` output instead.
+pub(crate) fn read_xlsx_files(workbook: &mut Workbook) -> HashMap<String, String> {
+    let buffer = workbook.save_to_buffer().unwrap();
+    let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+    let mut files = HashMap::new();
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index).unwrap();
+        let mut data = vec![];
+        file.read_to_end(&mut data).unwrap();
+
+        files.insert(
+            file.name().to_string(),
+            String::from_utf8_lossy(&data).to_string(),
+        );
+    }
+    files
+}
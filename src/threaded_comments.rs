@@ -0,0 +1,106 @@
+// threaded_comments - A module for creating the Excel threadedComment.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct ThreadedComments {
+    pub(crate) writer: XMLWriter,
+    pub(crate) comments: Vec<ThreadedCommentInfo>,
+    pub(crate) date_time: String,
+}
+
+impl ThreadedComments {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new ThreadedComments struct.
+    pub(crate) fn new() -> ThreadedComments {
+        let writer = XMLWriter::new();
+
+        ThreadedComments {
+            writer,
+            comments: vec![],
+            date_time: String::new(),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the ThreadedComments element.
+        self.write_threaded_comments();
+
+        for comment in &self.comments.clone() {
+            // Write the threadedComment element.
+            self.write_threaded_comment(comment);
+        }
+
+        // Close the ThreadedComments tag.
+        self.writer.xml_end_tag("ThreadedComments");
+    }
+
+    // Write the <ThreadedComments> element.
+    fn write_threaded_comments(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ];
+
+        self.writer.xml_start_tag("ThreadedComments", &attributes);
+    }
+
+    // Write the <threadedComment> element.
+    fn write_threaded_comment(&mut self, comment: &ThreadedCommentInfo) {
+        let mut attributes = vec![("ref", comment.cell.clone())];
+
+        if !self.date_time.is_empty() {
+            attributes.push(("dT", self.date_time.clone()));
+        }
+
+        attributes.push(("personId", comment.person_id.clone()));
+        attributes.push(("id", comment.id.clone()));
+
+        if !comment.parent_id.is_empty() {
+            attributes.push(("parentId", comment.parent_id.clone()));
+        }
+
+        self.writer.xml_start_tag("threadedComment", &attributes);
+
+        // Write the text element.
+        self.writer.xml_data_element_only("text", &comment.text);
+
+        self.writer.xml_end_tag("threadedComment");
+    }
+}
+
+// -----------------------------------------------------------------------
+// Helper enums/structs/functions.
+// -----------------------------------------------------------------------
+
+// The cell, ids and text of a threaded comment or of a reply. A reply has the
+// id of the first comment in the thread as its parent id.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ThreadedCommentInfo {
+    pub(crate) cell: String,
+    pub(crate) id: String,
+    pub(crate) parent_id: String,
+    pub(crate) person_id: String,
+    pub(crate) text: String,
+}
@@ -0,0 +1,56 @@
+// threaded_comments unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod threaded_comments_tests {
+
+    use crate::test_functions::xml_to_vec;
+    use crate::threaded_comments::{ThreadedCommentInfo, ThreadedComments};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut threaded_comments = ThreadedComments::new();
+        threaded_comments.date_time = "2024-01-01T00:00:00Z".to_string();
+
+        threaded_comments.comments.push(ThreadedCommentInfo {
+            cell: "B2".to_string(),
+            id: "{00000000-0001-0000-0000-000000000001}".to_string(),
+            parent_id: String::new(),
+            person_id: "{00000000-0002-0000-0000-000000000001}".to_string(),
+            text: "Is this right?".to_string(),
+        });
+
+        threaded_comments.comments.push(ThreadedCommentInfo {
+            cell: "B2".to_string(),
+            id: "{00000000-0001-0000-0000-000000000002}".to_string(),
+            parent_id: "{00000000-0001-0000-0000-000000000001}".to_string(),
+            person_id: "{00000000-0002-0000-0000-000000000002}".to_string(),
+            text: "Yes & no".to_string(),
+        });
+
+        threaded_comments.assemble_xml_file();
+
+        let got = threaded_comments.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <ThreadedComments xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                  <threadedComment ref="B2" dT="2024-01-01T00:00:00Z" personId="{00000000-0002-0000-0000-000000000001}" id="{00000000-0001-0000-0000-000000000001}">
+                    <text>Is this right?</text>
+                  </threadedComment>
+                  <threadedComment ref="B2" dT="2024-01-01T00:00:00Z" personId="{00000000-0002-0000-0000-000000000002}" id="{00000000-0001-0000-0000-000000000002}" parentId="{00000000-0001-0000-0000-000000000001}">
+                    <text>Yes &amp; no</text>
+                  </threadedComment>
+                </ThreadedComments>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+}
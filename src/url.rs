@@ -6,7 +6,7 @@
 
 #![warn(missing_docs)]
 
-use crate::{static_regex, XlsxError, MAX_PARAMETER_LEN};
+use crate::{static_regex, Format, XlsxError, MAX_PARAMETER_LEN};
 
 const MAX_URL_LEN: usize = 2_080;
 
@@ -133,6 +133,16 @@ const MAX_URL_LEN: usize = 2_080;
 ///    non alphanumeric characters are single quoted as follows `'Sales
 ///    Data'!A1`.
 ///
+/// 4. Internal links to a defined name in the workbook using a `#` prefix:
+///
+///    * `#MyNamedRange`
+///    * `#Sheet1!MyLocalRange`
+///
+///    The name should be created with
+///    [`Workbook::define_name()`](crate::Workbook::define_name). If the name
+///    doesn't exist when the file is saved a warning is displayed, since
+///    Excel will show an error when the link is clicked.
+///
 /// The library will escape the following characters in URLs as required by
 /// Excel, ``\s " < > \ [ ] ` ^ { }``, unless the URL already contains `%xx`
 /// style escapes. In which case it is assumed that the URL was escaped
@@ -151,6 +161,8 @@ pub struct Url {
     pub(crate) rel_display: bool,
     pub(crate) link_type: HyperlinkType,
     pub(crate) rel_id: u32,
+    pub(crate) is_defined_name: bool,
+    pub(crate) format: Option<Format>,
 }
 
 impl Url {
@@ -172,6 +184,8 @@ impl Url {
             rel_id: 0,                         // Relationship id.
             tool_tip: String::new(),           // The mouseover tool tip.
             link_type: HyperlinkType::Unknown, // Url, file, internal.
+            is_defined_name: false,            // Internal link to a defined name.
+            format: None,                      // User defined cell format.
         }
     }
 
@@ -232,6 +246,58 @@ impl Url {
         self
     }
 
+    /// Set the cell format for the url.
+    ///
+    /// By default urls are written with the Excel "Hyperlink" cell style,
+    /// which is a blue underlined font. This method sets a user defined
+    /// [`Format`] that replaces the hyperlink style entirely, rather than
+    /// being merged with it. For example a format with a brand color and no
+    /// underline will display the url without an underline.
+    ///
+    /// A format passed directly to a worksheet method such as
+    /// [`Worksheet::write_url_with_format()`](crate::Worksheet::write_url_with_format)
+    /// takes precedence over this format.
+    ///
+    /// # Parameters
+    ///
+    /// `format` - The [`Format`] property for the url cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a url with a brand color and
+    /// without the default underline.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_url_set_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, Url, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a format without an underline.
+    ///     let brand_format = Format::new().set_font_color(Color::RGB(0x7030A0));
+    ///
+    ///     // Write a url with the alternative format.
+    ///     let url = Url::new("https://www.rust-lang.org").set_format(&brand_format);
+    ///     worksheet.write(0, 0, url)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_format(mut self, format: &Format) -> Url {
+        self.format = Some(format.clone());
+        self
+    }
+
     // -----------------------------------------------------------------------
     // Crate level helper methods.
     // -----------------------------------------------------------------------
@@ -293,6 +359,15 @@ impl Url {
             self.link_type = HyperlinkType::Internal;
             self.rel_anchor = self.url_link.replacen("internal:", "", 1);
 
+            if self.user_text.is_empty() {
+                self.user_text.clone_from(&self.rel_anchor);
+            }
+        } else if let Some(name) = self.url_link.strip_prefix('#') {
+            // Handle links to defined names within the workbook.
+            self.link_type = HyperlinkType::Internal;
+            self.rel_anchor = name.to_string();
+            self.is_defined_name = true;
+
             if self.user_text.is_empty() {
                 self.user_text.clone_from(&self.rel_anchor);
             }
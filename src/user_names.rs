@@ -0,0 +1,54 @@
+// user_names - A module for creating the Excel userNames.xml file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct UserNames {
+    pub(crate) writer: XMLWriter,
+}
+
+impl UserNames {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new UserNames struct.
+    pub(crate) fn new() -> UserNames {
+        let writer = XMLWriter::new();
+
+        UserNames { writer }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file. The user list is always empty since it
+    // only records users that currently have the shared workbook open.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the users element.
+        self.write_users();
+    }
+
+    // Write the <users> element.
+    fn write_users(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+            ("count", "0"),
+        ];
+
+        self.writer.xml_empty_tag("users", &attributes);
+    }
+}
@@ -32,6 +32,9 @@ use crate::IntoExcelDateTime;
 #[cfg(feature = "serde")]
 use serde::Serializer;
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use crate::worksheet::ColNum;
 use crate::worksheet::RowNum;
 use crate::XlsxError;
@@ -601,6 +604,82 @@ pub(crate) fn hash_password(password: &str) -> u16 {
     hash
 }
 
+// Create a pseudo GUID, in the Excel "{8-4-4-4-12}" hex digit format, from a
+// hash of some data. This is used instead of a random GUID so that the output
+// file is reproducible.
+pub(crate) fn hash_to_guid<T: Hash>(data: &T) -> String {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    let high = hasher.finish();
+
+    high.hash(&mut hasher);
+    let low = hasher.finish();
+
+    format!(
+        "{{{:08X}-{:04X}-{:04X}-{:04X}-{:012X}}}",
+        high >> 32,
+        (high >> 16) & 0xFFFF,
+        high & 0xFFFF,
+        low >> 48,
+        low & 0xFFFF_FFFF_FFFF
+    )
+}
+
+// Round a number to a number of decimal places. Excel numbers have 15 digits
+// of precision so higher values are returned unchanged. The rounding is done
+// on the shortest decimal string representation of the number, rather than by
+// scaling, so that values like 1.005 round to 1.01 as they would in Excel.
+pub(crate) fn round_to_decimals(number: f64, decimals: u8) -> f64 {
+    if decimals > 15 || !number.is_finite() {
+        return number;
+    }
+
+    let decimals = usize::from(decimals);
+    let string = number.abs().to_string();
+
+    let (integer, fraction) = match string.split_once('.') {
+        Some((integer, fraction)) if fraction.len() > decimals => (integer, fraction),
+        _ => return number,
+    };
+
+    // Get the digits to keep and round up, away from zero, if the next digit
+    // is 5 or more.
+    let mut digits: Vec<u8> = integer
+        .bytes()
+        .chain(fraction.bytes().take(decimals))
+        .collect();
+
+    if fraction.as_bytes()[decimals] >= b'5' {
+        let mut index = digits.len();
+
+        loop {
+            if index == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+
+            index -= 1;
+
+            if digits[index] == b'9' {
+                digits[index] = b'0';
+            } else {
+                digits[index] += 1;
+                break;
+            }
+        }
+    }
+
+    // Add the decimal point back before converting to a number.
+    let point = digits.len() - decimals;
+    let mut rounded = String::from_utf8(digits).unwrap_or_default();
+    rounded.insert(point, '.');
+
+    match rounded.parse::<f64>() {
+        Ok(rounded) => rounded.copysign(number),
+        Err(_) => number,
+    }
+}
+
 // Clone and strip the leading '=' from formulas, if present.
 pub(crate) fn formula_to_string(formula: &str) -> String {
     let mut formula = formula.to_string();
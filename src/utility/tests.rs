@@ -348,4 +348,31 @@ mod utility_tests {
             Err(XlsxError::SheetnameStartsOrEndsWithApostrophe(_))
         ));
     }
+
+    #[test]
+    fn test_round_to_decimals() {
+        let tests = vec![
+            (0.1 + 0.2, 2, 0.3),
+            (0.1 + 0.2, 0, 0.0),
+            (1234.5678, 2, 1234.57),
+            (-1234.5678, 1, -1234.6),
+            (2.5, 0, 3.0),
+            (-2.5, 0, -3.0),
+            (1.005, 2, 1.01),
+            (1.0049, 2, 1.0),
+            (2.675, 2, 2.68),
+            (-1.005, 2, -1.01),
+            (9.995, 2, 10.0),
+            (99.5, 0, 100.0),
+            (0.045, 2, 0.05),
+            (1.5e-10, 9, 0.0),
+            (123.0, 2, 123.0),
+            (1.0e300, 15, 1.0e300),
+            (0.30000000000000004, 16, 0.30000000000000004),
+        ];
+
+        for (number, decimals, expected) in tests {
+            assert_eq!(expected, utility::round_to_decimals(number, decimals));
+        }
+    }
 }
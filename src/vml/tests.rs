@@ -8,7 +8,7 @@
 mod theme_tests {
 
     use crate::vml::Vml;
-    use crate::{test_functions::vml_to_vec, vml::VmlInfo};
+    use crate::{test_functions::vml_to_vec, vml::VmlInfo, Note, Worksheet};
 
     use pretty_assertions::assert_eq;
 
@@ -141,4 +141,82 @@ mod theme_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_notes() {
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .insert_note(1, 1, &Note::new("Note").set_visible(true))
+            .unwrap();
+        worksheet
+            .insert_note(
+                3,
+                2,
+                &Note::new("Note")
+                    .set_width(200)
+                    .set_height(100)
+                    .set_background_color("#CCFFCC"),
+            )
+            .unwrap();
+        worksheet.prepare_vml_notes(1, 1);
+
+        let mut vml = Vml::new();
+        vml.notes.append(&mut worksheet.note_vml_info);
+        vml.data_id = 1;
+        vml.shape_id = 1024;
+
+        vml.assemble_xml_file();
+
+        let got = vml.writer.read_to_str();
+        let got = vml_to_vec(got);
+
+        let expected = vml_to_vec(
+            r##"
+                <xml xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:x="urn:schemas-microsoft-com:office:excel">
+                <o:shapelayout v:ext="edit">
+                  <o:idmap v:ext="edit" data="1"/>
+                </o:shapelayout>
+                <v:shapetype id="_x0000_t202" coordsize="21600,21600" o:spt="202" path="m,l,21600r21600,l21600,xe">
+                  <v:stroke joinstyle="miter"/>
+                  <v:path gradientshapeok="t" o:connecttype="rect"/>
+                </v:shapetype>
+                <v:shape id="_x0000_s1025" type="#_x0000_t202" style="position:absolute;margin-left:107.25pt;margin-top:7.5pt;width:96pt;height:55.5pt;z-index:1;visibility:visible" fillcolor="#ffffe1" o:insetmode="auto">
+                  <v:fill color2="#ffffe1"/>
+                  <v:shadow on="t" color="black" obscured="t"/>
+                  <v:path o:connecttype="none"/>
+                  <v:textbox style="mso-direction-alt:auto">
+                    <div style="text-align:left"></div>
+                  </v:textbox>
+                  <x:ClientData ObjectType="Note">
+                    <x:MoveWithCells/>
+                    <x:SizeWithCells/>
+                    <x:Anchor>2, 15, 0, 10, 4, 15, 4, 4</x:Anchor>
+                    <x:AutoFill>False</x:AutoFill>
+                    <x:Row>1</x:Row>
+                    <x:Column>1</x:Column>
+                    <x:Visible/>
+                  </x:ClientData>
+                </v:shape>
+                <v:shape id="_x0000_s1026" type="#_x0000_t202" style="position:absolute;margin-left:155.25pt;margin-top:37.5pt;width:150pt;height:75pt;z-index:2;visibility:hidden" fillcolor="#ccffcc" o:insetmode="auto">
+                  <v:fill color2="#ffffe1"/>
+                  <v:shadow on="t" color="black" obscured="t"/>
+                  <v:path o:connecttype="none"/>
+                  <v:textbox style="mso-direction-alt:auto">
+                    <div style="text-align:left"></div>
+                  </v:textbox>
+                  <x:ClientData ObjectType="Note">
+                    <x:MoveWithCells/>
+                    <x:SizeWithCells/>
+                    <x:Anchor>3, 15, 2, 10, 6, 23, 7, 10</x:Anchor>
+                    <x:AutoFill>False</x:AutoFill>
+                    <x:Row>3</x:Row>
+                    <x:Column>2</x:Column>
+                  </x:ClientData>
+                </v:shape>
+                </xml>
+            "##,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
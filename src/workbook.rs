@@ -222,6 +222,7 @@ use std::io::{Cursor, Seek, Write};
 use std::mem;
 use std::path::Path;
 
+use crate::custom_xml::CustomXmlProperties;
 use crate::error::XlsxError;
 use crate::format::Format;
 use crate::packager::Packager;
@@ -315,6 +316,7 @@ pub struct Workbook {
     pub(crate) num_formats: Vec<String>,
     pub(crate) has_hyperlink_style: bool,
     pub(crate) embedded_images: Vec<Image>,
+    pub(crate) custom_xml_parts: Vec<(String, String)>,
     xf_indices: HashMap<Format, u32>,
     dxf_indices: HashMap<Format, u32>,
     active_tab: u16,
@@ -322,6 +324,11 @@ pub struct Workbook {
     defined_names: Vec<DefinedName>,
     user_defined_names: Vec<DefinedName>,
     read_only_mode: u8,
+    track_changes: bool,
+    default_right_to_left: bool,
+    default_formula_result: Option<String>,
+    calc_mode: CalcMode,
+    force_full_calc_on_load: Option<bool>,
 }
 
 impl Default for Workbook {
@@ -383,6 +390,11 @@ impl Workbook {
             border_count: 0,
             num_formats: vec![],
             read_only_mode: 0,
+            track_changes: false,
+            default_right_to_left: false,
+            default_formula_result: None,
+            calc_mode: CalcMode::Auto,
+            force_full_calc_on_load: None,
             has_hyperlink_style: false,
             worksheets: vec![],
             xf_formats: vec![],
@@ -392,6 +404,7 @@ impl Workbook {
             xf_indices: HashMap::new(),
             dxf_indices: HashMap::new(),
             embedded_images: vec![],
+            custom_xml_parts: vec![],
         };
 
         // Initialize the workbook with the same function used to reset it.
@@ -460,6 +473,11 @@ impl Workbook {
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.set_right_to_left(self.default_right_to_left);
+
+        if let Some(result) = &self.default_formula_result {
+            worksheet.set_formula_result_default(result);
+        }
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -467,6 +485,68 @@ impl Workbook {
         worksheet
     }
 
+    /// Add a new chartsheet to a workbook.
+    ///
+    /// The `add_chartsheet()` method adds a new chartsheet to a workbook. A
+    /// chartsheet is a sheet that only displays a single chart, on its own
+    /// tab, rather than a chart embedded in a worksheet. The chart is added
+    /// with [`Worksheet::insert_chart()`], the row and column parameters are
+    /// ignored.
+    ///
+    /// The chartsheet is represented by a [`Worksheet`] object that only
+    /// supports the methods that apply to a sheet as a whole, see
+    /// [`Worksheet::new_chartsheet()`] for details.
+    ///
+    /// The chartsheet is given the Excel default name of Chart1, Chart2, etc.
+    /// This can be changed with [`Worksheet::set_name()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a pie chart to a chartsheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_chartsheet.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add some data for the chart.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_column(0, 0, ["Apple", "Cherry", "Pecan"])?;
+    ///     worksheet.write_column(0, 1, [60, 30, 10])?;
+    ///
+    ///     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Pie);
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories("Sheet1!$A$1:$A$3")
+    ///         .set_values("Sheet1!$B$1:$B$3");
+    ///
+    ///     // Add the chart to a chartsheet.
+    ///     let chartsheet = workbook.add_chartsheet();
+    ///     chartsheet.insert_chart(0, 0, &chart)?;
+    ///     chartsheet.set_active(true);
+    ///
+    ///     workbook.save("chartsheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_chartsheet(&mut self) -> &mut Worksheet {
+        let name = format!("Chart{}", self.num_chartsheets() + 1);
+
+        let mut chartsheet = Worksheet::new_chartsheet();
+        chartsheet.set_name(&name).unwrap();
+
+        self.worksheets.push(chartsheet);
+        let chartsheet = self.worksheets.last_mut().unwrap();
+
+        chartsheet
+    }
+
     /// Get a worksheet reference by index.
     ///
     /// Get a reference to a worksheet created via
@@ -795,7 +875,11 @@ impl Workbook {
     ///
     pub fn push_worksheet(&mut self, mut worksheet: Worksheet) {
         if worksheet.name().is_empty() {
-            let name = format!("Sheet{}", self.worksheets.len() + 1);
+            let name = if worksheet.is_chartsheet {
+                format!("Chart{}", self.num_chartsheets() + 1)
+            } else {
+                format!("Sheet{}", self.worksheets.len() + 1)
+            };
             worksheet.set_name(&name).unwrap();
         }
 
@@ -1005,6 +1089,10 @@ impl Workbook {
     ///     worksheet.write_formula(0, 0, "=Exchange_rate")?;
     /// ```
     ///
+    /// The name can refer to a constant value like `"=0.2"`, a cell or range
+    /// like `"=Sheet1!$A$1:$A$10"`, or a formula like `"=Sheet1!$A$1*2"`. The
+    /// leading `=` is optional.
+    ///
     /// A name defined like this is "global" to the workbook and can be used in
     /// any worksheet in the workbook.  It is also possible to define a
     /// local/worksheet name by prefixing it with the sheet name using the
@@ -1028,9 +1116,13 @@ impl Workbook {
     /// follow the Excel convention and enclose it in single quotes:
     ///
     /// ```text
-    ///     workbook.define_name("'New Data'!Sales", ""=Sheet2!$G$1:$G$10")?;
+    ///     workbook.define_name("'New Data'!Sales", "=Sheet2!$G$1:$G$10")?;
     /// ```
     ///
+    /// Local names can also be defined directly on a worksheet, without having
+    /// to quote the sheet name, using
+    /// [`Worksheet::define_local_name()`](crate::Worksheet::define_local_name).
+    ///
     /// The rules for names in Excel are explained in the Microsoft Office
     /// documentation on how to [Define and use names in
     /// formulas](https://support.microsoft.com/en-us/office/define-and-use-names-in-formulas-4d0f13ac-53b7-422e-afd2-abd7ff379c64)
@@ -1047,6 +1139,12 @@ impl Workbook {
     ///   raise a `ParameterError` error:
     ///   * If the name doesn't start with a letter or underscore.
     ///   * If the name contains `,/*[]:\"'` or `space`.
+    ///   * If the name looks like an Excel cell reference such as `A1` or
+    ///     `R1C1`.
+    ///   * If the name is longer than Excel's limit of 255 characters.
+    ///   * If the name has already been defined in the same Global or Local
+    ///     scope.
+    ///   * If the formula, value or range is blank.
     ///
     /// # Examples
     ///
@@ -1125,32 +1223,22 @@ impl Workbook {
             }
         }
 
-        // Excel requires that the name starts with a letter or underscore.
-        // Also, backspace is allowed but undocumented by Excel.
-        if !defined_name.name.chars().next().unwrap().is_alphabetic()
-            && !defined_name.name.starts_with('_')
-            && !defined_name.name.starts_with('\\')
-        {
-            let error = format!(
-                "Name '{}' must start with a letter or underscore in Excel",
-                defined_name.name
-            );
-            return Err(XlsxError::ParameterError(error));
-        }
+        // Check that the name is valid in Excel.
+        DefinedName::validate_name(&defined_name.name)?;
 
-        // Excel also prohibits certain characters in the name.
-        if defined_name
-            .name
-            .contains([' ', ',', '/', '*', '[', ']', ':', '"', '\''])
-        {
-            let error = format!(
-                "Name '{}' cannot contain any of the characters `,/*[]:\"'` or `space` in Excel",
-                defined_name.name
-            );
+        // Check that the name isn't a duplicate of another name in the same
+        // Global or Local scope.
+        let is_duplicate = self.user_defined_names.iter().any(|existing| {
+            existing.name.to_lowercase() == defined_name.name.to_lowercase()
+                && existing.unquoted_sheet_name() == defined_name.unquoted_sheet_name()
+        });
+
+        if is_duplicate {
+            let error = format!("Defined name '{name}' has already been used in this workbook");
             return Err(XlsxError::ParameterError(error));
         }
 
-        defined_name.range = utility::formula_to_string(formula);
+        defined_name.range = DefinedName::validate_formula(&name, formula)?;
         defined_name.set_sort_name();
 
         self.user_defined_names.push(defined_name);
@@ -1287,6 +1375,320 @@ impl Workbook {
         self
     }
 
+    /// Mark the workbook as a shared workbook with change tracking turned on.
+    ///
+    /// This method sets the Excel "Track Changes" option. When the output file
+    /// is opened Excel will show it as a "Shared" workbook with "Track changes
+    /// while editing" enabled, and any subsequent edits made in Excel will be
+    /// recorded in the change history.
+    ///
+    /// **Note**: `rust_xlsxwriter` doesn't record any revisions itself. The
+    /// file is written with a minimal, empty, revision history so the data
+    /// written by `rust_xlsxwriter` will not appear as tracked changes in
+    /// Excel. Only changes made later in Excel are tracked. The revision
+    /// history is attributed to the document author, see
+    /// [`DocProperties::set_author()`], or to `rust_xlsxwriter` if the author
+    /// isn't set.
+    ///
+    /// Also note that Excel doesn't support some features, such as worksheet
+    /// tables, in shared workbooks. These will be unavailable, or may be
+    /// reported as errors, when the file is opened in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a simple workbook which
+    /// opens as a shared workbook with change tracking turned on.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_track_changes.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     workbook.set_track_changes(true);
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_track_changes(&mut self, enable: bool) -> &mut Workbook {
+        self.track_changes = enable;
+        self
+    }
+
+    /// Display new worksheets from right to left by default.
+    ///
+    /// The `set_default_right_to_left()` method sets the right to left
+    /// direction for all worksheets subsequently added to the workbook via
+    /// [`add_worksheet()`](Workbook::add_worksheet). It is the equivalent of
+    /// calling [`Worksheet::set_right_to_left()`] on each new worksheet and is
+    /// useful for Arabic, Hebrew or other near or far eastern languages.
+    ///
+    /// The default can be overridden for individual worksheets with
+    /// [`Worksheet::set_right_to_left()`]. It doesn't apply to worksheets that
+    /// have already been added, or to worksheets created separately with
+    /// [`Worksheet::new()`] and added with
+    /// [`push_worksheet()`](Workbook::push_worksheet).
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a workbook where all the
+    /// worksheets are displayed from right to left.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_right_to_left.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_default_right_to_left(true);
+    ///
+    ///     // Both worksheets are displayed right to left.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "نص عربي")?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "טקסט בעברית")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_right_to_left(&mut self, enable: bool) -> &mut Workbook {
+        self.default_right_to_left = enable;
+        self
+    }
+
+    /// Set the default formula result for the worksheets in a workbook.
+    ///
+    /// The `rust_xlsxwriter` library doesn't calculate the result of formulas.
+    /// Instead it stores the value 0 as the formula result and Excel
+    /// recalculates it when the file is opened. The
+    /// `set_formula_result_default()` method changes the default result for
+    /// all the worksheets in the workbook and for worksheets subsequently
+    /// added via [`add_worksheet()`](Workbook::add_worksheet). It is the
+    /// equivalent of calling [`Worksheet::set_formula_result_default()`] on
+    /// each of these worksheets, so it also applies to formulas that have
+    /// already been written.
+    ///
+    /// For example, `LibreOffice` recalculates formulas with an empty string
+    /// result, `""`, when it opens a file. Individual formula results can be
+    /// set with [`Worksheet::write_formula_with_result()`] or
+    /// [`Worksheet::set_formula_result()`].
+    ///
+    /// The default doesn't apply to worksheets created separately with
+    /// [`Worksheet::new()`] and added afterwards with
+    /// [`push_worksheet()`](Workbook::push_worksheet). Those worksheets keep
+    /// their own default.
+    ///
+    /// # Parameters
+    ///
+    /// * `result` - The default formula result to write to formula cells.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the default formula result
+    /// for all the worksheets in a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_formula_result_default.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_formula_result_default("");
+    ///
+    ///     // Both worksheets use the empty string as the default result.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_formula(0, 0, "=1+1")?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_formula(0, 0, "=2+2")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Workbook {
+        let result = result.into();
+
+        for worksheet in &mut self.worksheets {
+            worksheet.set_formula_result_default(result.as_str());
+        }
+
+        self.default_formula_result = Some(result);
+        self
+    }
+
+    /// Set the calculation mode for formulas in the workbook.
+    ///
+    /// The `set_calc_mode()` method sets the Excel "Calculation Options" for
+    /// the workbook. By default Excel recalculates formulas automatically, see
+    /// [`CalcMode`] for the other options.
+    ///
+    /// In manual mode Excel doesn't recalculate formulas when the file is
+    /// opened or saved so formulas should be written with a cached result, for
+    /// example using [`Worksheet::write_formula_with_result()`]. Alternatively
+    /// a recalculation on load can be forced with
+    /// [`set_force_full_calc_on_load()`](Workbook::set_force_full_calc_on_load).
+    ///
+    /// # Parameters
+    ///
+    /// * `mode` - The calculation mode, as defined by [`CalcMode`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the calculation mode of a
+    /// workbook to manual.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_calc_mode.rs
+    /// #
+    /// # use rust_xlsxwriter::{CalcMode, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_calc_mode(CalcMode::Manual);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_formula_with_result(0, 0, "=1+1", "2", &Format::new())?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_calc_mode(&mut self, mode: CalcMode) -> &mut Workbook {
+        self.calc_mode = mode;
+        self
+    }
+
+    /// Force Excel to fully recalculate the workbook when it is opened.
+    ///
+    /// By default `rust_xlsxwriter` sets a flag in the file that tells Excel
+    /// to recalculate all formulas when the workbook is opened, since the
+    /// library doesn't calculate formula results itself.
+    ///
+    /// The `set_force_full_calc_on_load()` method makes this explicit. When
+    /// enabled it also sets the calculation engine id of the file to 0, which
+    /// forces Excel, and other applications that check the id, to treat any
+    /// cached formula results as stale. When disabled the flag is turned off
+    /// and applications may display the cached formula results without
+    /// recalculating them.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates forcing a full recalculation of the
+    /// workbook formulas when the file is opened.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_force_full_calc_on_load.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_force_full_calc_on_load(true);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_formula(0, 0, "=1+1")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_force_full_calc_on_load(&mut self, enable: bool) -> &mut Workbook {
+        self.force_full_calc_on_load = Some(enable);
+        self
+    }
+
+    /// Add a custom XML data part to a workbook.
+    ///
+    /// Excel files can contain custom XML data "islands" that are used by
+    /// document management systems and other applications to store metadata
+    /// along with the workbook. Excel doesn't display the data but it
+    /// preserves it when the file is edited and saved.
+    ///
+    /// The `add_custom_xml_part()` method stores the XML data as a
+    /// `customXml/item{N}.xml` part in the file along with an associated
+    /// properties part that identifies it with a unique GUID style `itemID`.
+    ///
+    /// The XML is stored as is and isn't checked by `rust_xlsxwriter`, so it
+    /// should be a complete, well formed, XML document.
+    ///
+    /// # Parameters
+    ///
+    /// * `xml` - The custom XML data.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a custom XML part to a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_custom_xml_part.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     let xml = r#"<?xml version="1.0" encoding="UTF-8"?><document id="1234"/>"#;
+    ///     let custom_xml_id = workbook.add_custom_xml_part(xml);
+    ///
+    ///     println!("Custom XML itemID: {}", custom_xml_id.item_id());
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_custom_xml_part(&mut self, xml: &str) -> CustomXmlId {
+        let index = self.custom_xml_parts.len() + 1;
+        let item_id = CustomXmlProperties::item_id(xml, index);
+
+        self.custom_xml_parts
+            .push((xml.to_string(), item_id.clone()));
+
+        CustomXmlId { item_id }
+    }
+
     // -----------------------------------------------------------------------
     // Internal function/methods.
     // -----------------------------------------------------------------------
@@ -1335,6 +1737,14 @@ impl Workbook {
             }
         }
 
+        // Check that chartsheets contain a chart, which is required by Excel.
+        for worksheet in &self.worksheets {
+            if worksheet.is_chartsheet && worksheet.charts.is_empty() {
+                let error = format!("Chartsheet '{}' doesn't contain a chart", worksheet.name);
+                return Err(XlsxError::ParameterError(error));
+            }
+        }
+
         // Check for duplicate sheet names, which aren't allowed by Excel
         let mut unique_worksheet_names = HashSet::new();
         for worksheet in &self.worksheets {
@@ -1423,6 +1833,14 @@ impl Workbook {
         Ok(())
     }
 
+    // Get the number of chartsheets in the workbook.
+    fn num_chartsheets(&self) -> usize {
+        self.worksheets
+            .iter()
+            .filter(|sheet| sheet.is_chartsheet)
+            .count()
+    }
+
     // Iterates through the worksheets and find which is the user defined Active
     // sheet. If none has been set then default to the first sheet, like Excel.
     fn set_active_worksheets(&mut self) {
@@ -1478,8 +1896,11 @@ impl Workbook {
     // Convert the images in the workbooks into drawing files and rel links.
     fn prepare_drawings(&mut self) {
         let mut chart_id = 1;
+        let mut chartex_id = 1;
         let mut drawing_id = 1;
         let mut vml_drawing_id = 1;
+        let mut comment_id = 1;
+        let mut threaded_comment_id = 1;
         let mut image_id = self.embedded_images.len() as u32;
 
         // These are the image ids for each unique image file.
@@ -1496,7 +1917,8 @@ impl Workbook {
             }
 
             if !worksheet.charts.is_empty() {
-                chart_id = worksheet.prepare_worksheet_charts(chart_id, drawing_id);
+                (chart_id, chartex_id) =
+                    worksheet.prepare_worksheet_charts(chart_id, chartex_id, drawing_id);
             }
 
             // Increase the drawing number/id for image/chart file.
@@ -1504,6 +1926,19 @@ impl Workbook {
                 drawing_id += 1;
             }
 
+            // Notes are stored in a VML file and a comments file.
+            if worksheet.has_notes() {
+                worksheet.prepare_vml_notes(vml_drawing_id, comment_id);
+                vml_drawing_id += 1;
+                comment_id += 1;
+            }
+
+            // Threaded comments are also stored in a threadedComment file.
+            if !worksheet.threaded_comments.is_empty() {
+                worksheet.prepare_threaded_comments(threaded_comment_id);
+                threaded_comment_id += 1;
+            }
+
             if worksheet.has_header_footer_images() {
                 // The header/footer images are counted from the last worksheet id.
                 let base_image_id = worksheet_image_ids.len() as u32;
@@ -1880,9 +2315,13 @@ impl Workbook {
         &mut self,
         mut package_options: PackagerOptions,
     ) -> Result<PackagerOptions, XlsxError> {
-        package_options.num_worksheets = self.worksheets.len() as u16;
+        package_options.num_chartsheets = self.num_chartsheets() as u16;
+        package_options.num_worksheets =
+            self.worksheets.len() as u16 - package_options.num_chartsheets;
         package_options.doc_security = self.read_only_mode;
+        package_options.has_revisions = self.track_changes;
         package_options.num_embedded_images = self.embedded_images.len() as u32;
+        package_options.num_custom_xml_parts = self.custom_xml_parts.len() as u16;
 
         let mut defined_names = self.user_defined_names.clone();
         let mut sheet_names: HashMap<String, u16> = HashMap::new();
@@ -1894,11 +2333,20 @@ impl Workbook {
             let quoted_sheet_name = utility::quote_sheetname(&sheet_name);
             sheet_names.insert(sheet_name.clone(), sheet_index as u16);
 
-            if worksheet.visible == Visible::VeryHidden {
-                package_options.worksheet_names.push(String::new());
+            let app_sheet_name = if worksheet.visible == Visible::VeryHidden {
+                String::new()
+            } else {
+                sheet_name.clone()
+            };
+
+            if worksheet.is_chartsheet {
+                package_options.chartsheet_names.push(app_sheet_name);
             } else {
-                package_options.worksheet_names.push(sheet_name.clone());
+                package_options.worksheet_names.push(app_sheet_name);
             }
+            package_options
+                .chartsheet_flags
+                .push(worksheet.is_chartsheet);
 
             package_options.properties = self.properties.clone();
 
@@ -1923,12 +2371,31 @@ impl Workbook {
                 package_options.has_vml = true;
             }
 
+            if worksheet.has_notes() {
+                package_options.has_vml = true;
+                package_options.num_comments += 1;
+            }
+
+            if !worksheet.threaded_comments.is_empty() {
+                package_options.num_threaded_comments += 1;
+
+                for author in worksheet.threaded_comment_authors() {
+                    if !package_options.persons.contains(&author) {
+                        package_options.persons.push(author);
+                    }
+                }
+            }
+
             if !worksheet.drawing.drawings.is_empty() {
                 package_options.num_drawings += 1;
             }
 
-            if !worksheet.charts.is_empty() {
-                package_options.num_charts += worksheet.charts.len() as u16;
+            for chart in worksheet.charts.values() {
+                if chart.is_chartex() {
+                    package_options.num_chartex += 1;
+                } else {
+                    package_options.num_charts += 1;
+                }
             }
 
             if !worksheet.tables.is_empty() {
@@ -1957,6 +2424,29 @@ impl Workbook {
                 defined_names.push(defined_name);
             }
 
+            // Store any local/worksheet defined names that were added via the
+            // worksheet.
+            for local_name in &worksheet.local_defined_names {
+                let is_duplicate = self.user_defined_names.iter().any(|existing| {
+                    existing.name.to_lowercase() == local_name.name.to_lowercase()
+                        && existing.unquoted_sheet_name() == sheet_name
+                });
+
+                if is_duplicate {
+                    let error = format!(
+                        "Defined name '{}' has already been used in worksheet '{sheet_name}'",
+                        local_name.name
+                    );
+                    return Err(XlsxError::ParameterError(error));
+                }
+
+                let mut defined_name = local_name.clone();
+                defined_name
+                    .quoted_sheet_name
+                    .clone_from(&quoted_sheet_name);
+                defined_names.push(defined_name);
+            }
+
             // Set the used image types.
             for i in 0..NUM_IMAGE_FORMATS {
                 if worksheet.image_types[i] {
@@ -1984,6 +2474,42 @@ impl Workbook {
             }
         }
 
+        // Check that any hyperlinks to defined names refer to a known name.
+        // Excel doesn't raise an error for a missing name until the link is
+        // clicked so we only warn about it.
+        for worksheet in &self.worksheets {
+            for hyperlink in worksheet.hyperlinks.values() {
+                if !hyperlink.is_defined_name {
+                    continue;
+                }
+
+                let (sheet_name, name) = match hyperlink.rel_anchor.rfind('!') {
+                    Some(position) => (
+                        hyperlink.rel_anchor[0..position]
+                            .trim_matches('\'')
+                            .to_string(),
+                        &hyperlink.rel_anchor[position + 1..],
+                    ),
+                    None => (String::new(), hyperlink.rel_anchor.as_str()),
+                };
+
+                let is_known = defined_names.iter().any(|defined_name| {
+                    let defined_sheet_name = defined_name.unquoted_sheet_name();
+
+                    defined_name.name.to_lowercase() == name.to_lowercase()
+                        && (defined_sheet_name == sheet_name
+                            || (sheet_name.is_empty() && defined_sheet_name == worksheet.name))
+                });
+
+                if !is_known {
+                    eprintln!(
+                        "Hyperlink in worksheet '{}' refers to unknown defined name '{}'.",
+                        worksheet.name, hyperlink.rel_anchor
+                    );
+                }
+            }
+        }
+
         // Excel stores defined names in a sorted order.
         defined_names.sort_by_key(|n| (n.sort_name.clone(), n.range.clone()));
 
@@ -2171,8 +2697,77 @@ impl Workbook {
 
     // Write the <calcPr> element.
     fn write_calc_pr(&mut self) {
-        let attributes = [("calcId", "124519"), ("fullCalcOnLoad", "1")];
+        let mut attributes = vec![];
+
+        // A calcId of 0 forces a recalculation in Excel.
+        if self.force_full_calc_on_load == Some(true) {
+            attributes.push(("calcId", "0"));
+        } else {
+            attributes.push(("calcId", "124519"));
+        }
+
+        match self.calc_mode {
+            CalcMode::Auto => {}
+            CalcMode::AutoNoTable => attributes.push(("calcMode", "autoNoTable")),
+            CalcMode::Manual => {
+                attributes.push(("calcMode", "manual"));
+                attributes.push(("calcOnSave", "0"));
+            }
+        }
+
+        // Manual mode doesn't recalculate on load unless explicitly requested.
+        let full_calc_on_load = self
+            .force_full_calc_on_load
+            .unwrap_or(self.calc_mode != CalcMode::Manual);
+
+        if full_calc_on_load {
+            attributes.push(("fullCalcOnLoad", "1"));
+        }
 
         self.writer.xml_empty_tag("calcPr", &attributes);
     }
 }
+
+// -----------------------------------------------------------------------
+// Helper enums/structs/functions.
+// -----------------------------------------------------------------------
+
+/// The `CustomXmlId` struct identifies a custom XML data part in a workbook.
+///
+/// It is returned by [`Workbook::add_custom_xml_part()`] and holds the GUID
+/// style `itemID` that is written to the properties part of the custom XML
+/// item. Applications that read the file can use this to find the data.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomXmlId {
+    item_id: String,
+}
+
+impl CustomXmlId {
+    /// Get the GUID style `itemID` of the custom XML data part, such as
+    /// `{6E963533-3A3B-4E52-A9A6-2B7A8D0A7C00}`.
+    pub fn item_id(&self) -> &str {
+        &self.item_id
+    }
+}
+
+/// The `CalcMode` enum defines the calculation mode of the formulas in a
+/// workbook.
+///
+/// This equates to the "Calculation Options" in Excel's Formulas tab.
+///
+/// Used with [`Workbook::set_calc_mode()`].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum CalcMode {
+    /// Recalculate formulas automatically when the data they depend on
+    /// changes. This is the default.
+    Auto,
+
+    /// Recalculate formulas automatically, except for data tables.
+    AutoNoTable,
+
+    /// Only recalculate formulas when the user requests it, for example by
+    /// pressing F9.
+    Manual,
+}
@@ -7,8 +7,10 @@
 #[cfg(test)]
 mod workbook_tests {
 
-    use crate::{test_functions::xml_to_vec, XlsxError};
-    use crate::{Table, Workbook};
+    use crate::packager::PackagerOptions;
+    use crate::test_functions::{read_xlsx_files, xml_to_vec};
+    use crate::XlsxError;
+    use crate::{CalcMode, Chart, ChartType, Format, Formula, Table, Workbook};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -54,9 +56,153 @@ mod workbook_tests {
         ];
 
         for name in names {
-            let result = workbook.define_name(name, "");
+            let result = workbook.define_name(name, "=1");
             assert!(matches!(result, Err(XlsxError::ParameterError(_))));
         }
+
+        // Test names that look like cell references, or are too long.
+        let long_name = "a".repeat(256);
+        let names = vec![
+            "",
+            "A1",
+            "a1",
+            "XFD1048576",
+            "Sheet1!B2",
+            "R",
+            "c",
+            "R1C1",
+            "rc",
+            "R2C",
+            &long_name,
+        ];
+
+        for name in names {
+            let result = workbook.define_name(name, "=1");
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+
+        // These names are similar to cell references but are valid in Excel.
+        let names = vec!["XFE1", "A1048577", "ABCD1", "A1B", "Rate", "Cost1"];
+
+        for name in names {
+            let result = workbook.define_name(name, "=1");
+            assert!(result.is_ok());
+        }
+
+        // Test duplicate names in the same scope. Case is ignored.
+        let result = workbook.define_name("rate", "=2");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // The same name is allowed in a different scope.
+        let result = workbook.define_name("Sheet1!Rate", "=3");
+        assert!(result.is_ok());
+
+        let result = workbook.define_name("Sheet1!RATE", "=3");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // The formula, value or range cannot be blank.
+        for formula in ["", "=", " = "] {
+            let result = workbook.define_name("Blank", formula);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
+
+    #[test]
+    fn define_name_constant_and_formula() {
+        let mut workbook = Workbook::default();
+
+        // A constant, a formula and a range are all valid targets.
+        workbook.define_name("Vat_rate", "=0.2").unwrap();
+        workbook.define_name("Double", "=Sheet1!$A$1*2").unwrap();
+        workbook.define_name("Prices", "=Sheet1!$A$1:$A$5").unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 1, "=Double*Vat_rate").unwrap();
+
+        workbook
+            .set_package_options(PackagerOptions::new())
+            .unwrap();
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+
+        assert!(got.contains(r#"<definedName name="Double">Sheet1!$A$1*2</definedName>"#));
+        assert!(got.contains(r#"<definedName name="Prices">Sheet1!$A$1:$A$5</definedName>"#));
+        assert!(got.contains(r#"<definedName name="Vat_rate">0.2</definedName>"#));
+    }
+
+    #[test]
+    fn define_local_name() {
+        let mut workbook = Workbook::default();
+
+        workbook.define_name("Sales", "=Sheet1!$A$1:$A$2").unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(2, 0, "=SUM(Sales)").unwrap();
+        worksheet
+            .define_local_name("Sales", "=Sheet1!$B$1:$B$2")
+            .unwrap();
+
+        let result = worksheet.define_local_name("sales", "=Sheet1!$C$1");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.define_local_name("B2", "=Sheet1!$C$1");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let worksheet = workbook.add_worksheet().set_name("Sheet 2").unwrap();
+        worksheet
+            .define_local_name("Tax", "='Sheet 2'!$A$1")
+            .unwrap();
+
+        workbook
+            .set_package_options(PackagerOptions::new())
+            .unwrap();
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+                <sheet name="Sheet 2" sheetId="2" r:id="rId2"/>
+              </sheets>
+              <definedNames>
+                <definedName name="Sales">Sheet1!$A$1:$A$2</definedName>
+                <definedName name="Sales" localSheetId="0">Sheet1!$B$1:$B$2</definedName>
+                <definedName name="Tax" localSheetId="1">'Sheet 2'!$A$1</definedName>
+              </definedNames>
+              <calcPr calcId="124519" fullCalcOnLoad="1"/>
+            </workbook>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn define_local_name_duplicate() {
+        let mut workbook = Workbook::default();
+
+        workbook
+            .define_name("Sheet1!Sales", "=Sheet1!$A$1")
+            .unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet
+            .define_local_name("Sales", "=Sheet1!$B$1")
+            .unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
     #[test]
@@ -97,4 +243,431 @@ mod workbook_tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn set_track_changes() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.add_worksheet();
+        workbook.set_track_changes(true);
+
+        let files = read_xlsx_files(&mut workbook);
+
+        for filename in [
+            "xl/revisions/revisionHeaders.xml",
+            "xl/revisions/revisionLog1.xml",
+            "xl/revisions/userNames.xml",
+            "xl/revisions/_rels/revisionHeaders.xml.rels",
+        ] {
+            assert!(files.contains_key(filename), "missing {filename}");
+        }
+
+        let content_types = &files["[Content_Types].xml"];
+        assert!(content_types.contains(r#"<Override PartName="/xl/revisions/revisionHeaders.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.revisionHeaders+xml"/>"#));
+        assert!(content_types.contains(r#"<Override PartName="/xl/revisions/revisionLog1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.revisionLog+xml"/>"#));
+        assert!(content_types.contains(r#"<Override PartName="/xl/revisions/userNames.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.userNames+xml"/>"#));
+
+        let workbook_rels = &files["xl/_rels/workbook.xml.rels"];
+        assert!(workbook_rels.contains(r#"<Relationship Id="rId5" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/revisionHeaders" Target="revisions/revisionHeaders.xml"/>"#));
+        assert!(workbook_rels.contains(r#"<Relationship Id="rId6" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/usernames" Target="revisions/userNames.xml"/>"#));
+
+        let revision_headers = &files["xl/revisions/revisionHeaders.xml"];
+        assert!(revision_headers.contains(r#"shared="1""#));
+        assert!(revision_headers.contains(
+            r#"<sheetIdMap count="2"><sheetId val="1"/><sheetId val="2"/></sheetIdMap>"#
+        ));
+
+        let revision_rels = &files["xl/revisions/_rels/revisionHeaders.xml.rels"];
+        assert!(revision_rels.contains(r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/revisionLog" Target="revisionLog1.xml"/>"#));
+
+        // Turning the option off again removes the revision files.
+        workbook.set_track_changes(false);
+
+        let files = read_xlsx_files(&mut workbook);
+        assert!(!files.contains_key("xl/revisions/revisionHeaders.xml"));
+    }
+
+    #[test]
+    fn write_url_defined_name() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.add_worksheet();
+        workbook
+            .define_name("MyNamedRange", "=Sheet2!$A$1:$B$5")
+            .unwrap();
+        workbook
+            .define_name("Sheet2!Sales", "=Sheet2!$C$1")
+            .unwrap();
+
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.write_url(0, 0, "#MyNamedRange").unwrap();
+        worksheet
+            .write_url_with_text(1, 0, "#Sheet2!Sales", "Sales")
+            .unwrap();
+
+        let files = read_xlsx_files(&mut workbook);
+
+        let worksheet_xml = &files["xl/worksheets/sheet1.xml"];
+        assert!(worksheet_xml
+            .contains(r#"<hyperlink ref="A1" location="MyNamedRange" display="MyNamedRange"/>"#));
+        assert!(worksheet_xml
+            .contains(r#"<hyperlink ref="A2" location="Sheet2!Sales" display="Sales"/>"#));
+
+        // Links to defined names don't use the worksheet rels file.
+        assert!(!files.contains_key("xl/worksheets/_rels/sheet1.xml.rels"));
+    }
+
+    #[test]
+    fn format_reuse_across_workbooks() {
+        // A set of prepared formats shared between workbooks.
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+        let formats = [bold.clone(), italic.clone(), bold.clone()];
+
+        // The first workbook uses the formats in order.
+        let mut workbook1 = Workbook::default();
+        let worksheet = workbook1.add_worksheet();
+        for (row, format) in formats.iter().enumerate() {
+            worksheet
+                .write_with_format(row as u32, 0, 1, format)
+                .unwrap();
+        }
+
+        // The second workbook uses them in reverse order.
+        let mut workbook2 = Workbook::default();
+        let worksheet = workbook2.add_worksheet();
+        for (row, format) in formats.iter().rev().enumerate() {
+            worksheet
+                .write_with_format(row as u32, 0, 1, format)
+                .unwrap();
+        }
+
+        // Each workbook has its own deduplicated styles.
+        let files1 = read_xlsx_files(&mut workbook1);
+        let files2 = read_xlsx_files(&mut workbook2);
+
+        for files in [&files1, &files2] {
+            let styles = &files["xl/styles.xml"];
+            assert!(styles.contains(r#"<cellXfs count="3">"#));
+            assert!(styles.contains("<b/>"));
+            assert!(styles.contains("<i/>"));
+        }
+
+        let worksheet1 = &files1["xl/worksheets/sheet1.xml"];
+        assert!(worksheet1.contains(r#"<c r="A1" s="1"><v>1</v></c>"#));
+        assert!(worksheet1.contains(r#"<c r="A2" s="2"><v>1</v></c>"#));
+        assert!(worksheet1.contains(r#"<c r="A3" s="1"><v>1</v></c>"#));
+
+        let worksheet2 = &files2["xl/worksheets/sheet1.xml"];
+        assert!(worksheet2.contains(r#"<c r="A1" s="1"><v>1</v></c>"#));
+        assert!(worksheet2.contains(r#"<c r="A2" s="2"><v>1</v></c>"#));
+        assert!(worksheet2.contains(r#"<c r="A3" s="1"><v>1</v></c>"#));
+
+        // The shared formats aren't changed by being used.
+        assert_eq!(formats[0], bold);
+        assert_eq!(formats[1], italic);
+    }
+
+    #[test]
+    fn set_default_right_to_left() {
+        let mut workbook = Workbook::new();
+        workbook.set_default_right_to_left(true);
+
+        // The frozen pane layout is the same as for a left to right sheet.
+        // Excel mirrors it so that the frozen column is on the right.
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_freeze_panes(0, 1).unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<sheetView rightToLeft="1" workbookViewId="0"><pane xSplit="1" topLeftCell="B1" activePane="topRight" state="frozen"/><selection pane="topRight"/></sheetView>"#
+        ));
+
+        // The default can be overridden per worksheet.
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_right_to_left(false);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<sheetView workbookViewId="0"/>"#));
+    }
+
+    #[test]
+    fn add_chartsheet() {
+        let mut workbook = Workbook::new();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_column(0, 0, [60, 30, 10]).unwrap();
+
+        let mut chart = Chart::new(ChartType::Pie);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+        let chartsheet = workbook.add_chartsheet();
+        chartsheet.insert_chart(0, 0, &chart).unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_chart(0, 0, &chart).unwrap();
+
+        let files = read_xlsx_files(&mut workbook);
+
+        // The sheet relationships are in workbook order with separate
+        // worksheet and chartsheet numbering.
+        let workbook_rels = &files["xl/_rels/workbook.xml.rels"];
+        assert!(workbook_rels.contains(
+            r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet" Target="chartsheets/sheet1.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet2.xml"/>"#
+        ));
+
+        let workbook_xml = &files["xl/workbook.xml"];
+        assert!(workbook_xml.contains(r#"<sheet name="Chart1" sheetId="2" r:id="rId2"/>"#));
+
+        let content_types = &files["[Content_Types].xml"];
+        assert!(content_types.contains(
+            r#"<Override PartName="/xl/chartsheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml"/>"#
+        ));
+        assert!(content_types.contains(r#"<Override PartName="/xl/worksheets/sheet2.xml""#));
+
+        let chartsheet_rels = &files["xl/chartsheets/_rels/sheet1.xml.rels"];
+        assert!(chartsheet_rels.contains(r#"Target="../drawings/drawing1.xml""#));
+
+        let chartsheet = &files["xl/chartsheets/sheet1.xml"];
+        assert!(chartsheet.contains(r#"<drawing r:id="rId1"/></chartsheet>"#));
+
+        // The chartsheet chart doesn't have print settings.
+        let chart1 = &files["xl/charts/chart1.xml"];
+        let chart2 = &files["xl/charts/chart2.xml"];
+        assert!(!chart1.contains("<c:printSettings>"));
+        assert!(chart2.contains("<c:printSettings>"));
+
+        let app = &files["docProps/app.xml"];
+        assert!(app.contains(
+            r#"<vt:lpstr>Worksheets</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant><vt:variant><vt:lpstr>Charts</vt:lpstr></vt:variant><vt:variant><vt:i4>1</vt:i4>"#
+        ));
+        assert!(app.contains(
+            r#"<vt:lpstr>Sheet1</vt:lpstr><vt:lpstr>Sheet3</vt:lpstr><vt:lpstr>Chart1</vt:lpstr>"#
+        ));
+    }
+
+    #[test]
+    fn chartsheet_without_chart() {
+        let mut workbook = Workbook::new();
+        workbook.add_chartsheet();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn set_calc_mode() {
+        let tests = [
+            (
+                None,
+                None,
+                r#"<calcPr calcId="124519" fullCalcOnLoad="1"/>"#,
+            ),
+            (
+                Some(CalcMode::Auto),
+                Some(true),
+                r#"<calcPr calcId="0" fullCalcOnLoad="1"/>"#,
+            ),
+            (
+                Some(CalcMode::AutoNoTable),
+                None,
+                r#"<calcPr calcId="124519" calcMode="autoNoTable" fullCalcOnLoad="1"/>"#,
+            ),
+            (
+                Some(CalcMode::Manual),
+                None,
+                r#"<calcPr calcId="124519" calcMode="manual" calcOnSave="0"/>"#,
+            ),
+            (
+                Some(CalcMode::Manual),
+                Some(true),
+                r#"<calcPr calcId="0" calcMode="manual" calcOnSave="0" fullCalcOnLoad="1"/>"#,
+            ),
+            (None, Some(false), r#"<calcPr calcId="124519"/>"#),
+        ];
+
+        for (mode, full_calc_on_load, expected) in tests {
+            let mut workbook = Workbook::new();
+
+            if let Some(mode) = mode {
+                workbook.set_calc_mode(mode);
+            }
+            if let Some(enable) = full_calc_on_load {
+                workbook.set_force_full_calc_on_load(enable);
+            }
+
+            workbook.write_calc_pr();
+
+            let got = workbook.writer.read_to_str();
+            assert_eq!(expected, got);
+        }
+    }
+
+    #[test]
+    fn set_formula_result_default() {
+        let mut workbook = Workbook::new();
+
+        // The default applies to worksheets, and formulas, that already exist
+        // but not to explicit results.
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 0, "=1+1").unwrap();
+        worksheet
+            .write_formula(1, 0, Formula::new("=2+2").set_result("4"))
+            .unwrap();
+
+        workbook.set_formula_result_default("");
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 0, "=1+1").unwrap();
+
+        // The default can be overridden per worksheet.
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 0, "=1+1").unwrap();
+        worksheet.set_formula_result_default("0");
+
+        let mut got = vec![];
+        for worksheet in &mut workbook.worksheets {
+            worksheet.assemble_xml_file();
+            got.push(worksheet.writer.read_to_str().to_string());
+        }
+
+        assert!(got[0].contains(r#"<c r="A1" t="str"><f>1+1</f><v></v></c>"#));
+        assert!(got[0].contains(r#"<c r="A2"><f>2+2</f><v>4</v></c>"#));
+        assert!(got[1].contains(r#"<c r="A1" t="str"><f>1+1</f><v></v></c>"#));
+        assert!(got[2].contains(r#"<c r="A1"><f>1+1</f><v>0</v></c>"#));
+    }
+
+    #[test]
+    fn clear_print_area() {
+        let mut workbook = Workbook::new();
+
+        let worksheet = workbook.add_worksheet();
+        assert_eq!(worksheet.print_area(), None);
+
+        worksheet.set_print_area(0, 0, 9, 2).unwrap();
+        worksheet.add_print_area(0, 4, 9, 6).unwrap();
+        assert_eq!(
+            worksheet.print_area(),
+            Some("$A$1:$C$10,$E$1:$G$10".to_string())
+        );
+
+        worksheet.set_print_area(0, 0, 1_048_575, 2).unwrap();
+        assert_eq!(worksheet.print_area(), Some("$A:$C".to_string()));
+
+        worksheet.clear_print_area();
+        assert_eq!(worksheet.print_area(), None);
+
+        workbook
+            .set_package_options(PackagerOptions::new())
+            .unwrap();
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        assert!(!got.contains("Print_Area"));
+        assert!(!got.contains("<definedNames>"));
+    }
+
+    #[test]
+    fn add_print_area() {
+        let mut workbook = Workbook::new();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_print_area(0, 0, 9, 2).unwrap();
+        worksheet.add_print_area(0, 4, 9, 6).unwrap();
+
+        // Setting the print area again replaces the additional areas.
+        let worksheet = workbook.add_worksheet();
+        worksheet.add_print_area(0, 0, 9, 2).unwrap();
+        worksheet.add_print_area(0, 4, 9, 6).unwrap();
+        worksheet.set_print_area(0, 0, 3, 3).unwrap();
+
+        let result = worksheet.add_print_area(0, 4, 0, 3);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        // A range of the entire worksheet is ignored.
+        worksheet.add_print_area(0, 0, 1_048_575, 16_383).unwrap();
+        assert_eq!(worksheet.print_area(), Some("$A$1:$D$4".to_string()));
+
+        let files = read_xlsx_files(&mut workbook);
+        let xml = &files["xl/workbook.xml"];
+
+        assert!(xml.contains(
+            r#"<definedName name="_xlnm.Print_Area" localSheetId="0">Sheet1!$A$1:$C$10,Sheet1!$E$1:$G$10</definedName>"#
+        ));
+        assert!(xml.contains(
+            r#"<definedName name="_xlnm.Print_Area" localSheetId="1">Sheet2!$A$1:$D$4</definedName>"#
+        ));
+    }
+
+    #[test]
+    fn chart_cache_from_formula_results() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        // Formula cells with cached numeric, boolean and error results.
+        worksheet
+            .write(0, 0, Formula::new("=1+1").set_result("2"))
+            .unwrap();
+        worksheet
+            .write(1, 0, Formula::new("=2*3").set_result("6"))
+            .unwrap();
+        worksheet
+            .write(2, 0, Formula::new("=1=1").set_result("TRUE"))
+            .unwrap();
+        worksheet
+            .write(3, 0, Formula::new("=NA()").set_result("#N/A"))
+            .unwrap();
+        worksheet
+            .write(4, 0, Formula::new("=2.5*2").set_result("5"))
+            .unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        worksheet.insert_chart(0, 2, &chart).unwrap();
+
+        let files = read_xlsx_files(&mut workbook);
+        let xml = &files["xl/charts/chart1.xml"];
+
+        assert!(xml.contains(concat!(
+            r#"<c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="5"/>"#,
+            r#"<c:pt idx="0"><c:v>2</c:v></c:pt>"#,
+            r#"<c:pt idx="1"><c:v>6</c:v></c:pt>"#,
+            r#"<c:pt idx="4"><c:v>5</c:v></c:pt>"#,
+            r#"</c:numCache>"#
+        )));
+    }
+
+    #[test]
+    fn add_custom_xml_part() {
+        let mut workbook = Workbook::new();
+        workbook.add_worksheet();
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><document id="1234"/>"#;
+        let custom_xml_id = workbook.add_custom_xml_part(xml);
+
+        let files = read_xlsx_files(&mut workbook);
+
+        assert_eq!(xml, files["customXml/item1.xml"]);
+
+        let item_props = &files["customXml/itemProps1.xml"];
+        let item_id = format!(r#"ds:itemID="{}""#, custom_xml_id.item_id());
+        assert!(item_props.contains(&item_id));
+
+        let item_rels = &files["customXml/_rels/item1.xml.rels"];
+        assert!(item_rels.contains(
+            r#"Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps" Target="itemProps1.xml""#
+        ));
+
+        let workbook_rels = &files["xl/_rels/workbook.xml.rels"];
+        assert!(workbook_rels.contains(
+            r#"Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml" Target="../customXml/item1.xml""#
+        ));
+
+        let content_types = &files["[Content_Types].xml"];
+        assert!(content_types.contains(
+            r#"<Override PartName="/customXml/itemProps1.xml" ContentType="application/vnd.openxmlformats-officedocument.customXmlProperties+xml"/>"#
+        ));
+    }
 }
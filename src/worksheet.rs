@@ -1050,35 +1050,43 @@ use std::borrow::Cow;
 use std::cmp;
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
 use std::io::Write;
 use std::mem;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "chrono")]
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 #[cfg(feature = "serde")]
 use crate::{
-    deserialize_headers, serializer::SerializerState, CustomSerializeField,
-    SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader, TableData, XlsxSerialize,
+    deserialize_headers,
+    serializer::{DeserializerCell, DeserializerRow, SerializerState},
+    CustomSerializeField, SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader,
+    TableData, XlsxSerialize,
 };
 
 use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject};
 use crate::error::XlsxError;
 use crate::format::Format;
 use crate::formula::Formula;
+use crate::person_list::PersonList;
 use crate::shared_strings_table::SharedStringsTable;
 use crate::styles::Styles;
-use crate::vml::VmlInfo;
+use crate::threaded_comments::ThreadedCommentInfo;
+use crate::vml::{VmlInfo, VmlNoteInfo};
 use crate::xmlwriter::{XMLWriter, XML_WRITE_ERROR};
 use crate::{
     static_regex, utility, Chart, ChartEmptyCells, ChartRangeCacheData, ChartRangeCacheDataType,
-    Color, ConditionalFormat, ExcelDateTime, FilterCondition, FilterCriteria, FilterData,
-    FilterDataType, HeaderImagePosition, HyperlinkType, Image, IntoColor, IntoExcelDateTime,
-    ObjectMovement, ProtectionOptions, Sparkline, SparklineType, Table, TableFunction, Url,
+    Color, ConditionalFormat, DataValidation, ExcelDateTime, FilterCondition, FilterCriteria,
+    FilterData, FilterDataType, HeaderImagePosition, HyperlinkType, Image, IntoColor,
+    IntoExcelDateTime, Note, NotePrintPosition, ObjectMovement, ProtectionOptions, Sparkline,
+    SparklineType, Table, TableFunction, ThreadedComment, Url,
 };
 
 /// Integer type to represent a zero indexed row number. Excel's limit for rows
@@ -1096,6 +1104,7 @@ pub(crate) const MAX_PARAMETER_LEN: usize = 255;
 const MAX_STRING_LEN: usize = 32_767;
 const DEFAULT_COL_WIDTH: f64 = 8.43;
 const DEFAULT_ROW_HEIGHT: f64 = 15.0;
+const MAX_OUTLINE_LEVEL: u8 = 7;
 const COLUMN_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 /// The `Worksheet` struct represents an Excel worksheet. It handles operations
@@ -1180,6 +1189,7 @@ pub struct Worksheet {
     pub(crate) print_area_defined_name: DefinedName,
     pub(crate) repeat_row_cols_defined_name: DefinedName,
     pub(crate) autofilter_defined_name: DefinedName,
+    pub(crate) local_defined_names: Vec<DefinedName>,
     pub(crate) autofilter_area: String,
     pub(crate) xf_formats: Vec<Format>,
     pub(crate) dxf_formats: Vec<Format>,
@@ -1189,12 +1199,19 @@ pub struct Worksheet {
     pub(crate) drawing_object_relationships: Vec<(String, String, String)>,
     pub(crate) drawing_relationships: Vec<(String, String, String)>,
     pub(crate) vml_drawing_relationships: Vec<(String, String, String)>,
+    pub(crate) comment_relationships: Vec<(String, String, String)>,
+    pub(crate) threaded_comment_relationships: Vec<(String, String, String)>,
     pub(crate) images: BTreeMap<(RowNum, ColNum), Image>,
     pub(crate) header_footer_vml_info: Vec<VmlInfo>,
+    pub(crate) notes: BTreeMap<(RowNum, ColNum), Note>,
+    pub(crate) threaded_comments: BTreeMap<(RowNum, ColNum), ThreadedComment>,
+    pub(crate) note_vml_info: Vec<VmlNoteInfo>,
+    pub(crate) default_note_author: String,
     pub(crate) drawing: Drawing,
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
     pub(crate) header_footer_images: [Option<Image>; 6],
     pub(crate) charts: BTreeMap<(RowNum, ColNum), Chart>,
+    pub(crate) is_chartsheet: bool,
     pub(crate) tables: Vec<Table>,
     pub(crate) has_embedded_image_descriptions: bool,
     pub(crate) embedded_images: Vec<Image>,
@@ -1211,11 +1228,14 @@ pub struct Worksheet {
     dxf_indices: HashMap<Format, u32>,
     global_xf_indices: Vec<u32>,
     global_dxf_indices: Vec<u32>,
+    format_id_key: u32,
     changed_rows: HashMap<RowNum, RowOptions>,
     changed_cols: HashMap<ColNum, ColOptions>,
     page_setup_changed: bool,
     tab_color: Color,
     fit_to_page: bool,
+    outline_symbols_below: bool,
+    outline_symbols_right: bool,
     fit_width: u16,
     fit_height: u16,
     paper_size: u8,
@@ -1224,6 +1244,7 @@ pub struct Worksheet {
     portrait: bool,
     page_view: PageView,
     zoom: u16,
+    page_break_preview_zoom: Option<u16>,
     print_scale: u16,
     print_options_changed: bool,
     center_horizontally: bool,
@@ -1233,6 +1254,7 @@ pub struct Worksheet {
     print_black_and_white: bool,
     print_draft: bool,
     print_headings: bool,
+    print_notes: NotePrintPosition,
     header: String,
     footer: String,
     head_footer_changed: bool,
@@ -1248,24 +1270,26 @@ pub struct Worksheet {
     default_result: Box<str>,
     use_future_functions: bool,
     panes: Panes,
-    hyperlinks: BTreeMap<(RowNum, ColNum), Url>,
+    pub(crate) hyperlinks: BTreeMap<(RowNum, ColNum), Url>,
     rel_count: u32,
     protection_on: bool,
     protection_hash: u16,
     protection_options: ProtectionOptions,
     unprotected_ranges: Vec<(String, String, u16)>,
     selected_range: (String, String),
-    top_left_cell: String,
+    top_left_cell: (RowNum, ColNum),
     horizontal_breaks: Vec<u32>,
     vertical_breaks: Vec<u32>,
     filter_conditions: BTreeMap<ColNum, FilterCondition>,
     filter_automatic_off: bool,
     has_drawing_object_linkage: bool,
     cells_with_autofilter: HashSet<(RowNum, ColNum)>,
+    autofit_disabled_cols: HashSet<ColNum>,
     conditional_formats: BTreeMap<String, Vec<Box<dyn ConditionalFormat + Send>>>,
     has_conditional_formats: bool,
     use_x14_extensions: bool,
     has_x14_conditional_formats: bool,
+    data_validations: BTreeMap<String, DataValidation>,
     has_sparklines: bool,
     sparklines: Vec<Sparkline>,
 
@@ -1361,6 +1385,7 @@ impl Worksheet {
         let panes = Panes {
             freeze_cell: (0, 0),
             top_cell: (0, 0),
+            split_position: (0.0, 0.0),
         };
 
         Worksheet {
@@ -1376,6 +1401,7 @@ impl Worksheet {
             print_area_defined_name: DefinedName::new(),
             repeat_row_cols_defined_name: DefinedName::new(),
             autofilter_defined_name: DefinedName::new(),
+            local_defined_names: vec![],
             autofilter_area: String::new(),
             data_table: BTreeMap::new(),
             col_names: HashMap::new(),
@@ -1391,11 +1417,14 @@ impl Worksheet {
             dxf_indices: HashMap::new(),
             global_xf_indices: vec![],
             global_dxf_indices: vec![],
+            format_id_key: NEXT_FORMAT_ID_KEY.fetch_add(1, Ordering::Relaxed),
             changed_rows: HashMap::new(),
             changed_cols: HashMap::new(),
             page_setup_changed: false,
             fit_to_page: false,
             tab_color: Color::Default,
+            outline_symbols_below: true,
+            outline_symbols_right: true,
             fit_width: 1,
             fit_height: 1,
             paper_size: 0,
@@ -1404,6 +1433,7 @@ impl Worksheet {
             portrait: true,
             page_view: PageView::Normal,
             zoom: 100,
+            page_break_preview_zoom: None,
             print_scale: 100,
             print_options_changed: false,
             center_horizontally: false,
@@ -1413,6 +1443,7 @@ impl Worksheet {
             print_black_and_white: false,
             print_draft: false,
             print_headings: false,
+            print_notes: NotePrintPosition::None,
             header: String::new(),
             footer: String::new(),
             head_footer_changed: false,
@@ -1435,29 +1466,38 @@ impl Worksheet {
             drawing_object_relationships: vec![],
             drawing_relationships: vec![],
             vml_drawing_relationships: vec![],
+            comment_relationships: vec![],
+            threaded_comment_relationships: vec![],
             images: BTreeMap::new(),
             drawing: Drawing::new(),
             image_types: [false; NUM_IMAGE_FORMATS],
             header_footer_images: [None, None, None, None, None, None],
             header_footer_vml_info: vec![],
+            notes: BTreeMap::new(),
+            threaded_comments: BTreeMap::new(),
+            note_vml_info: vec![],
+            default_note_author: String::new(),
             rel_count: 0,
             protection_on: false,
             protection_hash: 0,
             protection_options: ProtectionOptions::new(),
             unprotected_ranges: vec![],
             selected_range: (String::new(), String::new()),
-            top_left_cell: String::new(),
+            top_left_cell: (0, 0),
             horizontal_breaks: vec![],
             vertical_breaks: vec![],
             filter_conditions: BTreeMap::new(),
             filter_automatic_off: false,
             charts: BTreeMap::new(),
+            is_chartsheet: false,
             has_drawing_object_linkage: false,
             cells_with_autofilter: HashSet::new(),
+            autofit_disabled_cols: HashSet::new(),
             conditional_formats: BTreeMap::new(),
             has_conditional_formats: false,
             use_x14_extensions: false,
             has_x14_conditional_formats: false,
+            data_validations: BTreeMap::new(),
             embedded_images: vec![],
             embedded_image_ids: HashMap::new(),
             global_embedded_image_indices: vec![],
@@ -1470,6 +1510,72 @@ impl Worksheet {
         }
     }
 
+    /// Create a new chartsheet object to display a chart on its own tab.
+    ///
+    /// A chartsheet is a type of worksheet that only contains a single chart
+    /// and no cell data. The chart fills the sheet and is resized with the
+    /// application window. In `rust_xlsxwriter` a chartsheet is represented by
+    /// a [`Worksheet`] object and the chart is added with
+    /// [`insert_chart()`](Worksheet::insert_chart). The row and column
+    /// parameters are ignored.
+    ///
+    /// A chartsheet supports the worksheet methods that apply to a sheet as a
+    /// whole such as [`set_name()`](Worksheet::set_name),
+    /// [`set_tab_color()`](Worksheet::set_tab_color),
+    /// [`set_zoom()`](Worksheet::set_zoom), [`set_hidden()`](Worksheet::set_hidden),
+    /// [`set_active()`](Worksheet::set_active) and the page setup methods for
+    /// paper size, orientation, margins and headers/footers. Cell data
+    /// written to a chartsheet is ignored. Chartsheets are printed in
+    /// landscape mode by default.
+    ///
+    /// Chartsheets can also be created directly in a workbook with
+    /// [`workbook.add_chartsheet()`](crate::Workbook::add_chartsheet). A
+    /// chartsheet created with `new_chartsheet()` needs to be added to a
+    /// workbook with [`workbook.push_worksheet`](crate::Workbook::push_worksheet).
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a chartsheet and adding it
+    /// to a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_new_chartsheet.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, Worksheet, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add some data for the chart.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+    ///
+    ///     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$6");
+    ///
+    ///     // Add the chart to a chartsheet.
+    ///     let mut chartsheet = Worksheet::new_chartsheet();
+    ///     chartsheet.set_name("Results")?;
+    ///     chartsheet.insert_chart(0, 0, &chart)?;
+    ///
+    ///     workbook.push_worksheet(chartsheet);
+    ///
+    ///     workbook.save("chartsheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn new_chartsheet() -> Worksheet {
+        let mut chartsheet = Worksheet::new();
+        chartsheet.is_chartsheet = true;
+        chartsheet.portrait = false;
+        chartsheet.page_setup_changed = true;
+
+        chartsheet
+    }
+
     /// Set the worksheet name.
     ///
     /// Set the worksheet name. If no name is set the default Excel convention
@@ -1595,7 +1701,8 @@ impl Worksheet {
     /// worksheet cell.
     ///
     /// The types currently supported are:
-    /// - String types: [`&str`], [`String`], `&String` and `Cow<'_, str>`.
+    /// - String types: [`&str`], [`String`], `&String`, `Cow<'_, str>` and
+    ///   [`char`].
     /// - Numbers that convert [`Into`] [`f64`]. Also, u64 and i64 are supported
     ///   with loss of precision outside Excel's integer range of +/-
     ///   999,999,999,999,999 (15 digits).
@@ -1603,6 +1710,8 @@ impl Worksheet {
     /// - [`ExcelDateTime`].
     /// - [`Formula`].
     /// - [`Url`].
+    /// - [`std::time::Duration`]: Written as an elapsed time with a default
+    ///   `[h]:mm:ss` number format.
     /// - [`Option<T>`]: If `T` is a supported type then write the [`Some`]
     ///   value but ignore the [`None`].
     /// - [`Result<T, E>`]: If `T` and `E` are supported types then write `T`
@@ -1651,7 +1760,8 @@ impl Worksheet {
     /// [`IntoExcelData`] to a worksheet cell.
     ///
     /// The types currently supported are:
-    /// - String types: [`&str`], [`String`], `&String` and `Cow<'_, str>`.
+    /// - String types: [`&str`], [`String`], `&String`, `Cow<'_, str>` and
+    ///   [`char`].
     /// - Numbers that convert [`Into`] [`f64`]. Also, u64 and i64 are supported
     ///   with loss of precision outside Excel's integer range of +/-
     ///   999,999,999,999,999 (15 digits).
@@ -1659,6 +1769,8 @@ impl Worksheet {
     /// - [`ExcelDateTime`].
     /// - [`Formula`].
     /// - [`Url`].
+    /// - [`std::time::Duration`]: Written as an elapsed time in the number
+    ///   format of the [`Format`].
     /// - [`Option<T>`]: If `T` is a supported type then write the [`Some`]
     ///   value or [`None`] as a formatted blank cell.
     /// - [`Result<T, E>`]: If `T` and `E` are supported types then write `T`
@@ -1705,6 +1817,105 @@ impl Worksheet {
         data.write_with_format(self, row, col, format)
     }
 
+    /// Register a format with the worksheet and return an id for it.
+    ///
+    /// Each call to [`Worksheet::write_with_format()`] looks up the [`Format`]
+    /// to find its index in the worksheet. For most applications this is
+    /// negligible but when writing millions of cells with a small number of
+    /// formats it can be avoided by registering the formats once and then
+    /// writing the data with [`Worksheet::write_with_format_id()`].
+    ///
+    /// The returned [`FormatId`] is local to the worksheet that created it
+    /// since format indices are resolved per worksheet and only mapped to the
+    /// workbook styles when the file is saved. Registering the same format
+    /// more than once returns the same id.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - The [`Format`] to register.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates registering a format and using it
+    /// to write data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_register_format.rs
+    /// #
+    /// use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Register the format once.
+    ///     let format = Format::new().set_num_format("0.00");
+    ///     let format_id = worksheet.register_format(&format);
+    ///
+    ///     // Write data using the pre-registered format.
+    ///     for row in 0..1000 {
+    ///         worksheet.write_with_format_id(row, 0, f64::from(row) / 3.0, format_id)?;
+    ///     }
+    ///
+    ///     workbook.save("worksheet.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn register_format(&mut self, format: &Format) -> FormatId {
+        FormatId {
+            xf_index: self.format_xf_index(format),
+            worksheet_key: self.format_id_key,
+        }
+    }
+
+    /// Write formatted generic data to a cell using a registered format id.
+    ///
+    /// The `write_with_format_id()` method is the same as
+    /// [`Worksheet::write_with_format()`] except that it uses a [`FormatId`]
+    /// returned by [`Worksheet::register_format()`] instead of a [`Format`].
+    /// This avoids the format lookup on each write. See the example above.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `data` - An type that implements the  [`IntoExcelData`] trait.
+    /// * `format_id` - The [`FormatId`] of a format registered with this
+    ///   worksheet.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// * [`XlsxError::ParameterError`] - The format id wasn't registered with
+    ///   this worksheet. Format ids from other worksheets are rejected.
+    ///
+    pub fn write_with_format_id<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: T,
+        format_id: FormatId,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoExcelData,
+    {
+        if format_id.worksheet_key != self.format_id_key
+            || format_id.xf_index as usize >= self.xf_formats.len()
+        {
+            return Err(XlsxError::ParameterError(format!(
+                "Format id '{}' isn't registered with worksheet '{}'",
+                format_id.xf_index, self.name
+            )));
+        }
+
+        data.write_with_xf_index(self, row, col, format_id.xf_index)
+    }
+
     /// Write an array like data structure as a row of data to a worksheet.
     ///
     /// Write an array of data horizontally rightwards starting from the initial
@@ -1886,6 +2097,74 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Write sparse data to a row of a worksheet.
+    ///
+    /// Write a sequence of `(offset, value)` pairs to a row, where each value
+    /// is written at the column `col + offset`. Columns that don't have an
+    /// offset in the data are left untouched. This is useful for sparse data
+    /// where most of the columns in a row are empty.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed base column number.
+    /// * `data` - An iterator of `(offset, value)` pairs where the value is a
+    ///   type that implements [`IntoExcelData`].
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing sparse data to a row.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_sparse_row.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Some sparse data as (column offset, value) pairs.
+    ///     let data = [(0, 10), (3, 20), (7, 30)];
+    ///
+    ///     // Write the data to columns B, E and I.
+    ///     worksheet.write_sparse_row(0, 1, data)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_sparse_row<I, T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: I,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator<Item = (ColNum, T)>,
+        T: IntoExcelData,
+    {
+        for (offset, item) in data {
+            let Some(col) = col.checked_add(offset) else {
+                return Err(XlsxError::RowColumnLimitError);
+            };
+
+            self.write(row, col, item)?;
+        }
+
+        Ok(self)
+    }
+
     /// Write an array like data structure as a column of data to a worksheet.
     ///
     /// Write an array of data vertically downwards starting from the initial
@@ -2355,6 +2634,108 @@ impl Worksheet {
         self.store_number(row, col, number.into(), Some(format))
     }
 
+    /// Write a number, rounded to a number of decimal places, to a worksheet
+    /// cell.
+    ///
+    /// Write a number to a worksheet cell after rounding it to `decimals`
+    /// decimal places. This is useful for values calculated in Rust, such as
+    /// `0.1 + 0.2`, that have floating point noise like `0.30000000000000004`.
+    ///
+    /// Note, this changes the value that is stored in the file, and that is
+    /// seen by Excel formulas and by other applications that read the file.
+    /// To only change how a number is displayed in Excel use a number format
+    /// instead, see [`Format::set_num_format()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `number` - The number to write to the cell.
+    /// * `decimals` - The number of decimal places to round to. Values above
+    ///   15, the precision of an Excel number, leave the number unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a rounded number to a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_number_rounded.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // This is stored as 0.30000000000000004.
+    ///     worksheet.write_number(0, 0, 0.1 + 0.2)?;
+    ///
+    ///     // This is stored as 0.3.
+    ///     worksheet.write_number_rounded(1, 0, 0.1 + 0.2, 2)?;
+    /// #
+    /// #     workbook.save("numbers.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_number_rounded(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: impl Into<f64>,
+        decimals: u8,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = utility::round_to_decimals(number.into(), decimals);
+
+        // Store the cell data.
+        self.store_number(row, col, number, None)
+    }
+
+    /// Write a formatted number, rounded to a number of decimal places, to a
+    /// worksheet cell.
+    ///
+    /// This method is similar to
+    /// [`write_number_rounded()`](Worksheet::write_number_rounded()) except
+    /// that it also applies a [`Format`] to the cell. The rounding changes the
+    /// stored value and the format only changes how it is displayed.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `number` - The number to write to the cell.
+    /// * `decimals` - The number of decimal places to round to. Values above
+    ///   15, the precision of an Excel number, leave the number unchanged.
+    /// * `format` - The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    pub fn write_number_rounded_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: impl Into<f64>,
+        decimals: u8,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = utility::round_to_decimals(number.into(), decimals);
+
+        // Store the cell data.
+        self.store_number(row, col, number, Some(format))
+    }
+
     /// Write an unformatted string to a worksheet cell.
     ///
     /// Write an unformatted string to a worksheet cell. To write a formatted
@@ -2867,65 +3248,144 @@ impl Worksheet {
         self.store_formula(row, col, formula.into(), Some(format))
     }
 
-    /// Write an  array formula to a worksheet cell.
+    /// Write a formatted formula, and its calculated result, to a worksheet
+    /// cell.
     ///
-    /// The `write_array_formula()` method writes an array formula to a
-    /// cell range. In Excel an array formula is a formula that performs a
-    /// calculation on a range of values. It can return a single value or a
-    /// range/"array" of values.
+    /// The `rust_xlsxwriter` library doesn't calculate the result of a
+    /// formula. Instead it stores a default value of 0 and sets a flag so that
+    /// Excel recalculates the formula when the file is opened. However,
+    /// applications that don't recalculate formulas, such as some file viewers
+    /// or libraries that read xlsx files, will only display the default value.
     ///
-    /// An array formula is displayed with a pair of curly brackets around the
-    /// formula like this: `{=SUM(A1:B1*A2:B2)}`. The `write_array()`
-    /// method doesn't require actually require these so you can omit them in
-    /// the formula, and the equal sign, if you wish like this:
-    /// `SUM(A1:B1*A2:B2)`.
+    /// The `write_formula_with_result()` method stores a cached result along
+    /// with the formula so that these applications see the expected value.
+    /// The result can be a number, such as `"42"` or `"3.14"`, a boolean,
+    /// `"TRUE"` or `"FALSE"`, or any other string. It is the equivalent of
+    /// [`write_formula_with_format()`](Worksheet::write_formula_with_format())
+    /// followed by [`set_formula_result()`](Worksheet::set_formula_result()).
     ///
-    /// For array formulas that return a range of values you must specify the
-    /// range that the return values will be written to with the `first_` and
-    /// `last_` parameters. If the array formula returns a single value then the
-    /// first_ and last_ parameters should be the same, as shown in the example
-    /// below.
+    /// Boolean results are stored in the same way as Excel, as a boolean cell
+    /// type with a value of 1 or 0. See [`Formula::set_result()`].
+    ///
+    /// Note, the result isn't checked against the formula so it is up to the
+    /// user to ensure that it is correct.
     ///
     /// # Parameters
     ///
-    /// * `first_row` - The first row of the range. (All zero indexed.)
-    /// * `first_col` - The first row of the range.
-    /// * `last_row` - The last row of the range.
-    /// * `last_col` - The last row of the range.
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
     /// * `formula` - The formula to write to the cell as a string or [`Formula`].
+    /// * `result` - The formula result to store in the cell.
+    /// * `format` - The [`Format`] property for the cell.
     ///
     /// # Errors
     ///
     /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// * [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates writing an array formulas to a
-    /// worksheet.
+    /// The following example demonstrates writing formulas with numeric,
+    /// boolean and string results to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_write_array_formula.rs
+    /// # // This code is available in examples/doc_worksheet_write_formula_with_result.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #    let worksheet = workbook.add_worksheet();
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Write some formulas with their calculated results.
+    ///     worksheet.write_formula_with_result(0, 0, "=1+2+3", "6", &bold)?;
+    ///     worksheet.write_formula_with_result(1, 0, "=A1>5", "TRUE", &bold)?;
+    ///     worksheet.write_formula_with_result(2, 0, r#"=UPPER("rust")"#, "RUST", &bold)?;
     /// #
-    /// #    // Write some test data.
-    /// #    worksheet.write_number(0, 1, 500)?;
-    /// #    worksheet.write_number(0, 2, 300)?;
-    /// #    worksheet.write_number(1, 1, 10)?;
-    /// #    worksheet.write_number(1, 2, 15)?;
+    /// #     workbook.save("formulas.xlsx")?;
     /// #
-    ///     // Write an array formula that returns a single value.
-    ///     worksheet.write_array_formula(0, 0, 0, 0, "{=SUM(B1:C1*B2:C2)}")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_formula_with_result(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        formula: impl Into<Formula>,
+        result: impl Into<String>,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let formula = formula.into().set_result(result);
+
+        // Store the cell data.
+        self.store_formula(row, col, formula, Some(format))
+    }
+
+    /// Write an  array formula to a worksheet cell.
+    ///
+    /// The `write_array_formula()` method writes an array formula to a
+    /// cell range. In Excel an array formula is a formula that performs a
+    /// calculation on a range of values. It can return a single value or a
+    /// range/"array" of values.
+    ///
+    /// An array formula is displayed with a pair of curly brackets around the
+    /// formula like this: `{=SUM(A1:B1*A2:B2)}`. The `write_array()`
+    /// method doesn't require actually require these so you can omit them in
+    /// the formula, and the equal sign, if you wish like this:
+    /// `SUM(A1:B1*A2:B2)`.
+    ///
+    /// For array formulas that return a range of values you must specify the
+    /// range that the return values will be written to with the `first_` and
+    /// `last_` parameters. If the array formula returns a single value then the
+    /// first_ and last_ parameters should be the same, as shown in the example
+    /// below.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    /// * `formula` - The formula to write to the cell as a string or [`Formula`].
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing an array formulas to a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_array_formula.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #    let worksheet = workbook.add_worksheet();
+    /// #
+    /// #    // Write some test data.
+    /// #    worksheet.write_number(0, 1, 500)?;
+    /// #    worksheet.write_number(0, 2, 300)?;
+    /// #    worksheet.write_number(1, 1, 10)?;
+    /// #    worksheet.write_number(1, 2, 15)?;
+    /// #
+    ///     // Write an array formula that returns a single value.
+    ///     worksheet.write_array_formula(0, 0, 0, 0, "{=SUM(B1:C1*B2:C2)}")?;
     ///
     /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
@@ -3449,6 +3909,15 @@ impl Worksheet {
     ///    or non alphanumeric characters are single quoted as follows `'Sales
     ///    Data'!A1`.
     ///
+    /// 4. Internal links to a defined name in the workbook using a `#` prefix:
+    ///
+    ///    * `#MyNamedRange`
+    ///    * `#Sheet1!MyLocalRange`
+    ///
+    ///    The name should be created with
+    ///    [`Workbook::define_name()`](crate::Workbook::define_name). If the
+    ///    name doesn't exist when the file is saved a warning is displayed.
+    ///
     /// The function will escape the following characters in URLs as required by
     /// Excel, ``\s " < > \ [ ] ` ^ { }``, unless the URL already contains `%xx`
     /// style escapes. In which case it is assumed that the URL was escaped
@@ -4378,6 +4847,98 @@ impl Worksheet {
         self.store_boolean(row, col, boolean, Some(format))
     }
 
+    /// Write a `Result<T, E>` value to a worksheet cell.
+    ///
+    /// Write the `Ok(value)` variant of a [`Result`] to a cell using the
+    /// generic [`Worksheet::write()`] method, or handle the `Err(error)`
+    /// variant according to the [`ResultErrorMode`] parameter. This is useful
+    /// for writing data that has been parsed or converted from another source
+    /// where some of the values may have failed, without needing a `match` for
+    /// each value.
+    ///
+    /// The error variant can be written as the error text, as a blank cell or
+    /// as an Excel `#VALUE!` error.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `result` - A `Result` where the `Ok` type implements
+    ///   [`IntoExcelData`] and the `Err` type implements [`Display`].
+    /// * `on_err` - A [`ResultErrorMode`] value to control how the `Err`
+    ///   variant is written.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing `Result` values to a
+    /// worksheet with the different error modes.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_result.rs
+    /// #
+    /// # use rust_xlsxwriter::{ResultErrorMode, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Some data parsed from strings, some of which will fail.
+    ///     let data = ["123", "456", "abc"];
+    ///
+    ///     for (row, value) in data.iter().enumerate() {
+    ///         let result = value.parse::<i32>();
+    ///         let row = row as u32;
+    ///
+    ///         worksheet.write_result(row, 0, &result, ResultErrorMode::Text)?;
+    ///         worksheet.write_result(row, 1, &result, ResultErrorMode::Blank)?;
+    ///         worksheet.write_result(row, 2, &result, ResultErrorMode::ValueError)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_result<T, E>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        result: &Result<T, E>,
+        on_err: ResultErrorMode,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoExcelData + Clone,
+        E: Display,
+    {
+        match result {
+            Ok(data) => data.clone().write(self, row, col),
+            Err(error) => match on_err {
+                ResultErrorMode::Text => self.store_string(row, col, error.to_string(), None),
+                ResultErrorMode::Blank => {
+                    // Check row and col are in the allowed range without
+                    // updating the dimensions since nothing is written.
+                    if !self.check_dimensions_only(row, col) {
+                        return Err(XlsxError::RowColumnLimitError);
+                    }
+
+                    // Remove any previous value in the cell.
+                    self.remove_cell(row, col);
+
+                    Ok(self)
+                }
+                ResultErrorMode::ValueError => self.store_value_error(row, col),
+            },
+        }
+    }
+
     /// Merge a range of cells.
     ///
     /// The `merge_range()` method allows cells to be merged together so that
@@ -4389,6 +4950,11 @@ impl Worksheet {
     /// `worksheet.write_*()` functions. The same [`Format`] instance should be
     /// used as was used in the merged range, see the example below.
     ///
+    /// The format is applied to every cell in the range, with the string only
+    /// written to the first cell. This ensures that properties such as borders
+    /// are displayed around the entire merged area and not just the first
+    /// cell.
+    ///
     /// # Parameters
     ///
     /// * `first_row` - The first row of the range. (All zero indexed.)
@@ -5078,11 +5644,192 @@ impl Worksheet {
         chart.x_offset = x_offset;
         chart.y_offset = y_offset;
 
+        // A chartsheet only displays a single chart.
+        if self.is_chartsheet {
+            self.charts.clear();
+            self.charts.insert((0, 0), chart);
+            return Ok(self);
+        }
+
         self.charts.insert((row, col), chart);
 
         Ok(self)
     }
 
+    /// Add a note to a worksheet cell.
+    ///
+    /// The `insert_note()` method is used to add a [`Note`] to a cell. A note
+    /// is indicated in Excel by a small red triangle in the top right corner
+    /// of the cell and by default it is displayed when the user hovers over
+    /// the cell.
+    ///
+    /// Notes are hidden by default, see [`Note::set_visible()`]. Each cell can
+    /// only have one note, or threaded comment, so a note added to a cell that
+    /// already has one will replace it.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `note` - The [`Note`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - Note text exceeds Excel's
+    ///   limit of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding notes to worksheet cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_note.rs
+    /// #
+    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some data.
+    ///     worksheet.write(0, 0, "Price")?;
+    ///     worksheet.write(1, 0, 17.5)?;
+    ///
+    ///     // Add notes to the cells.
+    ///     let note = Note::new("Price excludes tax.").set_author("Finance");
+    ///     worksheet.insert_note(1, 0, &note)?;
+    ///
+    ///     let note = Note::new("Prices are in EUR.").set_visible(true);
+    ///     worksheet.insert_note(0, 0, &note)?;
+    /// #
+    /// #     workbook.save("notes.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_note(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        note: &Note,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the note text is within Excel's limit.
+        if note.text.chars().count() > MAX_STRING_LEN {
+            return Err(XlsxError::MaxStringLengthExceeded);
+        }
+
+        self.threaded_comments.remove(&(row, col));
+        self.notes.insert((row, col), note.clone());
+
+        Ok(self)
+    }
+
+    /// Add a threaded comment to a worksheet cell.
+    ///
+    /// The `insert_threaded_comment()` method is used to add a
+    /// [`ThreadedComment`], and any replies, to a cell. Threaded comments are
+    /// the newer style of Excel comment. The older style is added with
+    /// [`Worksheet::insert_note()`].
+    ///
+    /// Each cell can only have one threaded comment, or note, so a comment
+    /// added to a cell that already has one will replace it.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `comment` - The [`ThreadedComment`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - The comment, or reply, text
+    ///   exceeds Excel's limit of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a threaded comment with a
+    /// reply to a worksheet cell.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_threaded_comment.rs
+    /// #
+    /// # use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some data.
+    ///     worksheet.write(0, 0, "Budget")?;
+    ///     worksheet.write(1, 0, 5000)?;
+    ///
+    ///     // Add a threaded comment with a reply.
+    ///     let reply = ThreadedComment::new("Agreed, it is approved.").set_author("Finance");
+    ///     let comment = ThreadedComment::new("Is this the final figure?")
+    ///         .set_author("Sales")
+    ///         .add_reply(&reply);
+    ///
+    ///     worksheet.insert_threaded_comment(1, 0, &comment)?;
+    /// #
+    /// #     workbook.save("threaded_comments.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_threaded_comment(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        comment: &ThreadedComment,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the comment and reply text is within Excel's limit.
+        if std::iter::once(comment)
+            .chain(&comment.replies)
+            .any(|comment| comment.text.chars().count() > MAX_STRING_LEN)
+        {
+            return Err(XlsxError::MaxStringLengthExceeded);
+        }
+
+        self.notes.remove(&(row, col));
+        self.threaded_comments.insert((row, col), comment.clone());
+
+        Ok(self)
+    }
+
+    /// Set the default author name for notes in the worksheet.
+    ///
+    /// Excel stores an author name with each note. This method sets the author
+    /// for any notes in the worksheet that don't have an author set via
+    /// [`Note::set_author()`]. If it isn't set then the author is blank.
+    ///
+    /// The default author is also used for threaded comments and replies that
+    /// don't have an author set via [`ThreadedComment::set_author()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The default note author name.
+    ///
+    pub fn set_default_note_author(&mut self, name: impl Into<String>) -> &mut Worksheet {
+        self.default_note_author = name.into();
+        self
+    }
+
     /// Set the height for a row of cells.
     ///
     /// The `set_row_height()` method is used to change the default height of a
@@ -5161,6 +5908,8 @@ impl Worksheet {
                     height,
                     xf_index: 0,
                     hidden: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5308,6 +6057,8 @@ impl Worksheet {
                     height: DEFAULT_ROW_HEIGHT,
                     xf_index,
                     hidden: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5378,6 +6129,8 @@ impl Worksheet {
                     height: DEFAULT_ROW_HEIGHT,
                     xf_index: 0,
                     hidden: true,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5418,32 +6171,72 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Set the width for a worksheet column.
-    ///
-    /// The `set_column_width()` method is used to change the default width of a
-    /// worksheet column.
-    ///
-    /// The ``width`` parameter sets the column width in the same units used by
-    /// Excel which is: the number of characters in the default font. The
-    /// default width is 8.43 in the default font of Calibri 11. The actual
-    /// relationship between a string width and a column width in Excel is
-    /// complex. See the [following explanation of column
-    /// widths](https://support.microsoft.com/en-us/kb/214123) from the
-    /// Microsoft support documentation for more details. To set the width in
-    /// pixels use the
-    /// [`set_column_width_pixels()`](Worksheet::set_column_width_pixels())
-    /// method.
+    /// Reset the height of a worksheet row to the default height.
     ///
-    /// See also the [`autofit()`](Worksheet::autofit()) method.
+    /// The `clear_row_height()` method is used to remove a row height set
+    /// previously with [`Worksheet::set_row_height()`] or
+    /// [`Worksheet::set_row_height_pixels()`]. This can be useful when a
+    /// worksheet is regenerated from a template. Other row properties such as
+    /// the format or the hidden state aren't changed.
     ///
     /// # Parameters
     ///
-    /// * `col` - The zero indexed column number.
-    /// * `width` - The row width in character units.
+    /// * `row` - The zero indexed row number.
     ///
     /// # Errors
     ///
-    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn clear_row_height(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        // Check row is in the allowed range without updating dimensions.
+        if row >= ROW_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Only update an existing row metadata object, and remove it if it no
+        // longer has any non-default properties.
+        if let Some(row_options) = self.changed_rows.get_mut(&row) {
+            row_options.height = DEFAULT_ROW_HEIGHT;
+
+            if row_options.xf_index == 0
+                && !row_options.hidden
+                && row_options.level == 0
+                && !row_options.collapsed
+            {
+                self.changed_rows.remove(&row);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Set the width for a worksheet column.
+    ///
+    /// The `set_column_width()` method is used to change the default width of a
+    /// worksheet column.
+    ///
+    /// The ``width`` parameter sets the column width in the same units used by
+    /// Excel which is: the number of characters in the default font. The
+    /// default width is 8.43 in the default font of Calibri 11. The actual
+    /// relationship between a string width and a column width in Excel is
+    /// complex. See the [following explanation of column
+    /// widths](https://support.microsoft.com/en-us/kb/214123) from the
+    /// Microsoft support documentation for more details. To set the width in
+    /// pixels use the
+    /// [`set_column_width_pixels()`](Worksheet::set_column_width_pixels())
+    /// method.
+    ///
+    /// See also the [`autofit()`](Worksheet::autofit()) method.
+    ///
+    /// # Parameters
+    ///
+    /// * `col` - The zero indexed column number.
+    /// * `width` - The row width in character units.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
     ///   limits.
     ///
     /// # Examples
@@ -5567,18 +6360,69 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-        let mut width = f64::from(width);
+        self.set_column_width(col, pixels_to_width(f64::from(width)))
+    }
 
-        if width < 12.0 {
-            width /= max_digit_width + padding;
-        } else {
-            width = (width - padding) / max_digit_width;
+    /// Set the width for a worksheet column in explicit units.
+    ///
+    /// The `set_column_width_units()` method is similar to
+    /// [`set_column_width()`](Worksheet::set_column_width()) and
+    /// [`set_column_width_pixels()`](Worksheet::set_column_width_pixels())
+    /// except that the unit of the width is given by a [`ColumnWidth`] enum
+    /// value at the call site. This avoids mixing up character and pixel
+    /// widths in code that uses both.
+    ///
+    /// # Parameters
+    ///
+    /// * `col` - The zero indexed column number.
+    /// * `width` - The column width as a [`ColumnWidth`] value in character
+    ///   units or pixels.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the width of columns in Excel
+    /// in character units and in pixels.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_width_units.rs
+    /// #
+    /// # use rust_xlsxwriter::{ColumnWidth, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add some text.
+    ///     worksheet.write_string(0, 0, "Normal")?;
+    ///     worksheet.write_string(0, 2, "Wider")?;
+    ///     worksheet.write_string(0, 4, "Narrower")?;
+    ///
+    ///     // Set the column widths in character units and in pixels.
+    ///     worksheet.set_column_width_units(2, ColumnWidth::Chars(16.0))?;
+    ///     worksheet.set_column_width_units(4, ColumnWidth::Pixels(33.0))?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_width_units(
+        &mut self,
+        col: ColNum,
+        width: ColumnWidth,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match width {
+            ColumnWidth::Chars(width) => self.set_column_width(col, width),
+            ColumnWidth::Pixels(width) => self.set_column_width(col, pixels_to_width(width)),
         }
-
-        self.set_column_width(col, width)
     }
 
     /// Set the format for a column of cells.
@@ -5607,106 +6451,623 @@ impl Worksheet {
     /// The following example demonstrates setting the format for a column in Excel.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_column_format.rs
+    /// # // This code is available in examples/doc_worksheet_set_column_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, Color, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add for formats.
+    ///     let bold_format = Format::new().set_bold();
+    ///     let red_format = Format::new().set_font_color(Color::Red);
+    ///
+    ///     // Set the column format.
+    ///     worksheet.set_column_format(1, &red_format)?;
+    ///
+    ///     // Add some unformatted text that adopts the column format.
+    ///     worksheet.write_string(0, 1, "Hello")?;
+    ///
+    ///     // Add some formatted text that overrides the column format.
+    ///     worksheet.write_string_with_format(2, 1, "Hello", &bold_format)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_column_format.png">
+    ///
+    pub fn set_column_format(
+        &mut self,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Set a suitable row range for the dimension check/set.
+        let min_row = self.get_min_row();
+
+        // Check column is in the allowed range.
+        if !self.check_dimensions(min_row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Get the index of the format object.
+        let xf_index = self.format_xf_index(format);
+
+        // Update an existing col metadata object or create a new one.
+        match self.changed_cols.get_mut(&col) {
+            Some(col_options) => col_options.xf_index = xf_index,
+            None => {
+                let col_options = ColOptions {
+                    width: DEFAULT_COL_WIDTH,
+                    xf_index,
+                    hidden: false,
+                    autofit: false,
+                    level: 0,
+                    collapsed: false,
+                };
+                self.changed_cols.insert(col, col_options);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Hide a worksheet column.
+    ///
+    /// The `set_column_hidden()` method is used to hide a column. This can be
+    /// used, for example, to hide intermediary steps in a complicated
+    /// calculation.
+    ///
+    /// # Parameters
+    ///
+    /// * `col` - The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates hiding a worksheet column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_hidden.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Hide column B.
+    ///     worksheet.set_column_hidden(1)?;
+    ///
+    ///     worksheet.write_string(0, 3, "Column B is hidden")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_column_hidden.png">
+    ///
+    pub fn set_column_hidden(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
+        // Check if column is in the allowed range without updating dimensions.
+        if col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Update an existing col metadata object or create a new one.
+        match self.changed_cols.get_mut(&col) {
+            Some(col_options) => col_options.hidden = true,
+            None => {
+                let col_options = ColOptions {
+                    width: DEFAULT_COL_WIDTH,
+                    xf_index: 0,
+                    hidden: true,
+                    autofit: false,
+                    level: 0,
+                    collapsed: false,
+                };
+                self.changed_cols.insert(col, col_options);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Reset the width of a worksheet column to the default width.
+    ///
+    /// The `clear_column_width()` method is used to remove a column width set
+    /// previously with [`Worksheet::set_column_width()`],
+    /// [`Worksheet::set_column_width_pixels()`] or
+    /// [`Worksheet::autofit()`]. This can be useful when a worksheet is
+    /// regenerated from a template. Other column properties such as the
+    /// format or the hidden state aren't changed.
+    ///
+    /// # Parameters
+    ///
+    /// * `col` - The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn clear_column_width(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
+        // Check if column is in the allowed range without updating dimensions.
+        if col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Only update an existing col metadata object, and remove it if it no
+        // longer has any non-default properties.
+        if let Some(col_options) = self.changed_cols.get_mut(&col) {
+            col_options.width = DEFAULT_COL_WIDTH;
+            col_options.autofit = false;
+
+            if col_options.xf_index == 0
+                && !col_options.hidden
+                && col_options.level == 0
+                && !col_options.collapsed
+            {
+                self.changed_cols.remove(&col);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Exclude a worksheet column from [`Worksheet::autofit()`].
+    ///
+    /// The `set_column_autofit_disabled()` method is used to stop
+    /// [`Worksheet::autofit()`] from changing the width of a column. This can
+    /// be useful for columns that have been intentionally set to a narrow
+    /// width, such as columns of long comments or descriptions.
+    ///
+    /// Note, `autofit()` never reduces a column width that has been set
+    /// explicitly, it only increases it. This method prevents the increase as
+    /// well.
+    ///
+    /// # Parameters
+    ///
+    /// * `col` - The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates excluding a column from autofit.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_autofit_disabled.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some data.
+    ///     worksheet.write_string(0, 0, "Name")?;
+    ///     worksheet.write_string(0, 1, "A long description of the item")?;
+    ///
+    ///     // Keep the description column narrow.
+    ///     worksheet.set_column_width(1, 10)?;
+    ///     worksheet.set_column_autofit_disabled(1)?;
+    ///
+    ///     // Autofit the other columns.
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_autofit_disabled(
+        &mut self,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check if column is in the allowed range without updating dimensions.
+        if col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.autofit_disabled_cols.insert(col);
+
+        Ok(self)
+    }
+
+    /// Group a range of rows into a worksheet outline group.
+    ///
+    /// Excel allows rows to be grouped together into outline groups so that
+    /// they can be collapsed or expanded by clicking on the outline symbols in
+    /// the margin of the worksheet. This is useful for financial statements and
+    /// other reports where detail rows are summarized by a subtotal row.
+    ///
+    /// Groups can be nested by calling `group_rows()` with a range that is
+    /// inside, or overlaps, a previous group. Each call increments the outline
+    /// level of the rows in the range. Excel supports up to 7 outline levels.
+    ///
+    /// If `collapsed` is `true` the rows in the group are hidden and the row
+    /// after the group, which is the summary row, is marked as collapsed.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the group. Zero indexed.
+    /// * `last_row` - The last row of the group.
+    /// * `collapsed` - Collapse and hide the group.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// * [`XlsxError::ParameterError`] - The group would exceed Excel's limit
+    ///   of 7 outline levels.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates grouping rows into nested outline
+    /// groups with subtotal rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some sample data.
+    /// #     worksheet.write_column(1, 0, ["North", "North", "North"])?;
+    /// #     worksheet.write_column(1, 1, [1000, 1200, 800])?;
+    /// #     worksheet.write(4, 0, "North Total")?;
+    /// #     worksheet.write_formula(4, 1, "=SUBTOTAL(9,B2:B4)")?;
+    /// #     worksheet.write_column(5, 0, ["South", "South"])?;
+    /// #     worksheet.write_column(5, 1, [500, 700])?;
+    /// #     worksheet.write(7, 0, "South Total")?;
+    /// #     worksheet.write_formula(7, 1, "=SUBTOTAL(9,B6:B7)")?;
+    /// #     worksheet.write(8, 0, "Grand Total")?;
+    /// #     worksheet.write_formula(8, 1, "=SUBTOTAL(9,B2:B7)")?;
+    /// #
+    ///     // Group all the regions at the first outline level.
+    ///     worksheet.group_rows(1, 7, false)?;
+    ///
+    ///     // Group the detail rows of each region at the second outline level,
+    ///     // with the "South" group collapsed.
+    ///     worksheet.group_rows(1, 3, false)?;
+    ///     worksheet.group_rows(5, 6, true)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_rows(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_row_group(first_row, last_row, None, collapsed)
+    }
+
+    /// Group a range of rows into an outline group at a specific level.
+    ///
+    /// This method is similar to [`Worksheet::group_rows()`] except that the
+    /// outline level of the rows is set explicitly instead of being
+    /// incremented. This is useful when the structure of a report is known in
+    /// advance, such as a financial statement with subtotal and total rows.
+    ///
+    /// Setting the level of a range of rows overwrites any previous level for
+    /// those rows.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the group. Zero indexed.
+    /// * `last_row` - The last row of the group.
+    /// * `level` - The outline level of the group, in the range 1-7.
+    /// * `collapsed` - Collapse and hide the group.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// * [`XlsxError::ParameterError`] - The level is outside Excel's range of
+    ///   1-7 outline levels.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates grouping rows into nested outline
+    /// groups with explicit outline levels.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_rows_with_level.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some sample data.
+    /// #     worksheet.write_column(1, 0, ["Salaries", "Rent", "Utilities"])?;
+    /// #     worksheet.write_column(1, 1, [5000, 1500, 300])?;
+    /// #     worksheet.write(4, 0, "Operating Costs")?;
+    /// #     worksheet.write_formula(4, 1, "=SUBTOTAL(9,B2:B4)")?;
+    /// #     worksheet.write_column(5, 0, ["Interest", "Fees"])?;
+    /// #     worksheet.write_column(5, 1, [200, 50])?;
+    /// #     worksheet.write(7, 0, "Financing Costs")?;
+    /// #     worksheet.write_formula(7, 1, "=SUBTOTAL(9,B6:B7)")?;
+    /// #     worksheet.write(8, 0, "Total Costs")?;
+    /// #     worksheet.write_formula(8, 1, "=SUBTOTAL(9,B2:B7)")?;
+    /// #
+    ///     // Group all the cost rows at the first outline level.
+    ///     worksheet.group_rows_with_level(1, 7, 1, false)?;
+    ///
+    ///     // Group the detail rows of each category at the second outline level.
+    ///     worksheet.group_rows_with_level(1, 3, 2, false)?;
+    ///     worksheet.group_rows_with_level(5, 6, 2, false)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_rows_with_level(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+        level: u8,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_row_group(first_row, last_row, Some(level), collapsed)
+    }
+
+    /// Group a range of columns into a worksheet outline group.
+    ///
+    /// This method is the column equivalent of
+    /// [`Worksheet::group_rows()`]. Each call increments the outline level of
+    /// the columns in the range so groups can be nested, up to Excel's limit
+    /// of 7 outline levels.
+    ///
+    /// If `collapsed` is `true` the columns in the group are hidden and the
+    /// column after the group, which is the summary column, is marked as
+    /// collapsed.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_col` - The first column of the group. Zero indexed.
+    /// * `last_col` - The last column of the group.
+    /// * `collapsed` - Collapse and hide the group.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First column larger than the
+    ///   last column.
+    /// * [`XlsxError::ParameterError`] - The group would exceed Excel's limit
+    ///   of 7 outline levels.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates grouping columns into an outline
+    /// group.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_columns.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some sample data.
+    /// #     worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1"])?;
+    /// #     worksheet.write_row(1, 0, [100, 200, 300])?;
+    /// #     worksheet.write_formula(1, 3, "=SUM(A2:C2)")?;
+    /// #
+    ///     // Group the monthly columns so they can be collapsed into the
+    ///     // quarterly summary column.
+    ///     worksheet.group_columns(0, 2, false)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_columns(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_column_group(first_col, last_col, None, collapsed)
+    }
+
+    /// Group a range of columns into an outline group at a specific level.
+    ///
+    /// This method is the column equivalent of
+    /// [`Worksheet::group_rows_with_level()`]. The outline level of the
+    /// columns is set explicitly instead of being incremented.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_col` - The first column of the group. Zero indexed.
+    /// * `last_col` - The last column of the group.
+    /// * `level` - The outline level of the group, in the range 1-7.
+    /// * `collapsed` - Collapse and hide the group.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First column larger than the
+    ///   last column.
+    /// * [`XlsxError::ParameterError`] - The level is outside Excel's range of
+    ///   1-7 outline levels.
+    ///
+    pub fn group_columns_with_level(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        level: u8,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_column_group(first_col, last_col, Some(level), collapsed)
+    }
+
+    /// Set the position of the outline group symbols.
+    ///
+    /// By default Excel displays the expand/collapse symbol for an outline
+    /// group below the grouped rows and to the right of the grouped columns,
+    /// since the summary row or column is usually after the detail data. This
+    /// method can be used to display the symbols above the rows or to the left
+    /// of the columns instead, for reports where the summary comes first.
+    ///
+    /// The summary row or column that is marked as collapsed by
+    /// [`Worksheet::group_rows()`] and [`Worksheet::group_columns()`] depends
+    /// on this setting so it should be called before grouping any rows or
+    /// columns.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbols_below` - Display the row group symbols below the group. The
+    ///   default is `true`.
+    /// * `symbols_right` - Display the column group symbols to the right of
+    ///   the group. The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates displaying the outline group symbols
+    /// above the grouped rows, for a report where the summary row comes first.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_outline_settings.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, Color, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    ///     let worksheet = workbook.add_worksheet();
-    ///
-    ///     // Add for formats.
-    ///     let bold_format = Format::new().set_bold();
-    ///     let red_format = Format::new().set_font_color(Color::Red);
-    ///
-    ///     // Set the column format.
-    ///     worksheet.set_column_format(1, &red_format)?;
-    ///
-    ///     // Add some unformatted text that adopts the column format.
-    ///     worksheet.write_string(0, 1, "Hello")?;
-    ///
-    ///     // Add some formatted text that overrides the column format.
-    ///     worksheet.write_string_with_format(2, 1, "Hello", &bold_format)?;
+    /// #     // Add some sample data.
+    /// #     worksheet.write(0, 0, "Total")?;
+    /// #     worksheet.write_formula(0, 1, "=SUBTOTAL(9,B2:B4)")?;
+    /// #     worksheet.write_column(1, 0, ["North", "South", "East"])?;
+    /// #     worksheet.write_column(1, 1, [1000, 1200, 800])?;
+    /// #
+    ///     // Display the outline symbols above the group, next to the summary row.
+    ///     worksheet.set_outline_settings(false, true);
     ///
+    ///     // Group and collapse the detail rows under the summary row.
+    ///     worksheet.group_rows(1, 3, true)?;
+    /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_column_format.png">
-    ///
-    pub fn set_column_format(
+    pub fn set_outline_settings(
         &mut self,
-        col: ColNum,
-        format: &Format,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Set a suitable row range for the dimension check/set.
-        let min_row = self.get_min_row();
-
-        // Check column is in the allowed range.
-        if !self.check_dimensions(min_row, col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Get the index of the format object.
-        let xf_index = self.format_xf_index(format);
-
-        // Update an existing col metadata object or create a new one.
-        match self.changed_cols.get_mut(&col) {
-            Some(col_options) => col_options.xf_index = xf_index,
-            None => {
-                let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
-                    xf_index,
-                    hidden: false,
-                    autofit: false,
-                };
-                self.changed_cols.insert(col, col_options);
-            }
-        }
-
-        Ok(self)
+        symbols_below: bool,
+        symbols_right: bool,
+    ) -> &mut Worksheet {
+        self.outline_symbols_below = symbols_below;
+        self.outline_symbols_right = symbols_right;
+        self
     }
 
-    /// Hide a worksheet column.
+    /// Collapse the worksheet outline groups to a given level.
     ///
-    /// The `set_column_hidden()` method is used to hide a column. This can be
-    /// used, for example, to hide intermediary steps in a complicated
-    /// calculation.
+    /// This method is the equivalent of clicking on one of the numbered
+    /// outline level buttons in Excel after rows or columns have been grouped
+    /// with [`Worksheet::group_rows()`] or [`Worksheet::group_columns()`]. All
+    /// rows or columns at the given outline level, or deeper, are hidden and
+    /// the summary rows or columns of those groups are marked as collapsed.
+    ///
+    /// For example, `collapse_outline_to_level(1, 0)` hides all the grouped
+    /// rows so that only the rows outside of any group are visible, like
+    /// clicking the "1" button. A level of 0 leaves the rows or columns
+    /// unchanged.
+    ///
+    /// This method should be called after the groups have been added.
     ///
     /// # Parameters
     ///
-    /// * `col` - The zero indexed column number.
+    /// * `row_level` - The row outline level to collapse to, in the range
+    ///   0-7.
+    /// * `col_level` - The column outline level to collapse to, in the range
+    ///   0-7.
     ///
     /// # Errors
     ///
-    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
-    ///   limits.
+    /// * [`XlsxError::ParameterError`] - A level is greater than Excel's limit
+    ///   of 7 outline levels.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates hiding a worksheet column.
+    /// The following example demonstrates collapsing a multi-level row outline
+    /// so that only the top level summary rows are visible.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_column_hidden.rs
+    /// # // This code is available in examples/doc_worksheet_collapse_outline_to_level.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Hide column B.
-    ///     worksheet.set_column_hidden(1)?;
-    ///
-    ///     worksheet.write_string(0, 3, "Column B is hidden")?;
+    /// #     // Add some sample data.
+    /// #     worksheet.write_column(1, 0, ["North", "North", "North"])?;
+    /// #     worksheet.write_column(1, 1, [1000, 1200, 800])?;
+    /// #     worksheet.write(4, 0, "North Total")?;
+    /// #     worksheet.write_formula(4, 1, "=SUBTOTAL(9,B2:B4)")?;
+    /// #     worksheet.write_column(5, 0, ["South", "South"])?;
+    /// #     worksheet.write_column(5, 1, [500, 700])?;
+    /// #     worksheet.write(7, 0, "South Total")?;
+    /// #     worksheet.write_formula(7, 1, "=SUBTOTAL(9,B6:B7)")?;
+    /// #     worksheet.write(8, 0, "Grand Total")?;
+    /// #     worksheet.write_formula(8, 1, "=SUBTOTAL(9,B2:B7)")?;
+    /// #
+    ///     // Create a 2 level outline of the regions and their detail rows.
+    ///     worksheet.group_rows(1, 7, false)?;
+    ///     worksheet.group_rows(1, 3, false)?;
+    ///     worksheet.group_rows(5, 6, false)?;
+    ///
+    ///     // Collapse the outline so that only the grand total is visible.
+    ///     worksheet.collapse_outline_to_level(1, 0)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -5714,27 +7075,93 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_column_hidden.png">
-    ///
-    pub fn set_column_hidden(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
-        // Check if column is in the allowed range without updating dimensions.
-        if col >= COL_MAX {
-            return Err(XlsxError::RowColumnLimitError);
+    pub fn collapse_outline_to_level(
+        &mut self,
+        row_level: u8,
+        col_level: u8,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if row_level > MAX_OUTLINE_LEVEL || col_level > MAX_OUTLINE_LEVEL {
+            let error = format!(
+                "Outline level must be in Excel's range of 0-{MAX_OUTLINE_LEVEL}: row level = {row_level}, column level = {col_level}"
+            );
+            return Err(XlsxError::ParameterError(error));
         }
 
-        // Update an existing col metadata object or create a new one.
-        match self.changed_cols.get_mut(&col) {
-            Some(col_options) => col_options.hidden = true,
-            None => {
-                let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
-                    xf_index: 0,
-                    hidden: true,
-                    autofit: false,
+        if row_level > 0 {
+            let mut summary_rows = vec![];
+            for (row, row_options) in &mut self.changed_rows {
+                if row_options.level >= row_level {
+                    row_options.hidden = true;
+                    summary_rows.push((*row, row_options.level));
+                }
+            }
+
+            // Mark the row after, or before, the end of each hidden group as
+            // collapsed. This includes the summary rows of nested groups.
+            for (row, level) in summary_rows {
+                let summary_row = if self.outline_symbols_below {
+                    row.checked_add(1).filter(|row| *row < ROW_MAX)
+                } else {
+                    row.checked_sub(1)
                 };
-                self.changed_cols.insert(col, col_options);
+
+                let Some(summary_row) = summary_row else {
+                    continue;
+                };
+
+                let summary_level = self.changed_rows.get(&summary_row).map_or(0, |r| r.level);
+                if summary_level < level {
+                    let min_col = self.get_min_col();
+                    self.check_dimensions(summary_row, min_col);
+
+                    let row_options = self.changed_rows.entry(summary_row).or_insert(RowOptions {
+                        height: DEFAULT_ROW_HEIGHT,
+                        xf_index: 0,
+                        hidden: false,
+                        level: 0,
+                        collapsed: false,
+                    });
+
+                    row_options.collapsed = true;
+                }
+            }
+        }
+
+        if col_level > 0 {
+            let mut summary_cols = vec![];
+            for (col, col_options) in &mut self.changed_cols {
+                if col_options.level >= col_level {
+                    col_options.hidden = true;
+                    summary_cols.push((*col, col_options.level));
+                }
+            }
+
+            // Mark the column after, or before, the end of each hidden group as
+            // collapsed.
+            for (col, level) in summary_cols {
+                let summary_col = if self.outline_symbols_right {
+                    col.checked_add(1).filter(|col| *col < COL_MAX)
+                } else {
+                    col.checked_sub(1)
+                };
+
+                let Some(summary_col) = summary_col else {
+                    continue;
+                };
+
+                let summary_level = self.changed_cols.get(&summary_col).map_or(0, |c| c.level);
+                if summary_level < level {
+                    let col_options = self.changed_cols.entry(summary_col).or_insert(ColOptions {
+                        width: DEFAULT_COL_WIDTH,
+                        xf_index: 0,
+                        hidden: false,
+                        autofit: false,
+                        level: 0,
+                        collapsed: false,
+                    });
+
+                    col_options.collapsed = true;
+                }
             }
         }
 
@@ -5769,6 +7196,9 @@ impl Worksheet {
     ///   worksheet limits.
     /// * [`XlsxError::RowColumnOrderError`] - First row larger than the last
     ///   row.
+    /// * [`XlsxError::AutofilterOverlapsTable`] - The autofilter range
+    ///   overlaps a worksheet table. Tables have their own autofilter, see
+    ///   [`Table::set_autofilter()`](crate::Table::set_autofilter).
     ///
     /// # Examples
     ///
@@ -5833,6 +7263,20 @@ impl Worksheet {
             return Err(XlsxError::RowColumnOrderError);
         }
 
+        // Check that the autofilter doesn't overlap a table, which has its own
+        // autofilter. Excel doesn't allow both on the same cells.
+        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+        if let Some(table_range) = self
+            .table_ranges
+            .iter()
+            .find(|table_range| table_range.overlaps(&cell_range))
+        {
+            return Err(XlsxError::AutofilterOverlapsTable(
+                cell_range.to_error_string(),
+                table_range.to_error_string(),
+            ));
+        }
+
         // Store the defined name information.
         self.autofilter_defined_name.in_use = true;
         self.autofilter_defined_name.name_type = DefinedNameType::Autofilter;
@@ -5990,7 +7434,9 @@ impl Worksheet {
         }
 
         // Check the filter condition have been set up correctly.
-        if filter_condition.list.is_empty()
+        if filter_condition.is_top10_filter {
+            filter_condition.validate_top10()?;
+        } else if filter_condition.list.is_empty()
             && filter_condition.custom1.is_none()
             && !filter_condition.should_match_blanks
         {
@@ -6061,6 +7507,10 @@ impl Worksheet {
     ///   row.
     /// * [`XlsxError::TableError`] - A general error that is raised when a
     ///   table parameter is incorrect or a table is configured incorrectly.
+    /// * [`XlsxError::TableRangeOverlaps`] - The table overlaps a previous
+    ///   table.
+    /// * [`XlsxError::AutofilterOverlapsTable`] - The table overlaps the
+    ///   worksheet autofilter range.
     ///
     /// # Examples
     ///
@@ -6154,6 +7604,24 @@ impl Worksheet {
             return Err(XlsxError::RowColumnOrderError);
         }
 
+        // Check that the table doesn't overlap the worksheet autofilter.
+        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+        if self.autofilter_defined_name.in_use {
+            let autofilter_range = CellRange::new(
+                self.autofilter_defined_name.first_row,
+                self.autofilter_defined_name.first_col,
+                self.autofilter_defined_name.last_row,
+                self.autofilter_defined_name.last_col,
+            );
+
+            if autofilter_range.overlaps(&cell_range) {
+                return Err(XlsxError::AutofilterOverlapsTable(
+                    autofilter_range.to_error_string(),
+                    cell_range.to_error_string(),
+                ));
+            }
+        }
+
         let default_headers =
             self.default_table_headers(first_row, first_col, last_col, table.show_header_row);
 
@@ -6211,9 +7679,6 @@ impl Worksheet {
             }
         }
 
-        // Create a cell range for storage and range testing.
-        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
-
         // Check if the table range overlaps any previous table range. This is a
         // major error in Excel. Note, the ranges are stored in a separate Vec
         // to the cells to cut down on storage size.
@@ -6419,6 +7884,103 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add a data validation to one or more cells to restrict user input.
+    ///
+    /// Data validation is a feature of Excel which allows you to restrict the
+    /// data that a user enters in a cell and to display associated help and
+    /// warning messages. It also allows you to restrict input to values in a
+    /// dropdown list.
+    ///
+    /// See [`DataValidation`] for more information and examples.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first column of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last column of the range.
+    /// * `data_validation` - A [`DataValidation`] data validation instance.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// * [`XlsxError::DataValidationError`] - The data validation has an
+    ///   invalid or incomplete property, or a title or message that exceeds
+    ///   Excel's length limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a data validation dropdown
+    /// list whose values are taken from a range on another worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_data_validation_allow_list_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet for the input data and one for the lookup list.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write(1, 0, "Select value in cell C2:")?;
+    /// #
+    ///     let data_validation = DataValidation::new()
+    ///         .allow_list_range("My Lists", 0, 0, 4, 0)
+    ///         .set_input_title("Select a fruit")
+    ///         .set_input_message("Choose a value from the dropdown list.");
+    ///
+    ///     worksheet.add_data_validation(1, 2, 1, 2, &data_validation)?;
+    /// #
+    /// #     let lists = workbook.add_worksheet().set_name("My Lists")?;
+    /// #     lists.write_column(0, 0, ["Apple", "Banana", "Cherry", "Damson", "Elder"])?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("data_validation.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_data_validation(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        data_validation: &DataValidation,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Validate the data validation.
+        data_validation.validate()?;
+
+        // Store the data validation based on its range.
+        let mut cell_range = utility::cell_range(first_row, first_col, last_row, last_col);
+        if !data_validation.multi_range.is_empty() {
+            cell_range.clone_from(&data_validation.multi_range);
+        }
+
+        self.data_validations
+            .insert(cell_range, data_validation.clone());
+
+        Ok(self)
+    }
+
     /// Add a sparkline to a worksheet cell.
     ///
     /// Sparklines are a feature of Excel 2010+ which allows you to add small
@@ -7066,15 +8628,16 @@ impl Worksheet {
     /// The active cell within a selected range is determined by the order in
     /// which `first_` and `last_` are specified.
     ///
-    /// Only one range of cells can be selected. The default cell selection is
-    /// (0, 0, 0, 0), "A1".
+    /// Only one range of cells can be selected with this method, see
+    /// [`Worksheet::set_selections()`] to select multiple ranges. The default
+    /// cell selection is (0, 0, 0, 0), "A1".
     ///
     /// # Parameters
     ///
     /// * `first_row` - The first row of the range. (All zero indexed.)
-    /// * `first_col` - The first row of the range.
+    /// * `first_col` - The first column of the range.
     /// * `last_row` - The last row of the range.
-    /// * `last_col` - The last row of the range.
+    /// * `last_col` - The last column of the range.
     ///
     /// # Errors
     ///
@@ -7114,39 +8677,104 @@ impl Worksheet {
     /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_selection.png">
     pub fn set_selection(
         &mut self,
-        first_row: RowNum,
-        first_col: ColNum,
-        last_row: RowNum,
-        last_col: ColNum,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // The first/last order can be reversed to allow a selection to go from
+        // the end to the start. We take the active cell from the user first
+        // row/col and then reverse them as required for the full range.
+        let active_cell = utility::row_col_to_cell(first_row, first_col);
+        let range = Self::selection_range(first_row, first_col, last_row, last_col);
+
+        self.selected_range = (active_cell, range);
+
+        Ok(self)
+    }
+
+    /// Set multiple selected ranges of cells in a worksheet.
+    ///
+    /// The `set_selections()` method is similar to
+    /// [`Worksheet::set_selection()`] except that it allows several
+    /// non-contiguous ranges of cells to be selected, in the same way as using
+    /// Ctrl-click in Excel. This can be useful to highlight several input
+    /// regions when a file is opened.
+    ///
+    /// The active cell is taken from the first range using the same `first_`
+    /// and `last_` ordering rules as `set_selection()`.
+    ///
+    /// # Parameters
+    ///
+    /// * `ranges` - A slice of `(first_row, first_col, last_row, last_col)`
+    ///   tuples. (All zero indexed.)
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::ParameterError`] - The list of ranges is empty.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates selecting several ranges of cells in
+    /// a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_selections.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Select cells B2 to C3 and E5 to F6. The active cell is B2.
+    ///     worksheet.set_selections(&[(1, 1, 2, 2), (4, 4, 5, 5)])?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_selections(
+        &mut self,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // The first/last order can be reversed to allow a selection to go from
-        // the end to the start. We take the active cell from the user first
-        // row/col and then reverse them as required for the full range.
-        let active_cell = utility::row_col_to_cell(first_row, first_col);
+        let Some(&(first_row, first_col, _, _)) = ranges.first() else {
+            return Err(XlsxError::ParameterError(
+                "Selection ranges cannot be empty".to_string(),
+            ));
+        };
 
-        let mut first_row = first_row;
-        let mut first_col = first_col;
-        let mut last_row = last_row;
-        let mut last_col = last_col;
+        let mut sqref = vec![];
 
-        if first_row > last_row {
-            std::mem::swap(&mut first_row, &mut last_row);
-        }
+        for &(first_row, first_col, last_row, last_col) in ranges {
+            // Check rows and cols are in the allowed range.
+            if !self.check_dimensions_only(first_row, first_col)
+                || !self.check_dimensions_only(last_row, last_col)
+            {
+                return Err(XlsxError::RowColumnLimitError);
+            }
 
-        if first_col > last_col {
-            std::mem::swap(&mut first_col, &mut last_col);
+            sqref.push(Self::selection_range(
+                first_row, first_col, last_row, last_col,
+            ));
         }
 
-        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+        // The active cell is in the first range.
+        let active_cell = utility::row_col_to_cell(first_row, first_col);
 
-        self.selected_range = (active_cell, range);
+        self.selected_range = (active_cell, sqref.join(" "));
 
         Ok(self)
     }
@@ -7156,6 +8784,14 @@ impl Worksheet {
     /// This `set_top_left_cell()` method can be used to set the top leftmost
     /// visible cell in the worksheet.
     ///
+    /// If the worksheet has frozen panes, see
+    /// [`Worksheet::set_freeze_panes()`], the cell is the top leftmost cell of
+    /// the scrollable region and the frozen rows and columns remain visible.
+    /// For example, with the first 2 rows frozen, a top-left cell of `C20`
+    /// displays the header rows above row 20 scrolled to column C. An explicit
+    /// [`Worksheet::set_freeze_panes_top_cell()`] takes precedence for the
+    /// scrollable region.
+    ///
     /// # Parameters
     ///
     /// * `row` - The zero indexed row number.
@@ -7209,12 +8845,7 @@ impl Worksheet {
             return Err(XlsxError::RowColumnLimitError);
         }
 
-        // Ignore cell (0, 0) since that is the default top-left cell.
-        if row == 0 && col == 0 {
-            return Ok(self);
-        }
-
-        self.top_left_cell = utility::row_col_to_cell(row, col);
+        self.top_left_cell = (row, col);
 
         Ok(self)
     }
@@ -7289,13 +8920,16 @@ impl Worksheet {
                 match cell {
                     CellType::Formula {
                         result: cell_result,
+                        has_result,
                         ..
                     }
                     | CellType::ArrayFormula {
                         result: cell_result,
+                        has_result,
                         ..
                     } => {
                         *cell_result = Box::from(result.into());
+                        *has_result = true;
                     }
                     _ => {
                         eprintln!("Cell ({row}, {col}) doesn't contain a formula.");
@@ -7320,6 +8954,10 @@ impl Worksheet {
     /// empty string literal `""`, via the `set_formula_result_default()`
     /// method, to force calculation of the result.
     ///
+    /// The default applies to all the formulas in the worksheet that don't
+    /// have an explicit result, including formulas that were written before
+    /// the default was set.
+    ///
     /// # Parameters
     ///
     /// * `result` - The default formula result to write to the cell.
@@ -7351,6 +8989,24 @@ impl Worksheet {
     ///
     pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Worksheet {
         self.default_result = Box::from(result.into());
+
+        // Update any formulas that have already been written.
+        for columns in self.data_table.values_mut() {
+            for cell in columns.values_mut() {
+                match cell {
+                    CellType::Formula {
+                        result, has_result, ..
+                    }
+                    | CellType::ArrayFormula {
+                        result, has_result, ..
+                    } if !*has_result => {
+                        result.clone_from(&self.default_result);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         self
     }
 
@@ -7424,6 +9080,88 @@ impl Worksheet {
         self.use_future_functions = enable;
     }
 
+    /// Create a local/worksheet defined name to represent a value, a single
+    /// cell or a range of cells in the worksheet.
+    ///
+    /// This is the worksheet scoped equivalent of
+    /// [`Workbook::define_name()`](crate::Workbook::define_name). The name is
+    /// only visible in formulas on this worksheet and it overrides any
+    /// Global/Workbook name with the same name. Unlike the
+    /// `"Sheet1!defined_name"` syntax of `define_name()` the worksheet name
+    /// doesn't need to be quoted since it is added automatically when the
+    /// file is saved.
+    ///
+    /// See [`Workbook::define_name()`](crate::Workbook::define_name) for the
+    /// Excel rules on names.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The variable name to define.
+    /// * `formula` - The formula, value or range that the name defines.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ParameterError`] - The name is invalid in Excel, see
+    ///   [`Workbook::define_name()`](crate::Workbook::define_name), it has
+    ///   already been defined in this worksheet, or the formula is blank.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates defining a local/worksheet name and
+    /// using it in a formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_define_local_name.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet().set_name("Sales Data")?;
+    /// #
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///
+    ///     // Define a local name for the data range and use it in a formula.
+    ///     worksheet.define_local_name("Sales", "='Sales Data'!$A$1:$A$3")?;
+    ///     worksheet.write_formula(3, 0, "=SUM(Sales)")?;
+    /// #
+    /// #     workbook.save("defined_name.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn define_local_name(
+        &mut self,
+        name: impl Into<String>,
+        formula: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let name = name.into();
+
+        // Check that the name is valid in Excel.
+        DefinedName::validate_name(&name)?;
+
+        // Check that the name isn't a duplicate in the worksheet scope.
+        if self
+            .local_defined_names
+            .iter()
+            .any(|existing| existing.name.to_lowercase() == name.to_lowercase())
+        {
+            let error = format!("Defined name '{name}' has already been used in this worksheet");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut defined_name = DefinedName::new();
+        defined_name.range = DefinedName::validate_formula(&name, formula)?;
+        defined_name.name = name;
+        defined_name.name_type = DefinedNameType::Local;
+        defined_name.set_sort_name();
+
+        self.local_defined_names.push(defined_name);
+
+        Ok(self)
+    }
+
     // -----------------------------------------------------------------------
     // Worksheet serde methods.
     // -----------------------------------------------------------------------
@@ -8676,6 +10414,174 @@ impl Worksheet {
             .get_column_dimensions(struct_name, field_name)
     }
 
+    /// Deserialize a range of worksheet cells to a vector of structs.
+    ///
+    /// The `deserialize()` method reads back data that has been written to a
+    /// worksheet, for example by [`Worksheet::serialize()`], and maps it to a
+    /// vector of Serde derived structs. The first row of the range is used as
+    /// the header row and the header names are matched to the struct field
+    /// names. Each subsequent row is deserialized to a struct instance.
+    ///
+    /// If the struct was previously serialized with renamed custom headers,
+    /// see [`CustomSerializeField::rename()`], then the custom header names are
+    /// mapped back to the original field names. Columns with headers that
+    /// don't match a field are ignored.
+    ///
+    /// This method only reads data from the in-memory worksheet. It doesn't
+    /// read or parse Excel files. Formula cells are read as their explicit
+    /// result, see [`Formula::set_result()`], or as empty cells if the result
+    /// hasn't been set.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the range, which contains the headers.
+    ///   (All zero indexed.)
+    /// * `first_col` - The first column of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// * [`XlsxError::SerdeError`] - A wrapped deserialization error, for
+    ///   example if a cell value doesn't match the type of the struct field,
+    ///   or if a cell contains an Excel error value such as `#VALUE!`.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing some data to a worksheet
+    /// and then deserializing it back to a vector of structs.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_deserialize.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: String,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let items = vec![
+    ///         Produce {
+    ///             fruit: "Peach".to_string(),
+    ///             cost: 1.05,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum".to_string(),
+    ///             cost: 0.15,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Pear".to_string(),
+    ///             cost: 0.75,
+    ///         },
+    ///     ];
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers::<Produce>(0, 0)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
+    ///
+    ///     // Get the range of the serialized data, including the headers.
+    ///     let (first_row, first_col, last_row, last_col) =
+    ///         worksheet.get_serialize_dimensions("Produce")?;
+    ///
+    ///     // Read the data back from the worksheet.
+    ///     let data: Vec<Produce> =
+    ///         worksheet.deserialize(first_row, first_col, last_row, last_col)?;
+    ///
+    ///     assert_eq!(data, items);
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn deserialize<T>(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<Vec<T>, XlsxError>
+    where
+        T: DeserializeOwned,
+    {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Map any custom header names from a previous serialization of the
+        // same struct back to the field names.
+        let headers = deserialize_headers::<T>();
+        let mut field_names: HashMap<String, String> = HashMap::new();
+        if let Some(header_config) = self.serializer_state.structs.get(&headers.struct_name) {
+            for field in header_config.fields.values() {
+                field_names.insert(field.header_name.clone(), field.field_name.clone());
+            }
+        }
+
+        // Read the field names from the header row, ignoring empty headers.
+        let mut columns: Vec<(ColNum, String)> = vec![];
+        for col in first_col..=last_col {
+            let header_name = match self.get_deserializer_cell(first_row, col)? {
+                DeserializerCell::String(string) => string,
+                DeserializerCell::Number(number) => number.to_string(),
+                DeserializerCell::Boolean(boolean) => boolean.to_string().to_uppercase(),
+                DeserializerCell::Empty => continue,
+            };
+
+            let field_name = match field_names.get(&header_name) {
+                Some(field_name) => field_name.clone(),
+                None => header_name,
+            };
+
+            columns.push((col, field_name));
+        }
+
+        // Deserialize each data row to a struct instance.
+        let mut data = vec![];
+        for row in first_row + 1..=last_row {
+            let cells = columns
+                .iter()
+                .map(|(col, field_name)| {
+                    Ok((field_name.clone(), self.get_deserializer_cell(row, *col)?))
+                })
+                .collect::<Result<_, XlsxError>>()?;
+
+            data.push(T::deserialize(DeserializerRow { cells })?);
+        }
+
+        Ok(data)
+    }
+
     // Store serialization headers and options.
     #[cfg(feature = "serde")]
     fn store_serialization_headers_with_options(
@@ -8880,6 +10786,59 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Get a cell value in a form that can be deserialized. Formula cells
+    // return their explicit result, if any. Error cells can't be deserialized.
+    #[cfg(feature = "serde")]
+    fn get_deserializer_cell(
+        &self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<DeserializerCell, XlsxError> {
+        let Some(cell) = self
+            .data_table
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+        else {
+            return Ok(DeserializerCell::Empty);
+        };
+
+        let cell = match cell {
+            CellType::Boolean { boolean, .. } => DeserializerCell::Boolean(*boolean),
+            CellType::Number { number, .. } | CellType::DateTime { number, .. } => {
+                DeserializerCell::Number(*number)
+            }
+            CellType::String { string, .. }
+            | CellType::RichString {
+                raw_string: string, ..
+            } => DeserializerCell::String(string.to_string()),
+            CellType::Formula {
+                result, has_result, ..
+            }
+            | CellType::ArrayFormula {
+                result, has_result, ..
+            } => {
+                if !*has_result || result.is_empty() {
+                    DeserializerCell::Empty
+                } else if let Ok(number) = result.parse::<f64>() {
+                    DeserializerCell::Number(number)
+                } else if &**result == "TRUE" || &**result == "FALSE" {
+                    DeserializerCell::Boolean(&**result == "TRUE")
+                } else {
+                    DeserializerCell::String(result.to_string())
+                }
+            }
+            CellType::Blank { .. } => DeserializerCell::Empty,
+            CellType::Error { .. } => {
+                let cell = utility::row_col_to_cell(row, col);
+                return Err(XlsxError::SerdeError(format!(
+                    "cell '{cell}' contains an Excel error value that can't be deserialized"
+                )));
+            }
+        };
+
+        Ok(cell)
+    }
+
     // Serialize the parent data structure to the worksheet.
     #[cfg(feature = "serde")]
     fn serialize_data_structure<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
@@ -9640,24 +11599,78 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Set the worksheet zoom factor.
-    ///
-    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
+    /// Set the worksheet zoom factor.
+    ///
+    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
+    ///
+    /// The default zoom level is 100. The `set_zoom()` method does not affect
+    /// the scale of the printed page in Excel. For that you should use
+    /// [`set_print_scale()`](Worksheet::set_print_scale).
+    ///
+    /// # Parameters
+    ///
+    /// * `zoom` - The worksheet zoom level.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the worksheet zoom level.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///     worksheet.set_zoom(200);
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
+    ///
+    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
+        if !(10..=400).contains(&zoom) {
+            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
+
+        self.zoom = zoom;
+        self
+    }
+
+    /// Set the worksheet zoom factor for the page break preview view.
     ///
-    /// The default zoom level is 100. The `set_zoom()` method does not affect
-    /// the scale of the printed page in Excel. For that you should use
-    /// [`set_print_scale()`](Worksheet::set_print_scale).
+    /// Excel stores a separate zoom level for the page break preview view. The
+    /// [`set_zoom()`](Worksheet::set_zoom) method sets the zoom of the current
+    /// view, see [`set_view_page_break_preview()`](Worksheet::set_view_page_break_preview).
+    /// This method sets the zoom level that Excel uses when the user switches
+    /// to page break preview. If it is set then it takes precedence over
+    /// `set_zoom()` for the page break preview view.
     ///
     /// # Parameters
     ///
-    /// * `zoom` - The worksheet zoom level.
+    /// * `zoom` - The page break preview zoom level in the range 10 <= zoom
+    ///   <= 400.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet zoom level.
+    /// The following example demonstrates setting a separate zoom level for the
+    /// page break preview view.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
+    /// # // This code is available in examples/doc_worksheet_set_page_break_preview_zoom.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -9668,7 +11681,8 @@ impl Worksheet {
     /// #     let worksheet = workbook.add_worksheet();
     /// #
     ///     worksheet.write_string(0, 0, "Hello")?;
-    ///     worksheet.set_zoom(200);
+    ///     worksheet.set_zoom(150);
+    ///     worksheet.set_page_break_preview_zoom(80);
     ///
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -9676,18 +11690,13 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
-    ///
-    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
+    pub fn set_page_break_preview_zoom(&mut self, zoom: u16) -> &mut Worksheet {
         if !(10..=400).contains(&zoom) {
             eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
             return self;
         }
 
-        self.zoom = zoom;
+        self.page_break_preview_zoom = Some(zoom);
         self
     }
 
@@ -9702,8 +11711,8 @@ impl Worksheet {
     /// Also, you can set one of the row and col parameters as 0 if you do not
     /// want either the vertical or horizontal split. See the example below.
     ///
-    /// In Excel it is also possible to set "split" panes without freezing them.
-    /// That feature isn't currently supported by `rust_xlsxwriter`.
+    /// In Excel it is also possible to set "split" panes without freezing them,
+    /// see [`Worksheet::set_split_panes()`].
     ///
     /// # Parameters
     ///
@@ -9769,9 +11778,68 @@ impl Worksheet {
         }
 
         self.panes.freeze_cell = (row, col);
+        self.panes.split_position = (0.0, 0.0);
         Ok(self)
     }
 
+    /// Split panes in a worksheet.
+    ///
+    /// The `set_split_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes. Unlike
+    /// [`Worksheet::set_freeze_panes()`] the panes aren't frozen, so all of
+    /// the regions can be scrolled and the splitter bars can be dragged by the
+    /// user.
+    ///
+    /// The split positions are given in the same units as the row height and
+    /// column width, see [`Worksheet::set_row_height()`] and
+    /// [`Worksheet::set_column_width()`]. So to split the worksheet below the
+    /// first row and to the right of the first column, with the default row
+    /// height and column width, you would use `(15.0, 8.43)`. You can set one
+    /// of the parameters to 0.0 if you do not want either the vertical or
+    /// horizontal split.
+    ///
+    /// The top leftmost visible cell in the bottom right pane is estimated
+    /// from the split position. It can be set explicitly with
+    /// [`Worksheet::set_freeze_panes_top_cell()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `y` - The vertical position of the horizontal split bar, in row
+    ///   height units.
+    /// * `x` - The horizontal position of the vertical split bar, in column
+    ///   width units.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting split worksheet panes.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_split_panes.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write_string(0, 0, "Scroll in any pane")?;
+    /// #
+    ///     // Split the worksheet below the first row and to the right of the
+    ///     // first column.
+    ///     worksheet.set_split_panes(15.0, 8.43);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_split_panes(&mut self, y: f64, x: f64) -> &mut Worksheet {
+        self.panes.split_position = (y.max(0.0), x.max(0.0));
+        self.panes.freeze_cell = (0, 0);
+        self
+    }
+
     /// Set the top most cell in the scrolling area of a freeze pane.
     ///
     /// This method is used in conjunction with the
@@ -9838,6 +11906,81 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Freeze the top row of a worksheet.
+    ///
+    /// The `freeze_top_row()` method is a convenience method for the most
+    /// common type of freeze pane where the header row stays visible when the
+    /// worksheet is scrolled down. It is the equivalent of
+    /// `set_freeze_panes(1, 0)`, see
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates freezing the top row and/or the
+    /// first column of worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_freeze_top_row.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Freeze the top row only.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Scroll down")?;
+    ///     worksheet.freeze_top_row();
+    ///
+    ///     // Freeze the first column only.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Scroll across")?;
+    ///     worksheet.freeze_first_column();
+    ///
+    ///     // Freeze the top row and first column.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Scroll down or across")?;
+    ///     worksheet.freeze_top_row_and_first_column();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn freeze_top_row(&mut self) -> &mut Worksheet {
+        // The cell is always in range so the call can't fail.
+        self.set_freeze_panes(1, 0).unwrap()
+    }
+
+    /// Freeze the first column of a worksheet.
+    ///
+    /// The `freeze_first_column()` method is a convenience method to keep the
+    /// first column visible when the worksheet is scrolled across. It is the
+    /// equivalent of `set_freeze_panes(0, 1)`, see
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details.
+    ///
+    /// See the example in [`freeze_top_row()`](Worksheet::freeze_top_row).
+    ///
+    pub fn freeze_first_column(&mut self) -> &mut Worksheet {
+        // The cell is always in range so the call can't fail.
+        self.set_freeze_panes(0, 1).unwrap()
+    }
+
+    /// Freeze the top row and the first column of a worksheet.
+    ///
+    /// The `freeze_top_row_and_first_column()` method is a convenience method
+    /// to keep the top row and the first column visible when the worksheet is
+    /// scrolled. It is the equivalent of `set_freeze_panes(1, 1)`, see
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details.
+    ///
+    /// See the example in [`freeze_top_row()`](Worksheet::freeze_top_row).
+    ///
+    pub fn freeze_top_row_and_first_column(&mut self) -> &mut Worksheet {
+        // The cell is always in range so the call can't fail.
+        self.set_freeze_panes(1, 1).unwrap()
+    }
+
     /// Set the printed page header caption.
     ///
     /// The `set_header()` method can be used to set the header for a worksheet.
@@ -10553,6 +12696,45 @@ impl Worksheet {
         self
     }
 
+    /// Fit the printed area to one page.
+    ///
+    /// The `fit_to_one_page()` method is a shortcut for the common requirement
+    /// of printing a worksheet on exactly one page. It is the same as calling
+    /// [`Worksheet::set_print_fit_to_pages()`] with a width and height of 1.
+    /// If the printed area exceeds one page it will be scaled down to fit.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the worksheet to print on
+    /// one page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_fit_to_one_page.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the printed output to fit on 1 page.
+    ///     worksheet.fit_to_one_page();
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn fit_to_one_page(&mut self) -> &mut Worksheet {
+        self.set_print_fit_to_pages(1, 1)
+    }
+
     /// Center the printed page horizontally.
     ///
     /// Center the worksheet data horizontally between the margins on the
@@ -10711,6 +12893,25 @@ impl Worksheet {
         self
     }
 
+    /// Set the page setup option to print notes and threaded comments.
+    ///
+    /// Notes and threaded comments aren't printed by default. This method can
+    /// be used to print them at the end of the sheet or, for notes, where they
+    /// are displayed on the sheet. See [`NotePrintPosition`] for the options.
+    ///
+    /// # Parameters
+    ///
+    /// * `position` - A [`NotePrintPosition`] enum value.
+    ///
+    pub fn set_print_notes(&mut self, position: NotePrintPosition) -> &mut Worksheet {
+        self.print_notes = position;
+
+        if position != NotePrintPosition::None {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
     /// Set the page setup option to print the row and column headers on the
     /// printed page.
     ///
@@ -10749,6 +12950,10 @@ impl Worksheet {
     /// In these examples 16,383 is the maximum column and 1,048,575 is the
     /// maximum row (zero indexed).
     ///
+    /// Calling `set_print_area()` replaces any existing print area. To set a
+    /// print area made up of several ranges see
+    /// [`Worksheet::add_print_area()`].
+    ///
     /// See also the example below and the documentation on
     /// [Worksheet Page Setup - Sheet](../worksheet/index.html#page-setup---sheet).
     ///
@@ -10834,11 +13039,163 @@ impl Worksheet {
         self.print_area_defined_name.first_col = first_col;
         self.print_area_defined_name.last_row = last_row;
         self.print_area_defined_name.last_col = last_col;
+        self.print_area_defined_name.additional_areas.clear();
 
         self.page_setup_changed = true;
         Ok(self)
     }
 
+    /// Add an additional print area to a worksheet.
+    ///
+    /// Excel allows a print area to be made up of several, non-contiguous,
+    /// ranges. Each range is printed on a separate page. The
+    /// `add_print_area()` method adds a range to the print area set by
+    /// [`Worksheet::set_print_area()`]. If no print area has been set then it
+    /// is the equivalent of `set_print_area()`. As with `set_print_area()` a
+    /// range that covers the entire worksheet is ignored.
+    ///
+    /// Excel stores the ranges in the `Print_Area` defined name as a comma
+    /// separated list like `Sheet1!$A$1:$C$10,Sheet1!$E$1:$G$10`.
+    ///
+    /// # Parameters
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a print area made up of two
+    /// ranges.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_add_print_area.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Set the print area to "A1:C10,E1:G10".
+    ///     worksheet.set_print_area(0, 0, 9, 2)?;
+    ///     worksheet.add_print_area(0, 4, 9, 6)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_print_area(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if !self.print_area_defined_name.in_use {
+            return self.set_print_area(first_row, first_col, last_row, last_col);
+        }
+
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // A print range of the entire worksheet is ignored, in the same way as
+        // set_print_area().
+        if first_row == 0 && first_col == 0 && last_row == ROW_MAX - 1 && last_col == COL_MAX - 1 {
+            return Ok(self);
+        }
+
+        self.print_area_defined_name
+            .additional_areas
+            .push((first_row, first_col, last_row, last_col));
+
+        Ok(self)
+    }
+
+    /// Get the print area of a worksheet.
+    ///
+    /// The `print_area()` method returns the print area set with
+    /// [`Worksheet::set_print_area()`] and
+    /// [`Worksheet::add_print_area()`] as an absolute range like
+    /// `$A$1:$M$32`. Multiple ranges are returned as a comma separated list
+    /// like `$A$1:$C$10,$E$1:$G$10`, in the same way as Excel. Print areas
+    /// for complete rows or columns are returned as `$1:$10` or `$A:$C`.
+    ///
+    /// Returns `None` if a print area hasn't been set.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting and clearing the print area
+    /// of a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_print_area.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.set_print_area(0, 0, 9, 2)?;
+    ///     assert_eq!(worksheet.print_area(), Some("$A$1:$C$10".to_string()));
+    ///
+    ///     worksheet.clear_print_area();
+    ///     assert_eq!(worksheet.print_area(), None);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn print_area(&self) -> Option<String> {
+        if !self.print_area_defined_name.in_use {
+            return None;
+        }
+
+        let mut defined_name = self.print_area_defined_name.clone();
+        defined_name.initialize("");
+
+        Some(defined_name.range)
+    }
+
+    /// Clear the print area of a worksheet.
+    ///
+    /// The `clear_print_area()` method removes a print area set with
+    /// [`Worksheet::set_print_area()`] or [`Worksheet::add_print_area()`]. The
+    /// `_xlnm.Print_Area` defined name for the worksheet is no longer written
+    /// to the file and the whole worksheet is printed, which is the Excel
+    /// default.
+    ///
+    /// See the example in [`Worksheet::print_area()`].
+    ///
+    pub fn clear_print_area(&mut self) -> &mut Worksheet {
+        self.print_area_defined_name = DefinedName::new();
+        self
+    }
+
     /// Set the number of rows to repeat at the top of each printed page.
     ///
     /// For large Excel documents it is often desirable to have the first row or
@@ -10922,13 +13279,18 @@ impl Worksheet {
     /// or columns of the worksheet print out at the left hand side of each
     /// page.
     ///
+    /// This can be combined with
+    /// [`Worksheet::set_repeat_rows()`](Worksheet::set_repeat_rows) in which
+    /// case both ranges are stored in the worksheet `Print_Titles` defined
+    /// name, as Excel does.
+    ///
     /// See the example below and the documentation on [Worksheet Page Setup -
     /// Sheet](../worksheet/index.html#page-setup---sheet).
     ///
     /// # Parameters
     ///
-    /// * `first_col` - The first row of the range. (Zero indexed.)
-    /// * `last_col` - The last row of the range.
+    /// * `first_col` - The first column of the range. (Zero indexed.)
+    /// * `last_col` - The last column of the range.
     ///
     /// # Errors
     ///
@@ -11026,7 +13388,9 @@ impl Worksheet {
     /// The `autofit()` method ignores columns that have already been explicitly
     /// set if the width is greater than the calculated autofit width.
     /// Alternatively, setting the column width explicitly after calling
-    /// `autofit()` will override the autofit value.
+    /// `autofit()` will override the autofit value. Columns can also be
+    /// excluded from `autofit()` using
+    /// [`set_column_autofit_disabled()`](Worksheet::set_column_autofit_disabled()).
     ///
     /// **Note 1**: As a performance optimization when dealing with large data
     /// sets you can call `autofit()` after writing the first 50 or 100 rows.
@@ -11083,6 +13447,11 @@ impl Worksheet {
         for row_num in self.dimensions.first_row..=self.dimensions.last_row {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
+                    // Skip columns that have been excluded from autofit.
+                    if self.autofit_disabled_cols.contains(&col_num) {
+                        continue;
+                    }
+
                     if let Some(cell) = columns.get(&col_num) {
                         let mut pixel_width = match cell {
                             // For strings we do a calculation based on
@@ -11214,8 +13583,20 @@ impl Worksheet {
         for col_num in self.filter_conditions.clone().keys() {
             // Iterate through each column filter conditions.
             let filter_condition = self.filter_conditions.get(col_num).unwrap().clone();
+            let top10_threshold = self.top10_filter_threshold(*col_num, &filter_condition);
+
             for row_num in first_row..=last_row {
-                if filter_condition.is_list_filter {
+                if filter_condition.is_top10_filter {
+                    // Handle "Top 10" filters.
+                    if !self.row_matches_top10_filter(
+                        row_num,
+                        *col_num,
+                        &filter_condition,
+                        top10_threshold,
+                    ) {
+                        self.set_row_hidden(row_num).unwrap();
+                    }
+                } else if filter_condition.is_list_filter {
                     // Handle list filters.
                     if !self.row_matches_list_filter(row_num, *col_num, &filter_condition) {
                         self.set_row_hidden(row_num).unwrap();
@@ -11420,6 +13801,77 @@ impl Worksheet {
         false
     }
 
+    // Get the boundary value for a "Top 10" filter. This is the smallest number
+    // that is included in the top n items (or the largest for a bottom n
+    // filter). Excel only applies "Top 10" filters to numbers.
+    fn top10_filter_threshold(
+        &self,
+        col_num: ColNum,
+        filter_condition: &FilterCondition,
+    ) -> Option<f64> {
+        if !filter_condition.is_top10_filter || !self.autofilter_defined_name.in_use {
+            return None;
+        }
+
+        let first_row = self.autofilter_defined_name.first_row + 1; // Skip header.
+        let last_row = self.autofilter_defined_name.last_row;
+
+        let mut numbers = vec![];
+        for row_num in first_row..=last_row {
+            if let Some(columns) = self.data_table.get(&row_num) {
+                if let Some(CellType::Number { number, .. }) = columns.get(&col_num) {
+                    numbers.push(*number);
+                }
+            }
+        }
+
+        if numbers.is_empty() {
+            return None;
+        }
+
+        if filter_condition.top10_is_bottom {
+            numbers.sort_by(f64::total_cmp);
+        } else {
+            numbers.sort_by(|a, b| b.total_cmp(a));
+        }
+
+        let count = if filter_condition.top10_is_percent {
+            numbers.len() * filter_condition.top10_value as usize / 100
+        } else {
+            filter_condition.top10_value as usize
+        };
+
+        let index = count.clamp(1, numbers.len()) - 1;
+
+        Some(numbers[index])
+    }
+
+    // Check if the data in a cell is one of the top (or bottom) values in a
+    // "Top 10" filter.
+    fn row_matches_top10_filter(
+        &self,
+        row_num: RowNum,
+        col_num: ColNum,
+        filter_condition: &FilterCondition,
+        threshold: Option<f64>,
+    ) -> bool {
+        let Some(threshold) = threshold else {
+            return false;
+        };
+
+        if let Some(columns) = self.data_table.get(&row_num) {
+            if let Some(CellType::Number { number, .. }) = columns.get(&col_num) {
+                if filter_condition.top10_is_bottom {
+                    return *number <= threshold;
+                }
+
+                return *number >= threshold;
+            }
+        }
+
+        false
+    }
+
     // Process pagebreaks to sort them, remove duplicates and check the number
     // is within the Excel limit.
     pub(crate) fn process_pagebreaks(breaks: &[u32]) -> Result<Vec<u32>, XlsxError> {
@@ -11444,6 +13896,173 @@ impl Worksheet {
         Ok(breaks)
     }
 
+    // Store a row outline group. If the level is `None` the existing level of
+    // the rows is incremented to create a nested group.
+    fn store_row_group(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+        level: Option<u8>,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order first.
+        if first_row > last_row {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Check rows are in the allowed range.
+        let min_col = self.get_min_col();
+        if !self.check_dimensions(first_row, min_col) || !self.check_dimensions(last_row, min_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the group doesn't exceed the Excel outline level limit.
+        if let Some(level) = level {
+            if level == 0 || level > MAX_OUTLINE_LEVEL {
+                let error = format!(
+                    "Row outline level '{level}' must be in Excel's range of 1-{MAX_OUTLINE_LEVEL}"
+                );
+                return Err(XlsxError::ParameterError(error));
+            }
+        } else {
+            for row in first_row..=last_row {
+                if let Some(row_options) = self.changed_rows.get(&row) {
+                    if row_options.level >= MAX_OUTLINE_LEVEL {
+                        let error = format!(
+                            "Row group would exceed Excel's limit of {MAX_OUTLINE_LEVEL} outline levels"
+                        );
+                        return Err(XlsxError::ParameterError(error));
+                    }
+                }
+            }
+        }
+
+        for row in first_row..=last_row {
+            let row_options = self.changed_rows.entry(row).or_insert(RowOptions {
+                height: DEFAULT_ROW_HEIGHT,
+                xf_index: 0,
+                hidden: false,
+                level: 0,
+                collapsed: false,
+            });
+
+            match level {
+                Some(level) => row_options.level = level,
+                None => row_options.level += 1,
+            }
+
+            if collapsed {
+                row_options.hidden = true;
+            }
+        }
+
+        // Mark the summary row before or after the group as collapsed.
+        let summary_row = if self.outline_symbols_below {
+            last_row.checked_add(1).filter(|row| *row < ROW_MAX)
+        } else {
+            first_row.checked_sub(1)
+        };
+
+        if let (true, Some(summary_row)) = (collapsed, summary_row) {
+            self.check_dimensions(summary_row, min_col);
+
+            let row_options = self.changed_rows.entry(summary_row).or_insert(RowOptions {
+                height: DEFAULT_ROW_HEIGHT,
+                xf_index: 0,
+                hidden: false,
+                level: 0,
+                collapsed: false,
+            });
+
+            row_options.collapsed = true;
+        }
+
+        Ok(self)
+    }
+
+    // Store a column outline group. If the level is `None` the existing level
+    // of the columns is incremented to create a nested group.
+    fn store_column_group(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        level: Option<u8>,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order first.
+        if first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Check if columns are in the allowed range without updating dimensions.
+        if last_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the group doesn't exceed the Excel outline level limit.
+        if let Some(level) = level {
+            if level == 0 || level > MAX_OUTLINE_LEVEL {
+                let error = format!(
+                    "Column outline level '{level}' must be in Excel's range of 1-{MAX_OUTLINE_LEVEL}"
+                );
+                return Err(XlsxError::ParameterError(error));
+            }
+        } else {
+            for col in first_col..=last_col {
+                if let Some(col_options) = self.changed_cols.get(&col) {
+                    if col_options.level >= MAX_OUTLINE_LEVEL {
+                        let error = format!(
+                            "Column group would exceed Excel's limit of {MAX_OUTLINE_LEVEL} outline levels"
+                        );
+                        return Err(XlsxError::ParameterError(error));
+                    }
+                }
+            }
+        }
+
+        for col in first_col..=last_col {
+            let col_options = self.changed_cols.entry(col).or_insert(ColOptions {
+                width: DEFAULT_COL_WIDTH,
+                xf_index: 0,
+                hidden: false,
+                autofit: false,
+                level: 0,
+                collapsed: false,
+            });
+
+            match level {
+                Some(level) => col_options.level = level,
+                None => col_options.level += 1,
+            }
+
+            if collapsed {
+                col_options.hidden = true;
+            }
+        }
+
+        // Mark the summary column before or after the group as collapsed.
+        let summary_col = if self.outline_symbols_right {
+            last_col.checked_add(1).filter(|col| *col < COL_MAX)
+        } else {
+            first_col.checked_sub(1)
+        };
+
+        if let (true, Some(summary_col)) = (collapsed, summary_col) {
+            let col_options = self.changed_cols.entry(summary_col).or_insert(ColOptions {
+                width: DEFAULT_COL_WIDTH,
+                xf_index: 0,
+                hidden: false,
+                autofit: false,
+                level: 0,
+                collapsed: false,
+            });
+
+            col_options.collapsed = true;
+        }
+
+        Ok(self)
+    }
+
     // Store a number cell in the worksheet data table structure.
     fn store_number(
         &mut self,
@@ -11452,7 +14071,7 @@ impl Worksheet {
         number: impl Into<f64>,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
-        self.store_number_type(row, col, number.into(), format, false)
+        self.store_number_type(row, col, number.into(), CellXf::from(format), false)
     }
 
     // Store a datetime cell in the worksheet data table structure.
@@ -11463,7 +14082,7 @@ impl Worksheet {
         number: f64,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
-        self.store_number_type(row, col, number, format, true)
+        self.store_number_type(row, col, number, CellXf::from(format), true)
     }
 
     // Store a number/datetime cell in the worksheet data table structure.
@@ -11472,7 +14091,7 @@ impl Worksheet {
         row: RowNum,
         col: ColNum,
         number: f64,
-        format: Option<&Format>,
+        cell_xf: CellXf,
         is_datetime: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
@@ -11491,10 +14110,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => 0,
-        };
+        let xf_index = self.cell_xf_index(cell_xf);
 
         // Create the appropriate cell type to hold the data.
         let cell = if is_datetime {
@@ -11515,13 +14131,24 @@ impl Worksheet {
         col: ColNum,
         string: String,
         format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_string_type(row, col, string, CellXf::from(format))
+    }
+
+    // Store a string cell with a format or xf index.
+    fn store_string_type(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string: String,
+        cell_xf: CellXf,
     ) -> Result<&mut Worksheet, XlsxError> {
         // Empty strings are ignored by Excel unless they have a format in which
         // case they are treated as a blank cell.
         if string.is_empty() {
-            match format {
-                Some(format) => return self.write_blank(row, col, format),
-                None => return Ok(self),
+            match cell_xf {
+                CellXf::None => return Ok(self),
+                _ => return self.store_blank_type(row, col, cell_xf),
             };
         }
 
@@ -11536,10 +14163,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => 0,
-        };
+        let xf_index = self.cell_xf_index(cell_xf);
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::String {
@@ -11577,8 +14201,9 @@ impl Worksheet {
             return Err(XlsxError::RowColumnLimitError);
         }
 
-        //  Check that the string is < Excel limit of 32767 chars.
-        if string.chars().count() > MAX_STRING_LEN {
+        // Check that the unformatted string is < Excel limit of 32767 chars.
+        // The formatted string also contains the font xml so it isn't used.
+        if raw_string.chars().count() > MAX_STRING_LEN {
             return Err(XlsxError::MaxStringLengthExceeded);
         }
 
@@ -11627,10 +14252,11 @@ impl Worksheet {
         };
 
         // Set the formula result to the default or user defined
-        let result = if formula.result.is_empty() {
-            self.default_result.clone()
-        } else {
+        let has_result = !formula.result.is_empty();
+        let result = if has_result {
             formula.result.clone()
+        } else {
+            self.default_result.clone()
         };
 
         // Create the appropriate cell type to hold the data.
@@ -11638,6 +14264,7 @@ impl Worksheet {
             formula: formula.expand_formula(self.use_future_functions),
             xf_index,
             result,
+            has_result,
         };
 
         self.insert_cell(row, col, cell);
@@ -11689,10 +14316,11 @@ impl Worksheet {
         }
 
         // Set the formula result to the default or user defined
-        let result = if formula.result.is_empty() {
-            self.default_result.clone()
-        } else {
+        let has_result = !formula.result.is_empty();
+        let result = if has_result {
             formula.result.clone()
+        } else {
+            self.default_result.clone()
         };
 
         // Create the appropriate cell type to hold the data.
@@ -11700,6 +14328,7 @@ impl Worksheet {
             formula: formula.expand_formula(self.use_future_functions),
             xf_index,
             result,
+            has_result,
             is_dynamic,
             range: range.into_boxed_str(),
         };
@@ -11727,6 +14356,16 @@ impl Worksheet {
         row: RowNum,
         col: ColNum,
         format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_blank_type(row, col, CellXf::Format(format))
+    }
+
+    // Store a blank cell with a format or xf index.
+    fn store_blank_type(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        cell_xf: CellXf,
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
@@ -11734,7 +14373,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object.
-        let xf_index = self.format_xf_index(format);
+        let xf_index = self.cell_xf_index(cell_xf);
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::Blank { xf_index };
@@ -11744,6 +14383,24 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Store a #VALUE! error cell in the worksheet data table structure.
+    fn store_value_error(&mut self, row: RowNum, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Create the appropriate cell type to hold the data.
+        let cell = CellType::Error {
+            xf_index: 0,
+            value: None,
+        };
+
+        self.insert_cell(row, col, cell);
+
+        Ok(self)
+    }
+
     // Store a boolean cell in the worksheet data table structure.
     fn store_boolean(
         &mut self,
@@ -11751,6 +14408,17 @@ impl Worksheet {
         col: ColNum,
         boolean: bool,
         format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_boolean_type(row, col, boolean, CellXf::from(format))
+    }
+
+    // Store a boolean cell with a format or xf index.
+    fn store_boolean_type(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        boolean: bool,
+        cell_xf: CellXf,
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
@@ -11758,10 +14426,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => 0,
-        };
+        let xf_index = self.cell_xf_index(cell_xf);
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::Boolean { boolean, xf_index };
@@ -11785,7 +14450,11 @@ impl Worksheet {
         let mut hyperlink = url.clone();
         hyperlink.initialize()?;
 
-        match format {
+        // A format passed to the worksheet method takes precedence over a
+        // format set on the Url.
+        let url_format = hyperlink.format.take();
+
+        match format.or(url_format.as_ref()) {
             Some(format) => {
                 self.write_string_with_format(row, col, &hyperlink.user_text, format)?
             }
@@ -11854,7 +14523,7 @@ impl Worksheet {
         // Create the appropriate cell type to hold the data.
         let cell = CellType::Error {
             xf_index,
-            value: image_id,
+            value: Some(image_id),
         };
 
         // Store the cell error value.
@@ -11932,6 +14601,18 @@ impl Worksheet {
         }
     }
 
+    // Remove a cell value, if any, from the worksheet data table structure.
+    fn remove_cell(&mut self, row: RowNum, col: ColNum) {
+        if let Entry::Occupied(mut entry) = self.data_table.entry(row) {
+            let columns = entry.get_mut();
+            columns.remove(&col);
+
+            if columns.is_empty() {
+                entry.remove();
+            }
+        }
+    }
+
     // Store the column width in Excel character units. Updates to the width can
     // come from the external user or from the internal autofit() routines.
     fn store_column_width(&mut self, col: ColNum, width: f64, autofit: bool) {
@@ -11963,6 +14644,8 @@ impl Worksheet {
                     xf_index: 0,
                     hidden: false,
                     autofit,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -11989,10 +14672,33 @@ impl Worksheet {
         true
     }
 
+    // Get a selection range from first/last values that may be reversed to
+    // allow a selection to go from the end to the start.
+    fn selection_range(
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> String {
+        let (first_row, last_row) = if first_row > last_row {
+            (last_row, first_row)
+        } else {
+            (first_row, last_row)
+        };
+
+        let (first_col, last_col) = if first_col > last_col {
+            (last_col, first_col)
+        } else {
+            (first_col, last_col)
+        };
+
+        utility::cell_range(first_row, first_col, last_row, last_col)
+    }
+
     // Check that row and col are within the allowed Excel range but don't
     // modify the worksheet cell range.
     #[allow(clippy::unused_self)]
-    pub(crate) fn check_dimensions_only(&mut self, row: RowNum, col: ColNum) -> bool {
+    pub(crate) fn check_dimensions_only(&self, row: RowNum, col: ColNum) -> bool {
         // Check that the row an column number are within Excel's ranges.
         if row >= ROW_MAX {
             return false;
@@ -12033,6 +14739,16 @@ impl Worksheet {
         }
     }
 
+    // Get the xf index for a cell from a format, a pre-registered index, or
+    // the default.
+    fn cell_xf_index(&mut self, cell_xf: CellXf) -> u32 {
+        match cell_xf {
+            CellXf::None => 0,
+            CellXf::Format(format) => self.format_xf_index(format),
+            CellXf::Index(xf_index) => xf_index,
+        }
+    }
+
     /// Get the local instance DXF id for a format.
     ///
     /// Get the local instance DXF id for a format. These indexes will be
@@ -12157,55 +14873,221 @@ impl Worksheet {
                             target_mode,
                         ));
 
-                        rel_id
-                    }
-                };
+                        rel_id
+                    }
+                };
+
+                hyperlink.rel_id = rel_id;
+
+                drawing_hyperlink = Some(hyperlink);
+            }
+
+            // Store the image references.
+            let rel_id = match rel_ids.get(&image.hash) {
+                Some(rel_id) => *rel_id,
+                None => {
+                    let rel_id = 1 + rel_ids.len() as u32;
+                    rel_ids.insert(image.hash.clone(), rel_id);
+
+                    // Store the linkage to the drawings rels file.
+                    let image_name =
+                        format!("../media/image{image_id}.{}", image.image_type.extension());
+                    self.drawing_relationships.push((
+                        "image".to_string(),
+                        image_name,
+                        String::new(),
+                    ));
+
+                    rel_id
+                }
+            };
+
+            // Convert the image dimensions to drawing dimensions and store the
+            // drawing object.
+            let mut drawing_info = self.position_object_emus(row, col, image);
+            drawing_info.rel_id = rel_id;
+            drawing_info.url.clone_from(&drawing_hyperlink);
+            self.drawing.drawings.push(drawing_info);
+
+            // Store the used image type for the Content Type file.
+            self.image_types[image.image_type.clone() as usize] = true;
+        }
+
+        // Store the linkage to the worksheets rels file.
+        let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
+        self.drawing_object_relationships.push((
+            "drawing".to_string(),
+            drawing_name,
+            String::new(),
+        ));
+
+        self.has_drawing_object_linkage = true;
+    }
+
+    // Set up the notes in the worksheet. Excel stores the note text in a
+    // comments file and the note boxes in a VML file.
+    pub(crate) fn prepare_vml_notes(&mut self, drawing_id: u32, comment_id: u32) {
+        for ((row, col), note) in self.legacy_notes() {
+            // The default position of the note box is offset up and to the
+            // right of the parent cell, except at the edges of the worksheet.
+            let start_row = match row {
+                0 => 0,
+                _ if row == ROW_MAX - 3 => ROW_MAX - 7,
+                _ if row == ROW_MAX - 2 => ROW_MAX - 6,
+                _ if row == ROW_MAX - 1 => ROW_MAX - 5,
+                _ => row - 1,
+            };
+
+            let y_offset = match row {
+                0 => 2,
+                _ if row == ROW_MAX - 3 || row == ROW_MAX - 2 => 16,
+                _ if row == ROW_MAX - 1 => 14,
+                _ => 10,
+            };
+
+            let (start_col, x_offset) = if col >= COL_MAX - 3 {
+                (COL_MAX - 6 + (col - (COL_MAX - 3)), 49)
+            } else {
+                (col + 1, 15)
+            };
+
+            let mut note = note;
+            note.x_offset = x_offset;
+            note.y_offset = y_offset;
+
+            let drawing_info = self.position_object_pixels(start_row, start_col, &note);
+
+            self.note_vml_info.push(VmlNoteInfo {
+                row,
+                col,
+                is_visible: note.is_visible,
+                fill_color: format!("#{}", note.background_color.rgb_hex_value().to_lowercase()),
+                drawing_info,
+            });
+        }
+
+        // Store the linkage to the worksheets rels file.
+        let vml_drawing_name = format!("../drawings/vmlDrawing{drawing_id}.vml");
+        self.drawing_object_relationships.push((
+            "vmlDrawing".to_string(),
+            vml_drawing_name,
+            String::new(),
+        ));
+
+        let comment_name = format!("../comments{comment_id}.xml");
+        self.comment_relationships
+            .push(("comments".to_string(), comment_name, String::new()));
+    }
+
+    // Set up the threaded comments in the worksheet. The comment text is also
+    // stored as a note, see legacy_notes(), so this only adds the linkage to the
+    // worksheets rels file.
+    pub(crate) fn prepare_threaded_comments(&mut self, threaded_comment_id: u32) {
+        let threaded_comment_name =
+            format!("../threadedComments/threadedComment{threaded_comment_id}.xml");
+
+        self.threaded_comment_relationships.push((
+            "threadedComment".to_string(),
+            threaded_comment_name,
+            String::new(),
+        ));
+    }
+
+    // Check if the worksheet has notes or threaded comments. Both are written
+    // to the comments and VML files.
+    pub(crate) fn has_notes(&self) -> bool {
+        !self.notes.is_empty() || !self.threaded_comments.is_empty()
+    }
+
+    // Get the notes for the comments and VML files. Excel also stores each
+    // threaded comment as a note, with the text of the comment and replies,
+    // for versions of Excel that don't support threaded comments. The note
+    // author links it to the threaded comment.
+    pub(crate) fn legacy_notes(&self) -> BTreeMap<(RowNum, ColNum), Note> {
+        let mut notes = self.notes.clone();
+
+        for (&(row, col), comment) in &self.threaded_comments {
+            let mut text = String::from(
+                "[Threaded comment]\n\n\
+                Your version of Excel allows you to read this threaded comment; \
+                however, any edits to it will get removed if the file is opened in \
+                a newer version of Excel. \
+                Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\n\
+                Comment:\n    ",
+            );
+            text.push_str(&comment.text);
+
+            for reply in &comment.replies {
+                text.push_str("\nReply:\n    ");
+                text.push_str(&reply.text);
+            }
+
+            let author = format!("tc={}", self.threaded_comment_id(row, col, 0));
+            notes.insert((row, col), Note::new(text).set_author(author));
+        }
+
+        notes
+    }
 
-                hyperlink.rel_id = rel_id;
+    // Get the threaded comments and replies, with their ids and authors, for
+    // the threadedComment file.
+    pub(crate) fn threaded_comment_info(&self) -> Vec<ThreadedCommentInfo> {
+        let mut comment_info = vec![];
 
-                drawing_hyperlink = Some(hyperlink);
+        for (&(row, col), comment) in &self.threaded_comments {
+            let parent_id = self.threaded_comment_id(row, col, 0);
+
+            for (index, comment) in std::iter::once(comment).chain(&comment.replies).enumerate() {
+                let id = self.threaded_comment_id(row, col, index);
+                let author = self.threaded_comment_author(comment);
+
+                comment_info.push(ThreadedCommentInfo {
+                    cell: utility::row_col_to_cell(row, col),
+                    parent_id: if index == 0 {
+                        String::new()
+                    } else {
+                        parent_id.clone()
+                    },
+                    id,
+                    person_id: PersonList::person_id(&author),
+                    text: comment.text.clone(),
+                });
             }
+        }
 
-            // Store the image references.
-            let rel_id = match rel_ids.get(&image.hash) {
-                Some(rel_id) => *rel_id,
-                None => {
-                    let rel_id = 1 + rel_ids.len() as u32;
-                    rel_ids.insert(image.hash.clone(), rel_id);
+        comment_info
+    }
 
-                    // Store the linkage to the drawings rels file.
-                    let image_name =
-                        format!("../media/image{image_id}.{}", image.image_type.extension());
-                    self.drawing_relationships.push((
-                        "image".to_string(),
-                        image_name,
-                        String::new(),
-                    ));
+    // Get the unique threaded comment authors in the order that they are used.
+    pub(crate) fn threaded_comment_authors(&self) -> Vec<String> {
+        let mut authors = vec![];
 
-                    rel_id
+        for comment in self.threaded_comments.values() {
+            for comment in std::iter::once(comment).chain(&comment.replies) {
+                let author = self.threaded_comment_author(comment);
+                if !authors.contains(&author) {
+                    authors.push(author);
                 }
-            };
+            }
+        }
 
-            // Convert the image dimensions to drawing dimensions and store the
-            // drawing object.
-            let mut drawing_info = self.position_object_emus(row, col, image);
-            drawing_info.rel_id = rel_id;
-            drawing_info.url.clone_from(&drawing_hyperlink);
-            self.drawing.drawings.push(drawing_info);
+        authors
+    }
 
-            // Store the used image type for the Content Type file.
-            self.image_types[image.image_type.clone() as usize] = true;
+    // Get the author of a threaded comment, or the default author if it isn't
+    // set.
+    fn threaded_comment_author(&self, comment: &ThreadedComment) -> String {
+        match &comment.author {
+            Some(author) => author.clone(),
+            None => self.default_note_author.clone(),
         }
+    }
 
-        // Store the linkage to the worksheets rels file.
-        let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
-        self.drawing_object_relationships.push((
-            "drawing".to_string(),
-            drawing_name,
-            String::new(),
-        ));
-
-        self.has_drawing_object_linkage = true;
+    // Get the id of a threaded comment, for index 0, or of one of its replies.
+    // It is generated from the worksheet name and cell, rather than randomly,
+    // so that the output file is reproducible.
+    fn threaded_comment_id(&self, row: RowNum, col: ColNum, index: usize) -> String {
+        utility::hash_to_guid(&(&self.name, row, col, index))
     }
 
     // Set up images used in headers and footers. Excel handles these
@@ -12276,11 +15158,31 @@ impl Worksheet {
 
     // Convert the chart dimensions into drawing dimensions and add them to the
     // Drawing object. Also set the rel linkages between the files.
-    pub(crate) fn prepare_worksheet_charts(&mut self, mut chart_id: u32, drawing_id: u32) -> u32 {
+    //
+    // Histogram and Pareto charts are numbered separately from standard charts
+    // since they are stored in chartEx{n}.xml files.
+    pub(crate) fn prepare_worksheet_charts(
+        &mut self,
+        mut chart_id: u32,
+        mut chartex_id: u32,
+        drawing_id: u32,
+    ) -> (u32, u32) {
+        // Chartsheet charts are drawn at an absolute position that fills the
+        // page.
+        self.drawing.is_chartsheet = self.is_chartsheet;
+        self.drawing.is_portrait = self.portrait;
+
         for chart in self.charts.values_mut() {
-            chart.id = chart_id;
-            chart.add_axis_ids(chart_id);
-            chart_id += 1;
+            chart.is_chartsheet = self.is_chartsheet;
+
+            if chart.is_chartex() {
+                chart.id = chartex_id;
+                chartex_id += 1;
+            } else {
+                chart.id = chart_id;
+                chart.add_axis_ids(chart_id);
+                chart_id += 1;
+            }
         }
 
         let mut rel_id = self.drawing_relationships.len() as u32;
@@ -12291,9 +15193,15 @@ impl Worksheet {
             let chart_id = chart.id;
 
             // Store the linkage to the charts rels file.
-            let chart_name = format!("../charts/chart{chart_id}.xml");
-            self.drawing_relationships
-                .push(("chart".to_string(), chart_name, String::new()));
+            if chart.is_chartex() {
+                let chart_name = format!("../charts/chartEx{chart_id}.xml");
+                self.drawing_relationships
+                    .push(("chartEx".to_string(), chart_name, String::new()));
+            } else {
+                let chart_name = format!("../charts/chart{chart_id}.xml");
+                self.drawing_relationships
+                    .push(("chart".to_string(), chart_name, String::new()));
+            }
 
             // Convert the chart dimensions to drawing dimensions and store the
             // drawing object.
@@ -12314,7 +15222,7 @@ impl Worksheet {
             ));
         }
 
-        chart_id
+        (chart_id, chartex_id)
     }
 
     // Set a unique table id for each table and also set the rel linkages
@@ -12588,7 +15496,10 @@ impl Worksheet {
         self.drawing_object_relationships.clear();
         self.drawing_relationships.clear();
         self.vml_drawing_relationships.clear();
+        self.comment_relationships.clear();
+        self.threaded_comment_relationships.clear();
         self.header_footer_vml_info.clear();
+        self.note_vml_info.clear();
     }
 
     // Check if any external relationships are required.
@@ -12596,6 +15507,8 @@ impl Worksheet {
         !self.hyperlink_relationships.is_empty()
             || !self.drawing_object_relationships.is_empty()
             || !self.table_relationships.is_empty()
+            || !self.comment_relationships.is_empty()
+            || !self.threaded_comment_relationships.is_empty()
     }
 
     // Check if there is a header image.
@@ -12681,6 +15594,35 @@ impl Worksheet {
                                     }
                                     data.push(number.to_string());
                                 }
+                                CellType::Formula {
+                                    result, has_result, ..
+                                }
+                                | CellType::ArrayFormula {
+                                    result, has_result, ..
+                                } => {
+                                    // Use the formula result if it was set
+                                    // explicitly by the user. The default
+                                    // result isn't a calculated value so it is
+                                    // left empty. Boolean and error results are
+                                    // treated like the equivalent cell types
+                                    // and also left empty.
+                                    if !*has_result {
+                                        data.push(String::new());
+                                    } else if result.parse::<f64>().is_ok() {
+                                        if cache.cache_type != ChartRangeCacheDataType::String {
+                                            cache.cache_type = ChartRangeCacheDataType::Number;
+                                        }
+                                        data.push(result.to_string());
+                                    } else if result.is_empty()
+                                        || result.starts_with('#')
+                                        || matches!(&**result, "TRUE" | "FALSE")
+                                    {
+                                        data.push(String::new());
+                                    } else {
+                                        cache.cache_type = ChartRangeCacheDataType::String;
+                                        data.push(result.to_string());
+                                    }
+                                }
 
                                 _ => data.push(String::new()),
                             },
@@ -12759,6 +15701,11 @@ impl Worksheet {
 
     // Assemble and write the XML file.
     pub(crate) fn assemble_xml_file(&mut self) {
+        if self.is_chartsheet {
+            self.assemble_chartsheet_xml_file();
+            return;
+        }
+
         self.writer.xml_declaration();
 
         // Write the worksheet element.
@@ -12807,6 +15754,11 @@ impl Worksheet {
             self.write_conditional_formats();
         }
 
+        // Write the dataValidations element.
+        if !self.data_validations.is_empty() {
+            self.write_data_validations();
+        }
+
         // Write the hyperlinks elements.
         if !self.hyperlinks.is_empty() {
             self.write_hyperlinks();
@@ -12845,6 +15797,11 @@ impl Worksheet {
             self.write_drawing();
         }
 
+        // Write the legacyDrawing element.
+        if self.has_notes() {
+            self.write_legacy_drawing();
+        }
+
         // Write the legacyDrawingHF element.
         if self.has_header_footer_images() {
             self.write_legacy_drawing_hf();
@@ -12864,6 +15821,109 @@ impl Worksheet {
         self.writer.xml_end_tag("worksheet");
     }
 
+    // Assemble and write the XML file for a chartsheet. This is a subset of
+    // the worksheet file with a chartsheet root element.
+    fn assemble_chartsheet_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the chartsheet element.
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+        ];
+        self.writer.xml_start_tag("chartsheet", &attributes);
+
+        // Write the sheetPr element.
+        self.write_chartsheet_pr();
+
+        // Write the sheetViews element.
+        self.writer.xml_start_tag_only("sheetViews");
+        self.write_chartsheet_view();
+        self.writer.xml_end_tag("sheetViews");
+
+        // Write the pageMargins element.
+        self.write_page_margins();
+
+        // Write the pageSetup element.
+        if self.page_setup_changed {
+            self.write_chartsheet_page_setup();
+        }
+
+        // Write the headerFooter element.
+        if self.head_footer_changed {
+            self.write_header_footer();
+        }
+
+        // Write the drawing element.
+        if !self.drawing.drawings.is_empty() {
+            self.write_drawing();
+        }
+
+        // Close the chartsheet tag.
+        self.writer.xml_end_tag("chartsheet");
+    }
+
+    // Write the <sheetPr> element for a chartsheet.
+    fn write_chartsheet_pr(&mut self) {
+        if self.tab_color == Color::Default || self.tab_color == Color::Automatic {
+            return;
+        }
+
+        self.writer.xml_start_tag_only("sheetPr");
+        self.write_tab_color();
+        self.writer.xml_end_tag("sheetPr");
+    }
+
+    // Write the <sheetView> element for a chartsheet.
+    fn write_chartsheet_view(&mut self) {
+        let mut attributes = vec![];
+
+        if self.selected {
+            attributes.push(("tabSelected", "1".to_string()));
+        }
+
+        if self.zoom != 100 {
+            attributes.push(("zoomScale", self.zoom.to_string()));
+        }
+
+        attributes.push(("workbookViewId", "0".to_string()));
+        attributes.push(("zoomToFit", "1".to_string()));
+
+        self.writer.xml_empty_tag("sheetView", &attributes);
+    }
+
+    // Write the <pageSetup> element for a chartsheet. This only supports a
+    // subset of the worksheet attributes.
+    fn write_chartsheet_page_setup(&mut self) {
+        let mut attributes = vec![];
+
+        if self.paper_size > 0 {
+            attributes.push(("paperSize", self.paper_size.to_string()));
+        }
+
+        if self.portrait {
+            attributes.push(("orientation", "portrait".to_string()));
+        } else {
+            attributes.push(("orientation", "landscape".to_string()));
+        }
+
+        if self.print_black_and_white {
+            attributes.push(("blackAndWhite", "1".to_string()));
+        }
+
+        if self.print_draft {
+            attributes.push(("draft", "1".to_string()));
+        }
+
+        self.writer.xml_empty_tag("pageSetup", &attributes);
+    }
+
     // Write the <worksheet> element.
     fn write_worksheet(&mut self) {
         let mut attributes = vec![
@@ -12897,6 +15957,8 @@ impl Worksheet {
         if self.filter_conditions.is_empty()
             && !self.fit_to_page
             && (self.tab_color == Color::Default || self.tab_color == Color::Automatic)
+            && self.outline_symbols_below
+            && self.outline_symbols_right
         {
             return;
         }
@@ -12908,15 +15970,20 @@ impl Worksheet {
 
         if self.fit_to_page
             || (self.tab_color != Color::Default && self.tab_color != Color::Automatic)
+            || !self.outline_symbols_below
+            || !self.outline_symbols_right
         {
             self.writer.xml_start_tag("sheetPr", &attributes);
 
-            // Write the pageSetUpPr element.
-            self.write_page_set_up_pr();
-
             // Write the tabColor element.
             self.write_tab_color();
 
+            // Write the outlinePr element.
+            self.write_outline_pr();
+
+            // Write the pageSetUpPr element.
+            self.write_page_set_up_pr();
+
             self.writer.xml_end_tag("sheetPr");
         } else {
             self.writer.xml_empty_tag("sheetPr", &attributes);
@@ -12934,6 +16001,25 @@ impl Worksheet {
         self.writer.xml_empty_tag("pageSetUpPr", &attributes);
     }
 
+    // Write the <outlinePr> element.
+    fn write_outline_pr(&mut self) {
+        if self.outline_symbols_below && self.outline_symbols_right {
+            return;
+        }
+
+        let mut attributes = vec![];
+
+        if !self.outline_symbols_below {
+            attributes.push(("summaryBelow", "0"));
+        }
+
+        if !self.outline_symbols_right {
+            attributes.push(("summaryRight", "0"));
+        }
+
+        self.writer.xml_empty_tag("outlinePr", &attributes);
+    }
+
     // Write the <tabColor> element.
     fn write_tab_color(&mut self) {
         if self.tab_color == Color::Default {
@@ -13004,26 +16090,45 @@ impl Worksheet {
             PageView::Normal => {}
         }
 
-        if !self.top_left_cell.is_empty() {
-            attributes.push(("topLeftCell", self.top_left_cell.clone()));
+        // With frozen panes the top-left cell only applies to the sheet view in
+        // the directions that aren't split. The scrolled region is handled by
+        // the <pane> element.
+        let (mut row, mut col) = self.top_left_cell;
+        if self.panes.freeze_cell.0 > 0 {
+            row = 0;
+        }
+        if self.panes.freeze_cell.1 > 0 {
+            col = 0;
+        }
+
+        if row > 0 || col > 0 {
+            attributes.push(("topLeftCell", utility::row_col_to_cell(row, col)));
         }
 
         if self.zoom != 100 {
             attributes.push(("zoomScale", self.zoom.to_string()));
 
-            match self.page_view {
-                PageView::PageLayout => {
-                    attributes.push(("zoomScalePageLayoutView", self.zoom.to_string()));
-                }
-                PageView::PageBreaks => {
-                    attributes.push(("zoomScaleSheetLayoutView", self.zoom.to_string()));
-                }
-                PageView::Normal => {
-                    attributes.push(("zoomScaleNormal", self.zoom.to_string()));
-                }
+            if self.page_view == PageView::Normal {
+                attributes.push(("zoomScaleNormal", self.zoom.to_string()));
             }
         }
 
+        // The page break preview zoom can be set independently of the current
+        // view zoom.
+        let page_break_preview_zoom = match self.page_break_preview_zoom {
+            Some(zoom) => Some(zoom),
+            None if self.zoom != 100 && self.page_view == PageView::PageBreaks => Some(self.zoom),
+            None => None,
+        };
+
+        if let Some(zoom) = page_break_preview_zoom {
+            attributes.push(("zoomScaleSheetLayoutView", zoom.to_string()));
+        }
+
+        if self.zoom != 100 && self.page_view == PageView::PageLayout {
+            attributes.push(("zoomScalePageLayoutView", self.zoom.to_string()));
+        }
+
         attributes.push(("workbookViewId", "0".to_string()));
 
         if self.panes.is_empty() && self.selected_range.0.is_empty() {
@@ -13031,7 +16136,12 @@ impl Worksheet {
         } else {
             self.writer.xml_start_tag("sheetView", &attributes);
             self.write_panes();
-            self.write_selections();
+
+            // Split panes write the selection in the active pane.
+            if !self.panes.is_split() {
+                self.write_selections();
+            }
+
             self.writer.xml_end_tag("sheetView");
         }
     }
@@ -13054,6 +16164,11 @@ impl Worksheet {
             return;
         }
 
+        if self.panes.is_split() {
+            self.write_split_panes();
+            return;
+        }
+
         let row = self.panes.freeze_cell.0;
         let col = self.panes.freeze_cell.1;
 
@@ -13094,13 +16209,88 @@ impl Worksheet {
             attributes.push(("ySplit", row.to_string()));
         }
 
-        attributes.push(("topLeftCell", self.panes.top_left()));
+        // Use the worksheet top-left cell for the scrolled region if it is
+        // beyond the frozen panes and there isn't an explicit pane top cell.
+        let top_left = if self.panes.top_cell == (0, 0) && self.top_left_cell != (0, 0) {
+            utility::row_col_to_cell(
+                cmp::max(self.top_left_cell.0, row),
+                cmp::max(self.top_left_cell.1, col),
+            )
+        } else {
+            self.panes.top_left()
+        };
+
+        attributes.push(("topLeftCell", top_left));
         attributes.push(("activePane", active_pane.to_string()));
         attributes.push(("state", "frozen".to_string()));
 
         self.writer.xml_empty_tag("pane", &attributes);
     }
 
+    // Write the <pane> and <selection> elements for split, unfrozen, panes.
+    fn write_split_panes(&mut self) {
+        let (y, x) = self.panes.split_position;
+        let mut attributes = vec![];
+
+        // Convert the split positions to twips with the Excel padding.
+        let y_split = if y > 0.0 {
+            (20.0 * y + 300.0) as u32
+        } else {
+            0
+        };
+        let x_split = if x > 0.0 {
+            let pixels = if x < 1.0 {
+                (x * 12.0 + 0.5) as u32
+            } else {
+                (x * 7.0 + 0.5) as u32 + 5
+            };
+            f64::from(pixels).mul_add(15.0, 390.0) as u32
+        } else {
+            0
+        };
+
+        // Estimate the top-left cell of the scrolled pane from the split
+        // position, unless it has been set explicitly. This doesn't take
+        // non-default row heights or column widths into account.
+        let top_left = if self.panes.top_cell == (0, 0) {
+            let row = (0.5 + f64::from(y_split.saturating_sub(300)) / 20.0 / 15.0) as u32;
+            let col =
+                (0.5 + f64::from(x_split.saturating_sub(390)) / 20.0 / 3.0 * 4.0 / 64.0) as u16;
+            utility::row_col_to_cell(row, col)
+        } else {
+            self.panes.top_left()
+        };
+
+        let active_pane = match (y_split > 0, x_split > 0) {
+            (true, true) => "bottomRight",
+            (false, true) => "topRight",
+            _ => "bottomLeft",
+        };
+
+        if x_split > 0 {
+            attributes.push(("xSplit", x_split.to_string()));
+        }
+
+        if y_split > 0 {
+            attributes.push(("ySplit", y_split.to_string()));
+        }
+
+        attributes.push(("topLeftCell", top_left));
+        attributes.push(("activePane", active_pane.to_string()));
+
+        self.writer.xml_empty_tag("pane", &attributes);
+
+        // Use the user selection, if any, for the active pane.
+        let active_cell = self.selected_range.0.clone();
+        let range = self.selected_range.1.clone();
+
+        if y_split > 0 && x_split > 0 {
+            self.write_selection("topRight", "", "");
+            self.write_selection("bottomLeft", "", "");
+        }
+        self.write_selection(active_pane, &active_cell, &range);
+    }
+
     // Write the <selection> element.
     fn write_selection(&mut self, position: &str, active_cell: &str, range: &str) {
         let mut attributes = vec![];
@@ -13122,10 +16312,32 @@ impl Worksheet {
 
     // Write the <sheetFormatPr> element.
     fn write_sheet_format_pr(&mut self) {
-        let mut attributes = vec![("defaultRowHeight", "15")];
+        let mut attributes = vec![("defaultRowHeight", "15".to_string())];
+
+        let row_level = self
+            .changed_rows
+            .values()
+            .map(|row_options| row_options.level)
+            .max()
+            .unwrap_or(0);
+
+        let col_level = self
+            .changed_cols
+            .values()
+            .map(|col_options| col_options.level)
+            .max()
+            .unwrap_or(0);
+
+        if row_level > 0 {
+            attributes.push(("outlineLevelRow", row_level.to_string()));
+        }
+
+        if col_level > 0 {
+            attributes.push(("outlineLevelCol", col_level.to_string()));
+        }
 
         if self.use_x14_extensions {
-            attributes.push(("x14ac:dyDescent", "0.25"));
+            attributes.push(("x14ac:dyDescent", "0.25".to_string()));
         }
 
         self.writer.xml_empty_tag("sheetFormatPr", &attributes);
@@ -13227,6 +16439,20 @@ impl Worksheet {
         }
     }
 
+    // Write the <dataValidations> element.
+    fn write_data_validations(&mut self) {
+        let attributes = [("count", self.data_validations.len().to_string())];
+
+        self.writer.xml_start_tag("dataValidations", &attributes);
+
+        for (cell_range, data_validation) in &self.data_validations {
+            let rule = data_validation.rule(cell_range);
+            self.writer.xml_raw_string(&rule);
+        }
+
+        self.writer.xml_end_tag("dataValidations");
+    }
+
     // Write the <x14:conditionalFormattings> element. This is used for new
     // style conditional formats added after the original spec.
     fn write_conditional_formattings(&mut self) {
@@ -13399,6 +16625,18 @@ impl Worksheet {
             attributes.push(("draft", "1".to_string()));
         }
 
+        // Threaded comments can't be displayed on the sheet so they can only
+        // be printed at the end.
+        match self.print_notes {
+            NotePrintPosition::None => {}
+            NotePrintPosition::AsDisplayed if !self.notes.is_empty() => {
+                attributes.push(("cellComments", "asDisplayed".to_string()));
+            }
+            NotePrintPosition::AtEnd | NotePrintPosition::AsDisplayed => {
+                attributes.push(("cellComments", "atEnd".to_string()));
+            }
+        }
+
         attributes.push(("horizontalDpi", "200".to_string()));
         attributes.push(("verticalDpi", "200".to_string()));
 
@@ -13417,8 +16655,9 @@ impl Worksheet {
 
             for col in self.filter_conditions.clone().keys() {
                 let filter_condition = self.filter_conditions.get(col).unwrap().clone();
+                let top10_threshold = self.top10_filter_threshold(*col, &filter_condition);
 
-                self.write_filter_column(*col - col_offset, &filter_condition);
+                self.write_filter_column(*col - col_offset, &filter_condition, top10_threshold);
             }
 
             self.writer.xml_end_tag("autoFilter");
@@ -13426,12 +16665,19 @@ impl Worksheet {
     }
 
     // Write the <filterColumn> element.
-    fn write_filter_column(&mut self, col: ColNum, filter_condition: &FilterCondition) {
+    fn write_filter_column(
+        &mut self,
+        col: ColNum,
+        filter_condition: &FilterCondition,
+        top10_threshold: Option<f64>,
+    ) {
         let attributes = [("colId", col.to_string())];
 
         self.writer.xml_start_tag("filterColumn", &attributes);
 
-        if filter_condition.is_list_filter {
+        if filter_condition.is_top10_filter {
+            self.write_top10_filter(filter_condition, top10_threshold);
+        } else if filter_condition.is_list_filter {
             self.write_list_filters(filter_condition);
         } else {
             self.write_custom_filters(filter_condition);
@@ -13458,15 +16704,36 @@ impl Worksheet {
                 self.write_filter(data.string.clone());
             }
 
-            self.writer.xml_end_tag("filters");
+            self.writer.xml_end_tag("filters");
+        }
+    }
+
+    // Write the <filter> element.
+    fn write_filter(&mut self, value: String) {
+        let attributes = [("val", value)];
+
+        self.writer.xml_empty_tag("filter", &attributes);
+    }
+
+    // Write the <top10> element.
+    fn write_top10_filter(&mut self, filter_condition: &FilterCondition, threshold: Option<f64>) {
+        let mut attributes = vec![];
+
+        if filter_condition.top10_is_bottom {
+            attributes.push(("top", "0".to_string()));
+        }
+
+        if filter_condition.top10_is_percent {
+            attributes.push(("percent", "1".to_string()));
         }
-    }
 
-    // Write the <filter> element.
-    fn write_filter(&mut self, value: String) {
-        let attributes = [("val", value)];
+        attributes.push(("val", filter_condition.top10_value.to_string()));
 
-        self.writer.xml_empty_tag("filter", &attributes);
+        if let Some(threshold) = threshold {
+            attributes.push(("filterVal", threshold.to_string()));
+        }
+
+        self.writer.xml_empty_tag("top10", &attributes);
     }
 
     // Write the <customFilters> element.
@@ -13576,6 +16843,7 @@ impl Worksheet {
                         formula,
                         xf_index,
                         result,
+                        ..
                     } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
                         self.write_formula_cell(row_num, col_num, formula, xf_index, result);
@@ -13586,6 +16854,7 @@ impl Worksheet {
                         result,
                         is_dynamic,
                         range,
+                        ..
                     } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
                         self.write_array_formula_cell(
@@ -13608,7 +16877,8 @@ impl Worksheet {
                     }
                     CellType::Error { value, xf_index } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        let image_id = self.global_embedded_image_indices[*value as usize];
+                        let image_id =
+                            value.map(|value| self.global_embedded_image_indices[value as usize]);
                         self.write_error_cell(row_num, col_num, image_id, xf_index);
                     }
                 }
@@ -13697,6 +16967,14 @@ impl Worksheet {
             if row_options.height != DEFAULT_ROW_HEIGHT {
                 attributes.push(("customHeight", "1".to_string()));
             }
+
+            if row_options.level > 0 {
+                attributes.push(("outlineLevel", row_options.level.to_string()));
+            }
+
+            if row_options.collapsed {
+                attributes.push(("collapsed", "1".to_string()));
+            }
         }
 
         if has_data {
@@ -13784,10 +17062,12 @@ impl Worksheet {
             String::new()
         };
 
-        let result_type = if result.parse::<f64>().is_err() {
-            r#" t="str""#
-        } else {
-            ""
+        // Boolean results are stored as 1/0 with a boolean type.
+        let (result_type, result) = match result {
+            "TRUE" => (r#" t="b""#, "1"),
+            "FALSE" => (r#" t="b""#, "0"),
+            _ if result.parse::<f64>().is_err() => (r#" t="str""#, result),
+            _ => ("", result),
         };
 
         write!(
@@ -13892,30 +17172,31 @@ impl Worksheet {
     }
 
     // Write the <c> element for an error cell. We currently only support the
-    // #VALUE! error type which is used for embedded images.
-    fn write_error_cell(&mut self, row: RowNum, col: ColNum, value: u32, xf_index: u32) {
+    // #VALUE! error type which is also used for embedded images, with a value
+    // metadata index.
+    fn write_error_cell(&mut self, row: RowNum, col: ColNum, image_id: Option<u32>, xf_index: u32) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
 
-        if xf_index > 0 {
-            write!(
-                &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}" t="e" vm="{}"><v>#VALUE!</v></c>"#,
-                col_name,
-                row + 1,
-                xf_index,
-                value
-            )
-            .expect(XML_WRITE_ERROR);
+        let style = if xf_index > 0 {
+            format!(r#" s="{xf_index}""#)
         } else {
-            write!(
-                &mut self.writer.xmlfile,
-                r#"<c r="{}{}" t="e" vm="{}"><v>#VALUE!</v></c>"#,
-                col_name,
-                row + 1,
-                value
-            )
-            .expect(XML_WRITE_ERROR);
-        }
+            String::new()
+        };
+
+        let metadata = match image_id {
+            Some(image_id) => format!(r#" vm="{image_id}""#),
+            None => String::new(),
+        };
+
+        write!(
+            &mut self.writer.xmlfile,
+            r#"<c r="{}{}"{} t="e"{}><v>#VALUE!</v></c>"#,
+            col_name,
+            row + 1,
+            style,
+            metadata
+        )
+        .expect(XML_WRITE_ERROR);
     }
 
     // Write the <cols> element.
@@ -14018,6 +17299,14 @@ impl Worksheet {
             attributes.push(("customWidth", "1".to_string()));
         }
 
+        if col_options.level > 0 {
+            attributes.push(("outlineLevel", col_options.level.to_string()));
+        }
+
+        if col_options.collapsed {
+            attributes.push(("collapsed", "1".to_string()));
+        }
+
         self.writer.xml_empty_tag("col", &attributes);
     }
 
@@ -14092,6 +17381,14 @@ impl Worksheet {
         self.writer.xml_empty_tag("drawing", &attributes);
     }
 
+    // Write the <legacyDrawing> element.
+    fn write_legacy_drawing(&mut self) {
+        self.rel_count += 1;
+        let attributes = [("r:id", format!("rId{}", self.rel_count))];
+
+        self.writer.xml_empty_tag("legacyDrawing", &attributes);
+    }
+
     // Write the <legacyDrawingHF> element.
     fn write_legacy_drawing_hf(&mut self) {
         self.rel_count += 1;
@@ -14604,6 +17901,35 @@ pub trait IntoExcelData {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError>;
+
+    /// Trait method to handle writing a type to Excel with a pre-registered
+    /// format index, see [`Worksheet::write_with_format_id()`].
+    ///
+    /// The default implementation writes the type with the registered format
+    /// via [`IntoExcelData::write_with_format()`]. It doesn't need to be
+    /// implemented for user types.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    #[doc(hidden)]
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        Self: Sized,
+    {
+        let format = worksheet.xf_formats[xf_index as usize].clone();
+        self.write_with_format(worksheet, row, col, &format)
+    }
 }
 
 macro_rules! write_string_trait_impl {
@@ -14627,10 +17953,20 @@ macro_rules! write_string_trait_impl {
             ) -> Result<&'a mut Worksheet, XlsxError> {
                 worksheet.store_string(row, col, self.into(), Some(format))
             }
+
+            fn write_with_xf_index(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                xf_index: u32,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                worksheet.store_string_type(row, col, self.into(), CellXf::Index(xf_index))
+            }
         }
     )*)
 }
-write_string_trait_impl!(&str &String String Cow<'_, str>);
+write_string_trait_impl!(&str &String String Cow<'_, str> char);
 
 macro_rules! write_number_trait_impl {
     ($($t:ty)*) => ($(
@@ -14653,6 +17989,16 @@ macro_rules! write_number_trait_impl {
             ) -> Result<&'a mut Worksheet, XlsxError> {
                 worksheet.store_number(row, col, self, Some(format))
             }
+
+            fn write_with_xf_index(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                xf_index: u32,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                worksheet.store_number_type(row, col, self.into(), CellXf::Index(xf_index), false)
+            }
         }
     )*)
 }
@@ -14683,6 +18029,17 @@ macro_rules! write_number_trait_impl {
                 #[allow(clippy::cast_precision_loss)]
                 worksheet.store_number(row, col, self as f64, Some(format))
             }
+
+            fn write_with_xf_index(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                xf_index: u32,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                #[allow(clippy::cast_precision_loss)]
+                worksheet.store_number_type(row, col, self as f64, CellXf::Index(xf_index), false)
+            }
         }
     )*)
 }
@@ -14707,6 +18064,16 @@ impl IntoExcelData for bool {
     ) -> Result<&'a mut Worksheet, XlsxError> {
         worksheet.store_boolean(row, col, self, Some(format))
     }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_boolean_type(row, col, self, CellXf::Index(xf_index))
+    }
 }
 
 impl IntoExcelData for &ExcelDateTime {
@@ -14730,6 +18097,17 @@ impl IntoExcelData for &ExcelDateTime {
         let number = self.to_excel();
         worksheet.store_datetime(row, col, number, Some(format))
     }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.to_excel();
+        worksheet.store_number_type(row, col, number, CellXf::Index(xf_index), true)
+    }
 }
 
 impl IntoExcelData for ExcelDateTime {
@@ -14753,6 +18131,55 @@ impl IntoExcelData for ExcelDateTime {
         let number = self.to_excel_serial_date();
         worksheet.store_datetime(row, col, number, Some(format))
     }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.to_excel_serial_date();
+        worksheet.store_number_type(row, col, number, CellXf::Index(xf_index), true)
+    }
+}
+
+// Durations are stored in Excel as a fraction of a day, like times. Since there
+// is no default date/time format they are written with an elapsed time format
+// when the user doesn't supply one.
+impl IntoExcelData for Duration {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.as_secs_f64() / (24.0 * 60.0 * 60.0);
+        let format = Format::new().set_num_format("[h]:mm:ss");
+        worksheet.store_datetime(row, col, number, Some(&format))
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = self.as_secs_f64() / (24.0 * 60.0 * 60.0);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.as_secs_f64() / (24.0 * 60.0 * 60.0);
+        worksheet.store_number_type(row, col, number, CellXf::Index(xf_index), true)
+    }
 }
 
 #[cfg(feature = "chrono")]
@@ -14939,6 +18366,19 @@ impl<T: IntoExcelData> IntoExcelData for Option<T> {
             None => worksheet.write_blank(row, col, format),
         }
     }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match self {
+            Some(data) => data.write_with_xf_index(worksheet, row, col, xf_index),
+            None => worksheet.store_blank_type(row, col, CellXf::Index(xf_index)),
+        }
+    }
 }
 
 impl<T: IntoExcelData, E: IntoExcelData> IntoExcelData for Result<T, E> {
@@ -14966,12 +18406,101 @@ impl<T: IntoExcelData, E: IntoExcelData> IntoExcelData for Result<T, E> {
             Err(data) => worksheet.write_with_format(row, col, data, format),
         }
     }
+
+    fn write_with_xf_index(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        xf_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match self {
+            Ok(data) => data.write_with_xf_index(worksheet, row, col, xf_index),
+            Err(data) => data.write_with_xf_index(worksheet, row, col, xf_index),
+        }
+    }
 }
 
 // -----------------------------------------------------------------------
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
 
+/// The `ResultErrorMode` enum defines how the `Err` variant of a `Result` is
+/// written by [`Worksheet::write_result()`].
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResultErrorMode {
+    /// Write the [`Display`] text of the error as a string.
+    Text,
+
+    /// Leave the cell blank. Any value previously written to the cell is
+    /// removed.
+    Blank,
+
+    /// Write an Excel `#VALUE!` error to the cell.
+    ValueError,
+}
+
+/// The `FormatId` struct is a handle to a format registered with
+/// [`Worksheet::register_format()`].
+///
+/// It is used with [`Worksheet::write_with_format_id()`] and is only valid for
+/// the worksheet that created it.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FormatId {
+    xf_index: u32,
+    worksheet_key: u32,
+}
+
+// A unique key for each worksheet so that a `FormatId` can't be used with a
+// worksheet other than the one that created it.
+static NEXT_FORMAT_ID_KEY: AtomicU32 = AtomicU32::new(0);
+
+// The format source for a stored cell: no format, a format that needs to be
+// looked up, or a pre-registered xf index from a `FormatId`.
+#[derive(Clone, Copy)]
+enum CellXf<'a> {
+    None,
+    Format(&'a Format),
+    Index(u32),
+}
+
+impl<'a> From<Option<&'a Format>> for CellXf<'a> {
+    fn from(format: Option<&'a Format>) -> CellXf<'a> {
+        match format {
+            Some(format) => CellXf::Format(format),
+            None => CellXf::None,
+        }
+    }
+}
+
+/// The `ColumnWidth` enum defines the unit of a column width used with
+/// [`Worksheet::set_column_width_units()`].
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnWidth {
+    /// The width in Excel character units, like
+    /// [`Worksheet::set_column_width()`].
+    Chars(f64),
+
+    /// The width in pixels, like [`Worksheet::set_column_width_pixels()`].
+    Pixels(f64),
+}
+
+// Convert a column width in pixels to Excel character units.
+fn pixels_to_width(pixels: f64) -> f64 {
+    // Properties for Calibri 11.
+    let max_digit_width = 7.0_f64;
+    let padding = 5.0_f64;
+
+    if pixels < 12.0 {
+        pixels / (max_digit_width + padding)
+    } else {
+        (pixels - padding) / max_digit_width
+    }
+}
+
 // Round to the closest integer number of emu units.
 fn round_to_emus(dimension: f64) -> f64 {
     (dimension * 9525.0).round()
@@ -15005,6 +18534,13 @@ impl CellRange {
         utility::cell_range(self.first_row, self.first_col, self.last_row, self.last_col)
     }
 
+    pub(crate) fn overlaps(&self, other: &CellRange) -> bool {
+        self.first_row <= other.last_row
+            && other.first_row <= self.last_row
+            && self.first_col <= other.last_col
+            && other.first_col <= self.last_col
+    }
+
     pub(crate) fn to_error_string(&self) -> String {
         format!(
             "({}, {}, {}, {}) / {}",
@@ -15033,6 +18569,8 @@ struct RowOptions {
     height: f64,
     xf_index: u32,
     hidden: bool,
+    level: u8,
+    collapsed: bool,
 }
 
 #[derive(Clone, PartialEq)]
@@ -15041,6 +18579,8 @@ struct ColOptions {
     xf_index: u32,
     hidden: bool,
     autofit: bool,
+    level: u8,
+    collapsed: bool,
 }
 
 #[derive(Clone)]
@@ -15049,6 +18589,7 @@ enum CellType {
         formula: Box<str>,
         xf_index: u32,
         result: Box<str>,
+        has_result: bool,
         is_dynamic: bool,
         range: Box<str>,
     },
@@ -15061,12 +18602,13 @@ enum CellType {
     },
     Error {
         xf_index: u32,
-        value: u32,
+        value: Option<u32>,
     },
     Formula {
         formula: Box<str>,
         xf_index: u32,
         result: Box<str>,
+        has_result: bool,
     },
     Number {
         number: f64,
@@ -15089,7 +18631,7 @@ enum CellType {
     },
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 enum PageView {
     Normal,
     PageLayout,
@@ -15100,11 +18642,16 @@ enum PageView {
 struct Panes {
     freeze_cell: (RowNum, ColNum),
     top_cell: (RowNum, ColNum),
+    split_position: (f64, f64),
 }
 
 impl Panes {
     fn is_empty(&self) -> bool {
-        self.freeze_cell.0 == 0 && self.freeze_cell.1 == 0
+        self.freeze_cell.0 == 0 && self.freeze_cell.1 == 0 && !self.is_split()
+    }
+
+    fn is_split(&self) -> bool {
+        self.split_position.0 > 0.0 || self.split_position.1 > 0.0
     }
 
     fn top_left(&self) -> String {
@@ -15131,6 +18678,7 @@ pub(crate) struct DefinedName {
     pub(crate) first_col: ColNum,
     pub(crate) last_row: RowNum,
     pub(crate) last_col: ColNum,
+    pub(crate) additional_areas: Vec<(RowNum, ColNum, RowNum, ColNum)>,
 }
 
 impl DefinedName {
@@ -15147,6 +18695,7 @@ impl DefinedName {
             first_col: COL_MAX,
             last_row: 0,
             last_col: 0,
+            additional_areas: vec![],
         }
     }
 
@@ -15183,6 +18732,72 @@ impl DefinedName {
         }
     }
 
+    // Check that a defined name is valid according to the Excel rules.
+    pub(crate) fn validate_name(name: &str) -> Result<(), XlsxError> {
+        // Excel requires that the name starts with a letter or underscore.
+        // Also, backspace is allowed but undocumented by Excel.
+        let Some(first_char) = name.chars().next() else {
+            return Err(XlsxError::ParameterError(
+                "Defined name cannot be blank".to_string(),
+            ));
+        };
+
+        if !first_char.is_alphabetic() && first_char != '_' && first_char != '\\' {
+            let error = format!("Name '{name}' must start with a letter or underscore in Excel");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        // Excel also prohibits certain characters in the name.
+        if name.contains([' ', ',', '/', '*', '[', ']', ':', '"', '\'']) {
+            let error = format!(
+                "Name '{name}' cannot contain any of the characters `,/*[]:\"'` or `space` in Excel"
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        // Check that the name is <= 255 characters, an Excel limit.
+        if name.chars().count() > MAX_PARAMETER_LEN {
+            let error = format!("Name '{name}' exceeds Excel's limit of 255 characters");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        // The name cannot look like an A1 style cell reference, within the
+        // worksheet limits, or an R1C1 style reference.
+        let a1_cell = static_regex!(r"^([A-Za-z]{1,3})([0-9]+)$");
+        let r1c1_cell = static_regex!(r"^([Rr][0-9]*)?([Cc][0-9]*)?$");
+
+        let mut is_cell_reference = r1c1_cell.is_match(name);
+
+        if let Some(caps) = a1_cell.captures(name) {
+            let col = utility::column_name_to_number(&caps[1].to_uppercase());
+            let row = caps[2].parse::<u64>().unwrap_or(u64::MAX);
+
+            if col < COL_MAX && row >= 1 && row <= u64::from(ROW_MAX) {
+                is_cell_reference = true;
+            }
+        }
+
+        if is_cell_reference {
+            let error = format!("Name '{name}' cannot look like an Excel cell reference");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        Ok(())
+    }
+
+    // Check that the formula, value or range that a name refers to isn't blank
+    // and return it without the leading "=".
+    pub(crate) fn validate_formula(name: &str, formula: &str) -> Result<String, XlsxError> {
+        let formula = utility::formula_to_string(formula.trim());
+
+        if formula.trim().is_empty() {
+            let error = format!("Formula for defined name '{name}' cannot be blank");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        Ok(formula)
+    }
+
     pub(crate) fn unquoted_sheet_name(&self) -> String {
         if self.quoted_sheet_name.starts_with('\'') && self.quoted_sheet_name.ends_with('\'') {
             self.quoted_sheet_name[1..self.quoted_sheet_name.len() - 1].to_string()
@@ -15205,33 +18820,51 @@ impl DefinedName {
         self.sort_name = sort_name.to_lowercase();
     }
 
+    // Get the sheet qualified, absolute, range for a print or autofilter area.
+    fn area_range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> String {
+        let range = if first_col == 0 && last_col == COL_MAX - 1 {
+            // The print range is the entire column range, therefore we
+            // create a row only range.
+            format!("${}:${}", first_row + 1, last_row + 1)
+        } else if first_row == 0 && last_row == ROW_MAX - 1 {
+            // The print range is the entire row range, therefore we
+            // create a column only range.
+            format!(
+                "${}:${}",
+                utility::column_number_to_name(first_col),
+                utility::column_number_to_name(last_col)
+            )
+        } else {
+            // Otherwise handle it as a standard cell range.
+            utility::cell_range_absolute(first_row, first_col, last_row, last_col)
+        };
+
+        if self.quoted_sheet_name.is_empty() {
+            range
+        } else {
+            format!("{}!{}", self.quoted_sheet_name, range)
+        }
+    }
+
     pub(crate) fn set_range(&mut self) {
         match self.name_type {
             DefinedNameType::Autofilter | DefinedNameType::PrintArea => {
-                let range;
-                if self.first_col == 0 && self.last_col == COL_MAX - 1 {
-                    // The print range is the entire column range, therefore we
-                    // create a row only range.
-                    range = format!("${}:${}", self.first_row + 1, self.last_row + 1);
-                } else if self.first_row == 0 && self.last_row == ROW_MAX - 1 {
-                    // The print range is the entire row range, therefore we
-                    // create a column only range.
-                    range = format!(
-                        "${}:${}",
-                        utility::column_number_to_name(self.first_col),
-                        utility::column_number_to_name(self.last_col)
-                    );
-                } else {
-                    // Otherwise handle it as a standard cell range.
-                    range = utility::cell_range_absolute(
-                        self.first_row,
-                        self.first_col,
-                        self.last_row,
-                        self.last_col,
-                    );
+                let mut range =
+                    self.area_range(self.first_row, self.first_col, self.last_row, self.last_col);
+
+                // Excel stores multiple print areas as a comma separated list.
+                for (first_row, first_col, last_row, last_col) in &self.additional_areas {
+                    let area = self.area_range(*first_row, *first_col, *last_row, *last_col);
+                    range = format!("{range},{area}");
                 }
 
-                self.range = format!("{}!{}", self.quoted_sheet_name, range);
+                self.range = range;
             }
             DefinedNameType::PrintTitles => {
                 let mut range = String::new();
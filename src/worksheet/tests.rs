@@ -7,8 +7,10 @@
 #[cfg(test)]
 mod worksheet_tests {
 
+    use crate::shared_strings::SharedStrings;
     use crate::test_functions::xml_to_vec;
     use crate::worksheet::*;
+    use crate::FormatBorder;
     use crate::XlsxError;
     use pretty_assertions::assert_eq;
     use std::collections::HashMap;
@@ -121,6 +123,42 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_formula_and_error_cells() {
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct MyStruct {
+            value: Option<f64>,
+        }
+
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "value").unwrap();
+
+        // Formulas without an explicit result are read as empty, not as the
+        // default result of 0.
+        worksheet.write(1, 0, Formula::new("=1+1")).unwrap();
+        worksheet
+            .write(2, 0, Formula::new("=2+2").set_result("4"))
+            .unwrap();
+
+        let data: Vec<MyStruct> = worksheet.deserialize(0, 0, 2, 0).unwrap();
+        assert_eq!(
+            data,
+            vec![MyStruct { value: None }, MyStruct { value: Some(4.0) }]
+        );
+
+        // Error cells can't be deserialized.
+        let err: Result<f64, String> = Err("Parse error".to_string());
+        worksheet
+            .write_result(3, 0, &err, ResultErrorMode::ValueError)
+            .unwrap();
+
+        let result = worksheet.deserialize::<MyStruct>(0, 0, 3, 0);
+        assert!(matches!(result, Err(XlsxError::SerdeError(_))));
+    }
+
     #[test]
     fn row_matches_list_filter_blanks() {
         let mut worksheet = Worksheet::new();
@@ -690,4 +728,1352 @@ mod worksheet_tests {
         let result = worksheet.write_string(0, 0, long_string.unwrap());
         assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
     }
+
+    #[test]
+    fn chart_cache_from_formula_results() {
+        let mut worksheet = Worksheet::new();
+
+        // Formulas with and without explicit results.
+        worksheet
+            .write(0, 0, Formula::new("=1+1").set_result("2"))
+            .unwrap();
+        worksheet.write(1, 0, Formula::new("=2*3")).unwrap();
+        worksheet
+            .write_array_formula(2, 0, 2, 0, Formula::new("=SUM(B1:B2)"))
+            .unwrap();
+        worksheet.set_formula_result(2, 0, "7");
+
+        let cache = worksheet.get_cache_data(0, 0, 2, 0);
+        assert!(cache.cache_type == ChartRangeCacheDataType::Number);
+        assert_eq!(cache.data, vec!["2", "", "7"]);
+
+        // Formulas without results, including the default "0" result, are
+        // cached as empty values.
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, Formula::new("=1+1")).unwrap();
+        worksheet.write(1, 0, Formula::new("=2*3")).unwrap();
+
+        let cache = worksheet.get_cache_data(0, 0, 1, 0);
+        assert!(cache.cache_type == ChartRangeCacheDataType::None);
+        assert_eq!(cache.data, vec!["", ""]);
+    }
+
+    #[test]
+    fn long_rich_string() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+        let chars: [u8; 16_384] = [64; 16_384];
+        let long_string = std::str::from_utf8(&chars).unwrap();
+
+        // The unformatted string is at the limit and the font xml is ignored.
+        let segments = [(&bold, &long_string[1..]), (&bold, long_string)];
+        let result = worksheet.write_rich_string(0, 0, &segments);
+        assert!(result.is_ok());
+
+        // The unformatted string is over the limit.
+        let segments = [(&bold, long_string), (&bold, long_string)];
+        let result = worksheet.write_rich_string(0, 0, &segments);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+    }
+
+    #[test]
+    fn rich_string_dedup() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+
+        let segments = [(&bold, "bold"), (&italic, " italic")];
+        worksheet.write_rich_string(0, 0, &segments).unwrap();
+        worksheet.write_rich_string(1, 0, &segments).unwrap();
+
+        let segments = [(&italic, "bold"), (&bold, " italic")];
+        worksheet.write_rich_string(2, 0, &segments).unwrap();
+
+        worksheet.update_string_table_ids(&mut string_table);
+
+        // Identical rich strings share a string table entry.
+        assert_eq!(string_table.count, 3);
+        assert_eq!(string_table.unique_count, 2);
+    }
+
+    #[test]
+    fn repeat_rows_and_columns() {
+        let mut worksheet = Worksheet::new();
+
+        // Repeat columns only.
+        worksheet.set_repeat_columns(0, 0).unwrap();
+
+        let mut defined_name = worksheet.repeat_row_cols_defined_name.clone();
+        defined_name.initialize("Sheet1");
+        assert_eq!("Sheet1!$A:$A", defined_name.range);
+
+        // Excel combines repeat columns and rows as a comma separated list.
+        worksheet.set_repeat_rows(0, 0).unwrap();
+
+        let mut defined_name = worksheet.repeat_row_cols_defined_name.clone();
+        defined_name.initialize("Sheet1");
+        assert_eq!("Sheet1!$A:$A,Sheet1!$1:$1", defined_name.range);
+        assert_eq!("_xlnm.Print_Titles", defined_name.name());
+
+        // Quoted sheet names are applied to both parts of the range.
+        let mut defined_name = worksheet.repeat_row_cols_defined_name.clone();
+        defined_name.initialize("'Sheet 1'");
+        assert_eq!("'Sheet 1'!$A:$A,'Sheet 1'!$1:$1", defined_name.range);
+
+        // Test error conditions.
+        let result = worksheet.set_repeat_columns(3, 1);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.set_repeat_columns(0, COL_MAX);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn set_selections() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Select two disjoint ranges, the second in reverse order.
+        worksheet
+            .set_selections(&[(1, 1, 2, 2), (5, 5, 4, 4)])
+            .unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <selection activeCell="B2" sqref="B2:C3 E5:F6"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Test error conditions.
+        let result = worksheet.set_selections(&[]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.set_selections(&[(0, 0, 0, 0), (ROW_MAX, 0, 0, 0)]);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn filter_column_top10() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet.write_string(0, 0, "Sales")?;
+        worksheet.write_number(1, 0, 3000)?;
+        worksheet.write_number(2, 0, 8000)?;
+        worksheet.write_number(3, 0, 5000)?;
+        worksheet.write_number(4, 0, 8000)?;
+
+        worksheet.autofilter(0, 0, 4, 0)?;
+
+        // The tied values of 8000 should both be shown.
+        let filter_condition = FilterCondition::new().add_top_filter(2);
+        worksheet.filter_column(0, &filter_condition)?;
+
+        worksheet.hide_autofilter_rows();
+        assert!(worksheet.changed_rows.get(&1).unwrap().hidden);
+        assert!(!worksheet.changed_rows.contains_key(&2));
+        assert!(worksheet.changed_rows.get(&3).unwrap().hidden);
+        assert!(!worksheet.changed_rows.contains_key(&4));
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<autoFilter ref="A1:A5"><filterColumn colId="0"><top10 val="2" filterVal="8000"/></filterColumn></autoFilter>"#
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_column_bottom_percent() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_string(0, 0, "Sales")?;
+        for row in 1..=10 {
+            worksheet.write_number(row, 0, row * 100)?;
+        }
+
+        worksheet.autofilter(0, 0, 10, 0)?;
+
+        let filter_condition = FilterCondition::new().add_bottom_percent_filter(20);
+        worksheet.filter_column(0, &filter_condition)?;
+
+        worksheet.hide_autofilter_rows();
+        for row in 1..=10 {
+            let is_hidden = worksheet
+                .changed_rows
+                .get(&row)
+                .is_some_and(|row_options| row_options.hidden);
+            assert_eq!(row > 2, is_hidden);
+        }
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<top10 top="0" percent="1" val="20" filterVal="200"/>"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_column_greater_than() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_string(0, 0, "Sales")?;
+        worksheet.write_number(1, 0, 3000)?;
+        worksheet.write_number(2, 0, 8000)?;
+        worksheet.write_string(3, 0, "N/A")?;
+
+        worksheet.autofilter(0, 0, 3, 0)?;
+
+        let filter_condition =
+            FilterCondition::new().add_custom_filter(FilterCriteria::GreaterThan, 5000.0);
+        worksheet.filter_column(0, &filter_condition)?;
+
+        worksheet.hide_autofilter_rows();
+        assert!(worksheet.changed_rows.get(&1).unwrap().hidden);
+        assert!(!worksheet.changed_rows.contains_key(&2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_column_top10_errors() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.autofilter(0, 0, 10, 0)?;
+
+        let filter_condition = FilterCondition::new().add_top_filter(0);
+        let result = worksheet.filter_column(0, &filter_condition);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let filter_condition = FilterCondition::new().add_bottom_filter(501);
+        let result = worksheet.filter_column(0, &filter_condition);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let filter_condition = FilterCondition::new().add_top_percent_filter(101);
+        let result = worksheet.filter_column(0, &filter_condition);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_rows() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet.group_rows(1, 4, false)?;
+        worksheet.group_rows(2, 3, true)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A2:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="2"/>
+              <sheetData>
+                <row r="2" outlineLevel="1"/>
+                <row r="3" hidden="1" outlineLevel="2"/>
+                <row r="4" hidden="1" outlineLevel="2"/>
+                <row r="5" outlineLevel="1" collapsed="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_columns() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet.group_columns(1, 2, true)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelCol="1"/>
+              <cols>
+                <col min="2" max="3" width="0" hidden="1" customWidth="1" outlineLevel="1"/>
+                <col min="4" max="4" width="9.140625" collapsed="1"/>
+              </cols>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_rows_columns_errors() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        // Excel allows a maximum of 7 outline levels.
+        for _ in 0..7 {
+            worksheet.group_rows(1, 2, false)?;
+            worksheet.group_columns(1, 2, false)?;
+        }
+
+        let result = worksheet.group_rows(2, 3, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.group_columns(0, 1, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // A failed group shouldn't change the existing levels.
+        assert!(!worksheet.changed_rows.contains_key(&3));
+        assert!(!worksheet.changed_cols.contains_key(&0));
+
+        // Range errors.
+        let result = worksheet.group_rows(5, 4, false);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.group_rows(0, ROW_MAX, false);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.group_columns(0, COL_MAX, false);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_rows_with_level() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Two nested row groups with explicit levels.
+        worksheet.group_rows_with_level(1, 4, 1, false)?;
+        worksheet.group_rows_with_level(2, 3, 2, false)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A2:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="2"/>
+              <sheetData>
+                <row r="2" outlineLevel="1"/>
+                <row r="3" outlineLevel="2"/>
+                <row r="4" outlineLevel="2"/>
+                <row r="5" outlineLevel="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_outline_settings() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // With the symbols above the group the summary row is before it.
+        worksheet.set_outline_settings(false, false);
+        worksheet.group_rows(1, 2, true)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr>
+                <outlinePr summaryBelow="0" summaryRight="0"/>
+              </sheetPr>
+              <dimension ref="A1:A3"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="1"/>
+              <sheetData>
+                <row r="1" collapsed="1"/>
+                <row r="2" hidden="1" outlineLevel="1"/>
+                <row r="3" hidden="1" outlineLevel="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_with_level_errors() {
+        let mut worksheet = Worksheet::new();
+
+        // Excel outline levels are in the range 1-7.
+        let result = worksheet.group_rows_with_level(1, 2, 0, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.group_rows_with_level(1, 2, 8, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.group_columns_with_level(1, 2, 0, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.group_columns_with_level(1, 2, 8, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.group_rows_with_level(1, 2, 7, false);
+        assert!(result.is_ok());
+
+        let result = worksheet.group_columns_with_level(1, 2, 7, false);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn write_result() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        let ok: Result<i32, String> = Ok(123);
+        let err: Result<i32, String> = Err("Parse error".to_string());
+
+        worksheet.write_result(0, 0, &ok, ResultErrorMode::Text)?;
+        worksheet.write_result(1, 0, &err, ResultErrorMode::Text)?;
+
+        // The blank mode removes any previous cell value.
+        worksheet.write(2, 0, 456)?;
+        worksheet.write_result(2, 0, &err, ResultErrorMode::Blank)?;
+        worksheet.write_result(3, 0, &err, ResultErrorMode::ValueError)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A4"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1">
+                    <v>123</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" t="s">
+                    <v>0</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="e">
+                    <v>#VALUE!</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_result_errors() {
+        let mut worksheet = Worksheet::new();
+        let err: Result<i32, String> = Err("Parse error".to_string());
+
+        for mode in [
+            ResultErrorMode::Text,
+            ResultErrorMode::Blank,
+            ResultErrorMode::ValueError,
+        ] {
+            let result = worksheet.write_result(ROW_MAX, 0, &err, mode);
+            assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        }
+    }
+
+    #[test]
+    fn set_top_left_cell_with_panes() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Freeze the first 2 rows and scroll the data region to C20.
+        worksheet.set_freeze_panes(2, 0)?;
+        worksheet.set_top_left_cell(19, 2)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" topLeftCell="C1" workbookViewId="0">
+                  <pane ySplit="2" topLeftCell="C20" activePane="bottomLeft" state="frozen"/>
+                  <selection pane="bottomLeft"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn freeze_top_row_and_first_column() {
+        let mut worksheet = Worksheet::new();
+        worksheet.freeze_top_row();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#
+        ));
+
+        let mut worksheet = Worksheet::new();
+        worksheet.freeze_first_column();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane xSplit="1" topLeftCell="B1" activePane="topRight" state="frozen"/>"#
+        ));
+
+        let mut worksheet = Worksheet::new();
+        worksheet.freeze_top_row_and_first_column();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane xSplit="1" ySplit="1" topLeftCell="B2" activePane="bottomRight" state="frozen"/>"#
+        ));
+
+        // The convenience methods replace any split panes.
+        let mut worksheet = Worksheet::new();
+        worksheet.set_split_panes(30.0, 0.0);
+        worksheet.freeze_top_row();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#
+        ));
+    }
+
+    #[test]
+    fn set_split_panes() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Split below the first row and to the right of the first column.
+        worksheet.set_split_panes(15.0, 8.43);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane xSplit="1350" ySplit="600" topLeftCell="B2" activePane="bottomRight"/>
+                  <selection pane="topRight"/>
+                  <selection pane="bottomLeft"/>
+                  <selection pane="bottomRight"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn set_split_panes_horizontal_only() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // A horizontal split only, with an explicit top cell for the scrolled
+        // pane. Freezing the panes afterwards replaces the split.
+        worksheet.set_split_panes(30.0, 0.0);
+        worksheet.set_freeze_panes_top_cell(9, 0)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane ySplit="900" topLeftCell="A10" activePane="bottomLeft"/><selection pane="bottomLeft"/>"#
+        ));
+
+        // A user selection is written in the active pane.
+        let mut worksheet = Worksheet::new();
+        worksheet.set_split_panes(30.0, 0.0);
+        worksheet.set_selection(11, 2, 12, 3)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<pane ySplit="900" topLeftCell="A3" activePane="bottomLeft"/><selection pane="bottomLeft" activeCell="C12" sqref="C12:D13"/></sheetView>"#
+        ));
+
+        let mut worksheet = Worksheet::new();
+        worksheet.set_split_panes(30.0, 0.0);
+        worksheet.set_freeze_panes(2, 0)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"state="frozen""#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn collapse_outline_to_level() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // A 2 level row outline collapsed to level 1, and a column group.
+        worksheet.group_rows(1, 5, false)?;
+        worksheet.group_rows(1, 2, false)?;
+        worksheet.group_rows(4, 4, false)?;
+        worksheet.group_columns(1, 2, false)?;
+        worksheet.collapse_outline_to_level(1, 1)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A2:A7"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="2" outlineLevelCol="1"/>
+              <cols>
+                <col min="2" max="3" width="0" hidden="1" customWidth="1" outlineLevel="1"/>
+                <col min="4" max="4" width="9.140625" collapsed="1"/>
+              </cols>
+              <sheetData>
+                <row r="2" hidden="1" outlineLevel="2"/>
+                <row r="3" hidden="1" outlineLevel="2"/>
+                <row r="4" hidden="1" outlineLevel="1" collapsed="1"/>
+                <row r="5" hidden="1" outlineLevel="2"/>
+                <row r="6" hidden="1" outlineLevel="1" collapsed="1"/>
+                <row r="7" collapsed="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Levels above Excel's limit are an error.
+        let result = worksheet.collapse_outline_to_level(8, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_zoom_page_break_preview() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // An out of range zoom is ignored and the previous value is kept.
+        worksheet.set_view_page_break_preview();
+        worksheet.set_zoom(150);
+        worksheet.set_zoom(401);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<sheetView tabSelected="1" view="pageBreakPreview" zoomScale="150" zoomScaleSheetLayoutView="150" workbookViewId="0"/>"#
+        ));
+    }
+
+    #[test]
+    fn set_page_break_preview_zoom() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // The page break preview zoom is stored separately from the normal zoom.
+        worksheet.set_zoom(150);
+        worksheet.set_page_break_preview_zoom(80);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<sheetView tabSelected="1" zoomScale="150" zoomScaleNormal="150" zoomScaleSheetLayoutView="80" workbookViewId="0"/>"#
+        ));
+    }
+
+    #[test]
+    fn autofit_disabled_column() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_string(0, 0, "A long string value")?;
+        worksheet.write_string(0, 1, "A long string value")?;
+
+        // Column A is narrowed but can still grow. Column B keeps its width.
+        worksheet.set_column_width(0, 5)?;
+        worksheet.set_column_width(1, 5)?;
+        worksheet.set_column_autofit_disabled(1)?;
+        worksheet.autofit();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<cols><col min="1" max="1" width="17.28515625" bestFit="1" customWidth="1"/><col min="2" max="2" width="5.7109375" customWidth="1"/></cols>"#
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_dynamic_formula_anchor() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_dynamic_formula(0, 2, "=SORT(A1:A10)")?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(
+            r#"<c r="C1" cm="1"><f t="array" ref="C1">_xlfn._xlws.SORT(A1:A10)</f><v>0</v></c>"#
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_tab_color_theme_and_rgb() {
+        // A theme color with a tint on a hidden worksheet.
+        let mut worksheet = Worksheet::new();
+        worksheet.set_hidden(true);
+        worksheet.set_tab_color(Color::Theme(4, 3));
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(
+            got.contains(r#"<sheetPr><tabColor theme="4" tint="0.39997558519241921"/></sheetPr>"#)
+        );
+
+        // An RGB color.
+        let mut worksheet = Worksheet::new();
+        worksheet.set_tab_color((0x4F, 0x02, 0x6A));
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<sheetPr><tabColor rgb="FF4F026A"/></sheetPr>"#));
+    }
+
+    #[test]
+    fn set_column_width_units() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        // Both unit kinds map to the same internal width as the existing
+        // character and pixel methods.
+        worksheet.set_column_width_units(0, ColumnWidth::Chars(16.0))?;
+        worksheet.set_column_width_units(1, ColumnWidth::Pixels(117.0))?;
+        worksheet.set_column_width_units(2, ColumnWidth::Pixels(33.0))?;
+        worksheet.set_column_width_units(3, ColumnWidth::Pixels(6.0))?;
+        worksheet.set_column_width(4, 16.0)?;
+        worksheet.set_column_width_pixels(5, 117)?;
+        worksheet.set_column_width_pixels(6, 33)?;
+        worksheet.set_column_width_pixels(7, 6)?;
+
+        let width = |col| worksheet.changed_cols.get(&col).unwrap().width;
+        assert_eq!(16.0, width(0));
+        assert_eq!(16.0, width(1));
+        assert_eq!(width(4), width(0));
+        assert_eq!(width(5), width(1));
+        assert_eq!(width(6), width(2));
+        assert_eq!(width(7), width(3));
+
+        let result = worksheet.set_column_width_units(COL_MAX, ColumnWidth::Pixels(33.0));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_row_height_and_column_width() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet.write(0, 0, "Foo")?;
+        worksheet.set_row_height(0, 30)?;
+        worksheet.set_row_height(1, 30)?;
+        worksheet.set_row_hidden(1)?;
+        worksheet.set_column_width(0, 20)?;
+        worksheet.set_column_width(1, 20)?;
+        worksheet.set_column_hidden(1)?;
+
+        // Clear the sizes. The hidden row and column keep their hidden state.
+        worksheet.clear_row_height(0)?;
+        worksheet.clear_row_height(1)?;
+        worksheet.clear_row_height(2)?;
+        worksheet.clear_column_width(0)?;
+        worksheet.clear_column_width(1)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A2"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <cols>
+                <col min="2" max="2" width="0" hidden="1" customWidth="1"/>
+              </cols>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" t="s">
+                    <v>0</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1" hidden="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        let result = worksheet.clear_row_height(ROW_MAX);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.clear_column_width(COL_MAX);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_sparse_row() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        worksheet
+            .write_sparse_row(0, 1, [(0, 10), (2, 20), (5, 30)])
+            .unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="B1:G1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="2:7">
+                  <c r="B1">
+                    <v>10</v>
+                  </c>
+                  <c r="D1">
+                    <v>20</v>
+                  </c>
+                  <c r="G1">
+                    <v>30</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Offsets past the column limit are an error.
+        let result = worksheet.write_sparse_row(0, COL_MAX - 1, [(1, 1)]);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_sparse_row(0, 1, [(ColNum::MAX, 1)]);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn write_url_with_url_format() {
+        let mut worksheet = Worksheet::new();
+
+        // A brand color format without an underline.
+        let brand_format = Format::new().set_font_color(crate::Color::RGB(0x7030A0));
+        let url = crate::Url::new("https://www.rust-lang.org").set_format(&brand_format);
+
+        worksheet.write(0, 0, url).unwrap();
+
+        // The url format replaces the default hyperlink style entirely.
+        assert_eq!(worksheet.xf_formats.len(), 2);
+        assert_eq!(worksheet.xf_formats[1], brand_format);
+        assert!(worksheet
+            .xf_formats
+            .iter()
+            .all(|format| format.font.underline == crate::FormatUnderline::None));
+
+        // A format passed to the worksheet method takes precedence.
+        let bold = Format::new().set_bold();
+        let url = crate::Url::new("https://www.rust-lang.org").set_format(&brand_format);
+
+        worksheet.write_url_with_format(1, 0, url, &bold).unwrap();
+        assert_eq!(worksheet.xf_formats.len(), 3);
+        assert_eq!(worksheet.xf_formats[2], bold);
+    }
+
+    #[test]
+    fn write_number_rounded() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_number(0, 0, 0.1 + 0.2).unwrap();
+        worksheet.write_number_rounded(1, 0, 0.1 + 0.2, 2).unwrap();
+        worksheet.write_number_rounded(2, 0, 1234.5678, 2).unwrap();
+
+        worksheet.assemble_xml_file();
+
+        // The rounded value is stored, not just displayed.
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1"><v>0.30000000000000004</v></c>"#));
+        assert!(got.contains(r#"<c r="A2"><v>0.3</v></c>"#));
+        assert!(got.contains(r#"<c r="A3"><v>1234.57</v></c>"#));
+    }
+
+    #[test]
+    fn write_formula_with_result() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new();
+
+        worksheet
+            .write_formula_with_result(0, 0, "=1+2+3", "6", &format)
+            .unwrap();
+        worksheet
+            .write_formula_with_result(1, 0, "=A1>5", "TRUE", &format)
+            .unwrap();
+        worksheet
+            .write_formula_with_result(2, 0, "=A1<5", "FALSE", &format)
+            .unwrap();
+        worksheet
+            .write_formula_with_result(3, 0, r#"=UPPER("rust")"#, "RUST", &format)
+            .unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1"><f>1+2+3</f><v>6</v></c>"#));
+        assert!(got.contains(r#"<c r="A2" t="b"><f>A1&gt;5</f><v>1</v></c>"#));
+        assert!(got.contains(r#"<c r="A3" t="b"><f>A1&lt;5</f><v>0</v></c>"#));
+        assert!(got.contains(r#"<c r="A4" t="str"><f>UPPER("rust")</f><v>RUST</v></c>"#));
+    }
+
+    #[test]
+    fn formula_result_types() {
+        let mut worksheet = Worksheet::new();
+
+        // Only the uppercase Excel boolean values are stored as booleans.
+        // Other non-numeric results are stored as strings, as before.
+        worksheet
+            .write(0, 0, Formula::new("=1=1").set_result("TRUE"))
+            .unwrap();
+        worksheet
+            .write(1, 0, Formula::new("=1=2").set_result("FALSE"))
+            .unwrap();
+        worksheet
+            .write(2, 0, Formula::new(r#"="True""#).set_result("True"))
+            .unwrap();
+        worksheet
+            .write(3, 0, Formula::new(r#"="false""#).set_result("false"))
+            .unwrap();
+        worksheet
+            .write(4, 0, Formula::new(r#"="yes""#).set_result("yes"))
+            .unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1" t="b"><f>1=1</f><v>1</v></c>"#));
+        assert!(got.contains(r#"<c r="A2" t="b"><f>1=2</f><v>0</v></c>"#));
+        assert!(got.contains(r#"<c r="A3" t="str"><f>"True"</f><v>True</v></c>"#));
+        assert!(got.contains(r#"<c r="A4" t="str"><f>"false"</f><v>false</v></c>"#));
+        assert!(got.contains(r#"<c r="A5" t="str"><f>"yes"</f><v>yes</v></c>"#));
+    }
+
+    #[test]
+    fn chartsheet() -> Result<(), XlsxError> {
+        let mut chart = crate::Chart::new(crate::ChartType::Pie);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+        let mut chartsheet = Worksheet::new_chartsheet();
+        chartsheet.set_selected(true);
+        chartsheet.set_tab_color("#FF0000");
+
+        // The row/col position is ignored and only one chart is stored.
+        chartsheet.insert_chart(5, 5, &chart)?;
+        chartsheet.insert_chart(0, 0, &chart)?;
+        assert_eq!(chartsheet.charts.len(), 1);
+
+        // Cell data isn't written to a chartsheet.
+        chartsheet.write_string(0, 0, "Ignored")?;
+
+        chartsheet.prepare_worksheet_charts(1, 1, 1);
+        chartsheet.assemble_xml_file();
+
+        let got = chartsheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <chartsheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr>
+                <tabColor rgb="FFFF0000"/>
+              </sheetPr>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0" zoomToFit="1"/>
+              </sheetViews>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              <pageSetup orientation="landscape"/>
+              <drawing r:id="rId1"/>
+            </chartsheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_range_border() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        let format = Format::new().set_border(FormatBorder::Thin);
+        worksheet.merge_range(0, 0, 2, 2, "Box", &format).unwrap();
+
+        worksheet.update_string_table_ids(&mut string_table);
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file();
+
+        // Only the first cell has the string but all cells have the border.
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1" s="1" t="s"><v>0</v></c>"#));
+        for cell in ["B1", "C1", "A2", "C2", "A3", "B3", "C3"] {
+            assert!(got.contains(&format!(r#"<c r="{cell}" s="1"/>"#)));
+        }
+        assert!(got.contains(r#"<mergeCell ref="A1:C3"/>"#));
+    }
+
+    #[test]
+    fn format_text() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+        let mut shared_strings = SharedStrings::new();
+
+        let format = Format::text();
+        worksheet
+            .write_string_with_format(0, 0, "00123", &format)
+            .unwrap();
+
+        worksheet.update_string_table_ids(&mut string_table);
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1" s="1" t="s"><v>0</v></c>"#));
+
+        // The numeric looking string is stored verbatim.
+        shared_strings.assemble_xml_file(&string_table);
+
+        let got = shared_strings.writer.read_to_str();
+        assert!(got.contains("<si><t>00123</t></si>"));
+    }
+
+    #[test]
+    fn write_duration() {
+        let mut worksheet = Worksheet::new();
+
+        let format = Format::new().set_num_format("mm:ss");
+        worksheet.write(0, 0, Duration::from_secs(3661)).unwrap();
+        worksheet
+            .write_with_format(1, 0, Duration::from_millis(90_000), &format)
+            .unwrap();
+
+        worksheet.set_global_xf_indices(&[0, 1, 2]);
+        worksheet.assemble_xml_file();
+
+        // The durations are stored as fractions of a day.
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1" s="1"><v>0.04237268518518519</v></c>"#));
+        assert!(got.contains(r#"<c r="A2" s="2"><v>0.0010416666666666667</v></c>"#));
+
+        // Durations without a format get a default elapsed time format.
+        assert_eq!(worksheet.xf_formats[1].num_format, "[h]:mm:ss");
+        assert_eq!(worksheet.xf_formats[2].num_format, "mm:ss");
+    }
+
+    #[test]
+    fn write_char() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+        let mut shared_strings = SharedStrings::new();
+
+        let format = Format::new().set_bold();
+        worksheet.write(0, 0, 'A').unwrap();
+        worksheet
+            .write_with_format(1, 0, '\u{1F600}', &format)
+            .unwrap();
+
+        worksheet.update_string_table_ids(&mut string_table);
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<c r="A1" t="s"><v>0</v></c>"#));
+        assert!(got.contains(r#"<c r="A2" s="1" t="s"><v>1</v></c>"#));
+
+        // Multi-byte chars are written as the full UTF-8 character.
+        shared_strings.assemble_xml_file(&string_table);
+
+        let got = shared_strings.writer.read_to_str();
+        assert!(got.contains("<si><t>A</t></si><si><t>\u{1F600}</t></si>"));
+    }
+
+    #[test]
+    fn set_right_to_left() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_right_to_left(true);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<sheetView rightToLeft="1" workbookViewId="0"/>"#));
+    }
+
+    #[test]
+    fn write_with_format_id() {
+        let bold = Format::new().set_bold();
+        let number = Format::new().set_num_format("0.00");
+
+        // Write the data with Format references.
+        let mut worksheet1 = Worksheet::new();
+        worksheet1.write_with_format(0, 0, "Foo", &bold).unwrap();
+        worksheet1.write_with_format(1, 0, 1.2345, &number).unwrap();
+        worksheet1.write_with_format(2, 0, true, &bold).unwrap();
+        worksheet1
+            .write_with_format(3, 0, None::<f64>, &number)
+            .unwrap();
+        worksheet1
+            .write_with_format(4, 0, Formula::new("=A2*2"), &number)
+            .unwrap();
+        worksheet1.write(5, 0, "Bar").unwrap();
+
+        // Write the same data with registered format ids.
+        let mut worksheet2 = Worksheet::new();
+        let bold_id = worksheet2.register_format(&bold);
+        let number_id = worksheet2.register_format(&number);
+        assert_eq!(bold_id, worksheet2.register_format(&bold));
+
+        worksheet2
+            .write_with_format_id(0, 0, "Foo", bold_id)
+            .unwrap();
+        worksheet2
+            .write_with_format_id(1, 0, 1.2345, number_id)
+            .unwrap();
+        worksheet2
+            .write_with_format_id(2, 0, true, bold_id)
+            .unwrap();
+        worksheet2
+            .write_with_format_id(3, 0, None::<f64>, number_id)
+            .unwrap();
+        worksheet2
+            .write_with_format_id(4, 0, Formula::new("=A2*2"), number_id)
+            .unwrap();
+        worksheet2.write(5, 0, "Bar").unwrap();
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet1.update_string_table_ids(&mut string_table);
+        worksheet1.set_global_xf_indices(&[0, 1, 2]);
+        worksheet1.assemble_xml_file();
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet2.update_string_table_ids(&mut string_table);
+        worksheet2.set_global_xf_indices(&[0, 1, 2]);
+        worksheet2.assemble_xml_file();
+
+        assert_eq!(worksheet1.xf_formats, worksheet2.xf_formats);
+        assert_eq!(
+            worksheet1.writer.read_to_str(),
+            worksheet2.writer.read_to_str()
+        );
+
+        // Ids are only valid for the worksheet that registered them, even if
+        // the other worksheet has registered the same formats.
+        let mut worksheet3 = Worksheet::new();
+        let result = worksheet3.write_with_format_id(0, 0, "Foo", number_id);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let other_bold_id = worksheet3.register_format(&bold);
+        let other_number_id = worksheet3.register_format(&number);
+        assert_ne!(bold_id, other_bold_id);
+
+        let result = worksheet3.write_with_format_id(0, 0, "Foo", number_id);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet3
+            .write_with_format_id(0, 0, "Foo", other_number_id)
+            .unwrap();
+    }
+
+    #[test]
+    fn set_print_notes() {
+        let tests = [
+            (NotePrintPosition::AtEnd, true, r#"cellComments="atEnd""#),
+            (
+                NotePrintPosition::AsDisplayed,
+                true,
+                r#"cellComments="asDisplayed""#,
+            ),
+            // Threaded comments can only be printed at the end.
+            (
+                NotePrintPosition::AsDisplayed,
+                false,
+                r#"cellComments="atEnd""#,
+            ),
+        ];
+
+        for (position, has_note, expected) in tests {
+            let mut worksheet = Worksheet::new();
+            if has_note {
+                worksheet.insert_note(0, 0, &Note::new("Note")).unwrap();
+            }
+            worksheet
+                .insert_threaded_comment(1, 0, &ThreadedComment::new("Comment"))
+                .unwrap();
+            worksheet.set_print_notes(position);
+
+            worksheet.assemble_xml_file();
+
+            let got = worksheet.writer.read_to_str();
+            assert!(got.contains(expected));
+        }
+
+        let mut worksheet = Worksheet::new();
+        worksheet.set_print_notes(NotePrintPosition::None);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(!got.contains("<pageSetup"));
+    }
 }
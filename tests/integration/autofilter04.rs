@@ -9,7 +9,7 @@ use crate::common;
 use rust_xlsxwriter::{FilterCondition, FilterCriteria, Workbook, XlsxError};
 
 // Test to demonstrate autofilters.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -45,11 +45,63 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
 
     Ok(())
 }
+
+// Test to demonstrate autofilters with f64 numeric filter values.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Write the headers.
+    worksheet.write_string(0, 0, "Region")?;
+    worksheet.write_string(0, 1, "Item")?;
+    worksheet.write_string(0, 2, "Volume")?;
+    worksheet.write_string(0, 3, "Month")?;
+
+    // Write the data used in the autofilter.
+    let data = common::get_autofilter_data();
+    for (row, data) in data.iter().enumerate() {
+        let row = 1 + row as u32;
+        worksheet.write_string(row, 0, data.0)?;
+        worksheet.write_string(row, 1, data.1)?;
+        worksheet.write_number(row, 2, data.2)?;
+        worksheet.write_string(row, 3, data.3)?;
+    }
+
+    worksheet.autofilter(0, 0, 50, 3)?;
+
+    let filter_condition1 = FilterCondition::new().add_list_filter("East");
+
+    let filter_condition2 = FilterCondition::new()
+        .add_custom_filter(FilterCriteria::GreaterThan, 3000.0)
+        .add_custom_filter(FilterCriteria::LessThan, 8000.0);
+
+    worksheet.filter_column(0, &filter_condition1)?;
+    worksheet.filter_column(2, &filter_condition2)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_autofilter04_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter04")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_autofilter04() {
+fn test_autofilter04_2() {
     let test_runner = common::TestRunner::new()
         .set_name("autofilter04")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
@@ -0,0 +1,43 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+// Create rust_xlsxwriter file to compare against Excel file.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add some test data for the chart(s).
+    let data = [1, 2, 2, 3, 3, 3, 4, 5, 5, 7];
+    worksheet.write_column(0, 0, data)?;
+
+    let mut chart = Chart::new(ChartType::Histogram);
+    chart
+        .add_series()
+        .set_values(("Sheet1", 0, 0, 9, 0))
+        .set_bin_count(3);
+
+    worksheet.insert_chart(8, 4, &chart)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_histogram01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("chart_histogram01")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,62 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+// Create rust_xlsxwriter file to compare against Excel file. Test a mix of
+// standard and chartex charts to check the chart and chartEx file numbering.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add some test data for the chart(s).
+    let categories = ["A", "B", "C", "D", "E"];
+    let data = [5, 12, 3, 8, 1];
+    worksheet.write_column(0, 0, categories)?;
+    worksheet.write_column(0, 1, data)?;
+
+    let mut chart1 = Chart::new(ChartType::Histogram);
+    chart1
+        .add_series()
+        .set_values(("Sheet1", 0, 1, 4, 1))
+        .set_bin_width(5.0);
+
+    let mut chart2 = Chart::new(ChartType::Column);
+    chart2.set_axis_ids(64052224, 64055552);
+    chart2
+        .add_series()
+        .set_categories(("Sheet1", 0, 0, 4, 0))
+        .set_values(("Sheet1", 0, 1, 4, 1));
+
+    let mut chart3 = Chart::new(ChartType::Pareto);
+    chart3.title().set_name("Pareto");
+    chart3
+        .add_series()
+        .set_categories(("Sheet1", 0, 0, 4, 0))
+        .set_values(("Sheet1", 0, 1, 4, 1));
+
+    worksheet.insert_chart(8, 4, &chart1)?;
+    worksheet.insert_chart(24, 4, &chart2)?;
+    worksheet.insert_chart(40, 4, &chart3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_histogram02() {
+    let test_runner = common::TestRunner::new()
+        .set_name("chart_histogram02")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,68 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{DataValidation, Formula, Workbook, XlsxError};
+
+// Test data validation with a list from a range on a quoted worksheet name.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let data_validation = DataValidation::new().allow_list_range("My Lists", 0, 0, 4, 0);
+
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.add_data_validation(2, 1, 2, 1, &data_validation)?;
+
+    let worksheet2 = workbook.add_worksheet().set_name("My Lists")?;
+    worksheet2.write_column(0, 0, ["Apple", "Pear", "Plum", "Kiwi", "Lime"])?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test data validation with a list from a formula with a quoted worksheet name.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let data_validation =
+        DataValidation::new().allow_list_formula(Formula::new("='My Lists'!$A$1:$A$5"));
+
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.add_data_validation(2, 1, 2, 1, &data_validation)?;
+
+    let worksheet2 = workbook.add_worksheet().set_name("My Lists")?;
+    worksheet2.write_column(0, 0, ["Apple", "Pear", "Plum", "Kiwi", "Lime"])?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_data_validation01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("data_validation01")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_data_validation01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("data_validation01")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
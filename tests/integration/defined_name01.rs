@@ -9,7 +9,7 @@ use crate::common;
 use rust_xlsxwriter::{Workbook, XlsxError};
 
 // Test to demonstrate defined names.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet1 = workbook.add_worksheet();
@@ -41,11 +41,60 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test to demonstrate defined names. Local names are added via the worksheet
+// and a global name is used in a formula.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet1 = workbook.add_worksheet();
+
+    worksheet1.write_number(0, 0, 10)?;
+    worksheet1.write_formula(1, 0, "=SUM(Abc)")?;
+    worksheet1.write_string(0, 5, "Filter")?;
+    worksheet1.write_string(0, 6, "Auto")?;
+
+    worksheet1.autofilter(0, 5, 0, 6)?;
+    worksheet1.set_print_area(0, 0, 5, 4)?;
+    worksheet1.set_print_fit_to_pages(2, 2);
+    worksheet1.define_local_name("Bar", "=Sheet1!$A$1")?;
+
+    let worksheet2 = workbook.add_worksheet();
+    worksheet2.set_portrait();
+    worksheet2.define_local_name("Bar", "=Sheet2!$A$1")?;
+    worksheet2.define_local_name("aaa", "=Sheet2!$A$1")?;
+
+    let worksheet3 = workbook.add_worksheet().set_name("Sheet 3")?;
+    worksheet3.set_portrait();
+    worksheet3.define_local_name("Bar", "='Sheet 3'!$A$1")?;
+
+    workbook.define_name("Abc", "=Sheet1!$A$1")?;
+    workbook.define_name("Baz", "=0.98")?;
+    workbook.define_name("_Egg", "=Sheet1!$A$1")?;
+    workbook.define_name("_Fog", "=Sheet1!$A$1")?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
-fn test_defined_name01() {
+fn test_defined_name01_1() {
     let test_runner = common::TestRunner::new()
         .set_name("defined_name01")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_defined_name01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("defined_name05")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
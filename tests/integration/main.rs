@@ -376,6 +376,8 @@ mod chart_gridlines08;
 mod chart_gridlines09;
 mod chart_high_low_lines01;
 mod chart_high_low_lines02;
+mod chart_histogram01;
+mod chart_histogram02;
 mod chart_legend01;
 mod chart_legend03;
 mod chart_legend04;
@@ -474,6 +476,7 @@ mod cond_format16;
 mod cond_format18;
 mod cond_format19;
 mod cond_format20;
+mod data_validation01;
 mod defined_name01;
 mod defined_name02;
 mod defined_name03;
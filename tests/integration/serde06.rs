@@ -277,6 +277,46 @@ fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for Serde serialization. With XlsxSerialize rename and header
+// format attributes on the same fields.
+fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_paper_size(9);
+
+    // Create a serializable test struct.
+    #[derive(XlsxSerialize, Serialize)]
+    struct MyStruct {
+        #[xlsx(rename = "col1", header_format = Format::new().set_bold())]
+        field1: u16,
+
+        #[xlsx(rename = "col2", header_format = Format::new().set_bold())]
+        field2: bool,
+    }
+
+    let data = [
+        MyStruct {
+            field1: 123,
+            field2: true,
+        },
+        MyStruct {
+            field1: 456,
+            field2: false,
+        },
+        MyStruct {
+            field1: 789,
+            field2: true,
+        },
+    ];
+
+    worksheet.set_serialize_headers::<MyStruct>(0, 0)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde06_1() {
     let test_runner = common::TestRunner::new()
@@ -360,3 +400,15 @@ fn test_serde06_7() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde06_8() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde06")
+        .set_function(create_new_xlsx_file_8)
+        .unique("8")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
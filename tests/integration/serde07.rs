@@ -9,7 +9,7 @@ use crate::common;
 use rust_xlsxwriter::{
     CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError, XlsxSerialize,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 // Test case for Serde serialization. First test isn't serialized.
 fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
@@ -655,6 +655,130 @@ fn create_new_xlsx_file_16(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for deserializing the serialized data back to structs.
+fn create_new_xlsx_file_17(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct MyStruct {
+        col1: u8,
+        col2: String,
+    }
+
+    let data = vec![
+        MyStruct {
+            col1: 1,
+            col2: "aaa".to_string(),
+        },
+        MyStruct {
+            col1: 2,
+            col2: "bbb".to_string(),
+        },
+        MyStruct {
+            col1: 3,
+            col2: "ccc".to_string(),
+        },
+    ];
+
+    worksheet.deserialize_headers::<MyStruct>(0, 0)?;
+    worksheet.serialize(&data)?;
+
+    let (min_row, min_col, max_row, max_col) = worksheet.get_serialize_dimensions("MyStruct")?;
+    let deserialized: Vec<MyStruct> = worksheet.deserialize(min_row, min_col, max_row, max_col)?;
+    assert_eq!(deserialized, data);
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for deserializing data with custom header names back to structs.
+fn create_new_xlsx_file_18(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct MyStruct {
+        field1: u8,
+        field2: String,
+    }
+
+    let data = vec![
+        MyStruct {
+            field1: 1,
+            field2: "aaa".to_string(),
+        },
+        MyStruct {
+            field1: 2,
+            field2: "bbb".to_string(),
+        },
+        MyStruct {
+            field1: 3,
+            field2: "ccc".to_string(),
+        },
+    ];
+
+    let custom_headers = [
+        CustomSerializeField::new("field1").rename("col1"),
+        CustomSerializeField::new("field2").rename("col2"),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.deserialize_headers_with_options::<MyStruct>(0, 0, &header_options)?;
+    worksheet.serialize(&data)?;
+
+    let deserialized: Vec<MyStruct> = worksheet.deserialize(0, 0, 3, 1)?;
+    assert_eq!(deserialized, data);
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for skipping a middle field via proc macro.
+fn create_new_xlsx_file_19(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Serialize, XlsxSerialize)]
+    #[allow(dead_code)]
+    struct MyStruct {
+        col1: u8,
+        #[xlsx(skip)]
+        internal: bool,
+        col2: &'static str,
+    }
+
+    let data = [
+        MyStruct {
+            col1: 1,
+            internal: true,
+            col2: "aaa",
+        },
+        MyStruct {
+            col1: 2,
+            internal: false,
+            col2: "bbb",
+        },
+        MyStruct {
+            col1: 3,
+            internal: true,
+            col2: "ccc",
+        },
+    ];
+
+    worksheet.set_serialize_headers::<MyStruct>(0, 0)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde07_1() {
     let test_runner = common::TestRunner::new()
@@ -845,3 +969,39 @@ fn test_serde07_16() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde07_17() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_17)
+        .unique("17")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde07_18() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_18)
+        .unique("18")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde07_19() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_19)
+        .unique("19")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -223,6 +223,53 @@ fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for Serde serialization. Set the column width and header name
+// with XlsxSerialize attributes on the same field.
+fn create_new_xlsx_file_6(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_paper_size(9);
+
+    // Create a serializable test struct.
+    #[derive(XlsxSerialize, Serialize)]
+    struct MyStruct {
+        col1: u8,
+
+        #[xlsx(rename = "col2", column_width = 13.57)]
+        field2: u8,
+
+        col3: u8,
+    }
+
+    let data1 = MyStruct {
+        col1: 1,
+        field2: 4,
+        col3: 7,
+    };
+
+    let data2 = MyStruct {
+        col1: 2,
+        field2: 5,
+        col3: 8,
+    };
+
+    let data3 = MyStruct {
+        col1: 3,
+        field2: 6,
+        col3: 9,
+    };
+
+    worksheet.set_serialize_headers::<MyStruct>(0, 0)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde12_1() {
     let test_runner = common::TestRunner::new()
@@ -282,3 +329,15 @@ fn test_serde12_5() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde12_6() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde12")
+        .set_function(create_new_xlsx_file_6)
+        .unique("6")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}